@@ -1,11 +1,13 @@
 use num_traits::ToPrimitive;
+use rand::RngCore;
 
 use crate::Stepper;
 
 /// A trait for common members of the Agents.
 pub trait Agent<T: ToPrimitive> {
-    /// The action chosen by the Agent.
-    fn action(&self) -> usize;
+    /// The action chosen by the Agent. Randomized Agents draw from the given random number
+    /// generator.
+    fn action(&self, rng: &mut dyn RngCore) -> usize;
 
     /// The number of arms in the Bandit the Agent is playing.
     fn arms(&self) -> usize;
@@ -16,6 +18,12 @@ pub trait Agent<T: ToPrimitive> {
     /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
     fn reset(&mut self, q_init: &[f64]);
 
+    /// Forget what the Agent has learned about one arm and give it a new initial guess of the
+    /// arm's value. Returns false, changing nothing, if the Agent can only be reset as a whole.
+    fn reset_arm(&mut self, _arm: usize, _q_init: f64) -> bool {
+        false
+    }
+
     /// Update the Agent's estimate of a Bandit arm based on a given reward.
     fn step(&mut self, arm: usize, reward: T);
 }
@@ -29,3 +37,56 @@ pub(crate) fn update<T: ToPrimitive>(
 ) -> f64 {
     stepper.step(arm) * (reward.to_f64().unwrap() - q_star[arm])
 }
+
+/// Returns the softmax of the values at the given temperature. At a temperature of zero all the
+/// probability is split between the largest values.
+pub(crate) fn softmax(values: &[f64], tau: f64) -> Vec<f64> {
+    let max = values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = if tau > 0.0 {
+        values.iter().map(|v| ((v - max) / tau).exp()).collect()
+    } else {
+        values
+            .iter()
+            .map(|&v| f64::from(u8::from(v == max)))
+            .collect()
+    };
+    let total: f64 = weights.iter().sum();
+    weights.iter().map(|w| w / total).collect()
+}
+
+/// Picks an arm at random with the given probabilities, using a uniform draw `u` from `[0, 1)`.
+pub(crate) fn pick(probabilities: &[f64], u: f64) -> usize {
+    let mut u = u;
+    for (arm, p) in probabilities.iter().enumerate() {
+        if u < *p {
+            return arm;
+        }
+        u -= p
+    }
+    probabilities.len() - 1
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{pick, softmax};
+
+    #[test]
+    fn test_softmax() {
+        let p = softmax(&[0.0, 2f64.ln(), 0.0], 1.0);
+        assert_approx_eq!(p[0], 0.25);
+        assert_approx_eq!(p[1], 0.5);
+        let p = softmax(&[0.0, 2f64.ln(), 0.0], 0.5);
+        assert_approx_eq!(p[1], 4.0 / 6.0);
+        assert_eq!(softmax(&[1.0, 3.0, 3.0], 0.0), vec![0.0, 0.5, 0.5])
+    }
+
+    #[test]
+    fn test_pick() {
+        let p = [0.25, 0.5, 0.25];
+        assert_eq!(pick(&p, 0.1), 0);
+        assert_eq!(pick(&p, 0.7), 1);
+        assert_eq!(pick(&p, 0.99), 2)
+    }
+}
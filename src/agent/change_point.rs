@@ -0,0 +1,261 @@
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+use serde::Deserialize;
+
+use crate::error::{check, check_len};
+use crate::{ChangeDetector, RatelError};
+
+use super::Agent;
+
+/// What a change-point Agent forgets when its detector fires.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Restart {
+    /// Resets the wrapped Agent and the detector on every arm.
+    Global,
+    /// Resets only the arm whose change was detected, or the whole Agent if the wrapped Agent
+    /// cannot reset a single arm.
+    PerArm,
+}
+
+impl Default for Restart {
+    /// Global restarts.
+    fn default() -> Self {
+        Restart::Global
+    }
+}
+
+/// Agent that wraps another Agent and restarts it when the rewards of an arm change abruptly.
+///
+/// Feeds every reward to a change detector as well as the wrapped Agent, and resets the wrapped
+/// Agent to its initial guess when the detector fires. With probability `alpha` the Agent pulls
+/// an arm uniformly at random instead, so changes in arms the wrapped Agent has given up on are
+/// still seen. A `UcbAgent` with a `CusumDetector` gives CUSUM-UCB, and a `KlUcbAgent` with a
+/// `GlrDetector` on a `BernoulliDivergence` gives GLR-klUCB.
+pub struct ChangePointAgent<T> {
+    /// The Agent choosing arms between changes.
+    agent: Box<dyn Agent<T>>,
+
+    /// The test watching each arm's rewards for a change.
+    detector: Box<dyn ChangeDetector>,
+
+    /// What is forgotten when a change is detected.
+    restart: Restart,
+
+    /// Probability of pulling an arm uniformly at random.
+    alpha: f64,
+
+    /// The initial guess of the Bandit arm values the wrapped Agent is reset to.
+    q_init: Vec<f64>,
+
+    /// Number of changes detected.
+    detections: u32,
+
+    /// A random uniform distribution for forced exploration.
+    uniform: Uniform<f64>,
+}
+
+impl<T: ToPrimitive> Agent<T> for ChangePointAgent<T> {
+    /// The action chosen by the Agent. A uniformly random arm with probability `alpha`, and
+    /// otherwise the wrapped Agent's choice.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        if self.alpha > 0.0 {
+            let u = self.uniform.sample(rng);
+            if u < self.alpha {
+                let arms = self.agent.arms();
+                return ((u / self.alpha * arms as f64) as usize).min(arms - 1);
+            }
+        }
+        self.agent.action(rng)
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.agent.arms()
+    }
+
+    /// The wrapped Agent's current estimate of the value of a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.agent.current_estimate(arm)
+    }
+
+    /// Reset the wrapped Agent and the detector, and give them a new initial guess of the
+    /// Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.q_init = q_init.to_owned();
+        self.agent.reset(q_init);
+        for arm in 0..self.detector.arms() {
+            self.detector.reset(arm)
+        }
+        self.detections = 0
+    }
+
+    /// Update the wrapped Agent and the detector with a given reward, restarting if a change is
+    /// detected.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = reward.to_f64().unwrap();
+        self.agent.step(arm, reward);
+        if !self.detector.update(arm, x) {
+            return;
+        }
+        self.detections += 1;
+        match self.restart {
+            Restart::PerArm if self.agent.reset_arm(arm, self.q_init[arm]) => {
+                self.detector.reset(arm)
+            }
+            _ => {
+                self.agent.reset(&self.q_init);
+                for a in 0..self.detector.arms() {
+                    self.detector.reset(a)
+                }
+            }
+        }
+    }
+}
+
+impl<T: ToPrimitive> ChangePointAgent<T> {
+    /// Initializes a new change-point agent. Panics if the parameters are invalid; see
+    /// `try_new`.
+    pub fn new(
+        agent: Box<dyn Agent<T>>,
+        detector: Box<dyn ChangeDetector>,
+        q_init: Vec<f64>,
+        restart: Restart,
+        alpha: f64,
+    ) -> ChangePointAgent<T> {
+        ChangePointAgent::try_new(agent, detector, q_init, restart, alpha)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new change-point agent that resets the wrapped Agent to `q_init`, failing
+    /// if the wrapped Agent, the detector and the initial guess have different numbers of arms or
+    /// `alpha` is not in `[0, 1)`.
+    pub fn try_new(
+        agent: Box<dyn Agent<T>>,
+        detector: Box<dyn ChangeDetector>,
+        q_init: Vec<f64>,
+        restart: Restart,
+        alpha: f64,
+    ) -> Result<ChangePointAgent<T>, RatelError> {
+        check_len("q_init", q_init.len(), agent.arms())?;
+        check_len("detector", detector.arms(), agent.arms())?;
+        check("alpha", alpha, "must be in [0, 1)", |a| {
+            (0.0..1.0).contains(&a)
+        })?;
+        Ok(ChangePointAgent {
+            agent,
+            detector,
+            restart,
+            alpha,
+            q_init,
+            detections: 0,
+            uniform: Uniform::new(0.0, 1.0),
+        })
+    }
+
+    /// Returns the number of changes detected since the last reset.
+    pub fn detections(&self) -> u32 {
+        self.detections
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{
+        BernoulliDivergence, CusumDetector, GlrDetector, GreedyAgent, HarmonicStepper, KlUcbAgent,
+        Ucb1Index, UcbAgent,
+    };
+
+    use super::{Agent, ChangePointAgent, Restart};
+
+    fn cusum_ucb(restart: Restart) -> ChangePointAgent<f64> {
+        let ucb = UcbAgent::new(vec![0.0; 2], Box::new(Ucb1Index::new()));
+        let cusum = CusumDetector::new(2, 0.0, 1.0, 2);
+        ChangePointAgent::new(Box::new(ucb), Box::new(cusum), vec![0.0; 2], restart, 0.0)
+    }
+
+    #[test]
+    fn test_global_restart() {
+        let mut agent = cusum_ucb(Restart::Global);
+        agent.step(1, 0.5);
+        agent.step(0, 0.0);
+        agent.step(0, 0.0);
+        agent.step(0, 0.8);
+        assert_eq!(agent.detections(), 0);
+        agent.step(0, 0.8);
+        assert_eq!(agent.detections(), 1);
+        assert_eq!(agent.current_estimate(1), 0.0);
+        assert_eq!(agent.action(&mut StdRng::seed_from_u64(0)), 0)
+    }
+
+    #[test]
+    fn test_per_arm_restart() {
+        let mut agent = cusum_ucb(Restart::PerArm);
+        agent.step(1, 0.5);
+        for x in &[0.0, 0.0, 0.8, 0.8] {
+            agent.step(0, *x)
+        }
+        assert_eq!(agent.detections(), 1);
+        assert_eq!(agent.current_estimate(1), 0.5);
+        assert_eq!(agent.current_estimate(0), 0.0);
+        assert_eq!(agent.action(&mut StdRng::seed_from_u64(0)), 0)
+    }
+
+    #[test]
+    fn test_per_arm_fallback() {
+        let greedy = GreedyAgent::new(vec![0.0; 2], Box::new(HarmonicStepper::new(1, 2)));
+        let cusum = CusumDetector::new(2, 0.0, 1.0, 1);
+        let mut agent: ChangePointAgent<f64> = ChangePointAgent::new(
+            Box::new(greedy),
+            Box::new(cusum),
+            vec![0.0; 2],
+            Restart::PerArm,
+            0.0,
+        );
+        agent.step(1, 3.0);
+        agent.step(0, 0.0);
+        agent.step(0, 2.0);
+        assert_eq!(agent.detections(), 1);
+        assert_eq!(agent.current_estimate(1), 0.0)
+    }
+
+    #[test]
+    fn test_forced_exploration() {
+        let kl = KlUcbAgent::new(
+            vec![0.0; 3],
+            Box::new(BernoulliDivergence::new(vec![1; 3])),
+            0.0,
+        );
+        let glr = GlrDetector::new(Box::new(BernoulliDivergence::new(vec![1; 3])), 0.05);
+        let agent: ChangePointAgent<u32> = ChangePointAgent::new(
+            Box::new(kl),
+            Box::new(glr),
+            vec![0.0; 3],
+            Restart::Global,
+            0.5,
+        );
+        let mut rng = StdRng::seed_from_u64(0);
+        let actions: Vec<usize> = (0..300).map(|_| agent.action(&mut rng)).collect();
+        assert!((0..3).all(|arm| actions.contains(&arm)));
+        assert!(actions.iter().filter(|&&a| a == 0).count() > 150)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_arms() {
+        let ucb = UcbAgent::new(vec![0.0; 2], Box::new(Ucb1Index::new()));
+        let cusum = CusumDetector::new(3, 0.0, 1.0, 2);
+        let _agent: ChangePointAgent<f64> = ChangePointAgent::new(
+            Box::new(ucb),
+            Box::new(cusum),
+            vec![0.0; 2],
+            Restart::Global,
+            0.0,
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use rand::RngCore;
+
+use crate::error::check;
+use crate::util::linalg::{dot, identity, mat_vec, quadratic_form, rank_one_update};
+use crate::RatelError;
+
+/// A trait for common members of the Agents that see a context before choosing an arm.
+pub trait ContextualAgent {
+    /// The action chosen by the Agent for the given context. Randomized Agents draw from the
+    /// given random number generator. Panics if the context does not have `dimension` values.
+    fn action(&self, context: &[f64], rng: &mut dyn RngCore) -> usize;
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize;
+
+    /// The number of features in each context.
+    fn dimension(&self) -> usize;
+
+    /// The Agent's current estimate of the value of a Bandit's arm in the given context.
+    fn current_estimate(&self, context: &[f64], arm: usize) -> f64;
+
+    /// Reset the Agent's history.
+    fn reset(&mut self);
+
+    /// Update the Agent's model of a Bandit arm based on a reward received in the given context.
+    /// Panics if the context does not have `dimension` values.
+    fn step(&mut self, context: &[f64], arm: usize, reward: f64);
+}
+
+/// Per-arm ridge regression of rewards on contexts, shared by the linear contextual Agents.
+///
+/// Keeps the inverse of each arm's regularized Gram matrix `A = lambda I + sum x x^T`, updated
+/// one context at a time, and the sum `b` of each arm's contexts weighted by their rewards, so the
+/// coefficient estimate is `A^-1 b`.
+pub(crate) struct LinearModel {
+    /// Weight of the ridge penalty, the diagonal of every Gram matrix before any update.
+    lambda: f64,
+
+    /// Inverse Gram matrix of each arm.
+    inverses: Vec<Vec<Vec<f64>>>,
+
+    /// Reward-weighted sum of each arm's contexts.
+    targets: Vec<Vec<f64>>,
+}
+
+impl LinearModel {
+    /// Initializes a model with no observations.
+    pub(crate) fn new(arms: usize, dimension: usize, lambda: f64) -> LinearModel {
+        LinearModel {
+            lambda,
+            inverses: vec![identity(dimension, 1.0 / lambda); arms],
+            targets: vec![vec![0.0; dimension]; arms],
+        }
+    }
+
+    /// Returns the number of arms.
+    pub(crate) fn arms(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns the number of features.
+    pub(crate) fn dimension(&self) -> usize {
+        self.targets[0].len()
+    }
+
+    /// Panics unless the context has one value per feature.
+    pub(crate) fn check_context(&self, context: &[f64]) {
+        assert_eq!(
+            context.len(),
+            self.dimension(),
+            "context must have one value per feature"
+        )
+    }
+
+    /// Returns the ridge estimate of an arm's coefficients.
+    pub(crate) fn theta(&self, arm: usize) -> Vec<f64> {
+        mat_vec(&self.inverses[arm], &self.targets[arm])
+    }
+
+    /// Returns the estimated mean reward of an arm in a context.
+    pub(crate) fn mean(&self, context: &[f64], arm: usize) -> f64 {
+        dot(&self.theta(arm), context)
+    }
+
+    /// Returns the width `sqrt(x^T A^-1 x)` of an arm's confidence ellipsoid in a context.
+    pub(crate) fn width(&self, context: &[f64], arm: usize) -> f64 {
+        quadratic_form(&self.inverses[arm], context).max(0.0).sqrt()
+    }
+
+    /// Forgets every observation.
+    pub(crate) fn reset(&mut self) {
+        *self = LinearModel::new(self.arms(), self.dimension(), self.lambda)
+    }
+
+    /// Adds a reward received from an arm in a context.
+    pub(crate) fn update(&mut self, context: &[f64], arm: usize, reward: f64) {
+        rank_one_update(&mut self.inverses[arm], context);
+        for (b, x) in self.targets[arm].iter_mut().zip(context) {
+            *b += reward * x
+        }
+    }
+}
+
+/// Checks the parameters shared by the linear contextual Agents.
+pub(crate) fn check_linear(arms: usize, dimension: usize, lambda: f64) -> Result<(), RatelError> {
+    if arms == 0 {
+        return Err(RatelError::Empty { name: "arms" });
+    }
+    if dimension == 0 {
+        return Err(RatelError::Empty { name: "dimension" });
+    }
+    check("lambda", lambda, "must be positive and finite", |l| {
+        l > 0.0 && l.is_finite()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::LinearModel;
+
+    #[test]
+    fn test_linear_model() {
+        let mut model = LinearModel::new(2, 2, 1.0);
+        model.update(&[1.0, 0.0], 0, 2.0);
+        model.update(&[0.0, 1.0], 0, -1.0);
+        assert_eq!(model.theta(0), vec![1.0, -0.5]);
+        assert_approx_eq!(model.mean(&[2.0, 2.0], 0), 1.0);
+        assert_approx_eq!(model.width(&[1.0, 1.0], 0), 1.0);
+        assert_approx_eq!(model.width(&[1.0, 1.0], 1), 2f64.sqrt());
+        model.reset();
+        assert_eq!(model.theta(0), vec![0.0; 2])
+    }
+}
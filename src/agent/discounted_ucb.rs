@@ -0,0 +1,199 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::error::check;
+use crate::{RatelError, UcbIndex};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows the Discounted UCB Algorithm of Kocsis and Szepesvári (2006).
+///
+/// Weights a reward received `s` rounds ago by `gamma^s`, so the Agent gradually forgets old
+/// rewards and tracks arms whose means change. Pulls every arm once, in order, and from then on
+/// the arm with the highest upper confidence bound, computed by the index from the discounted
+/// mean, variance and count of each arm, with the discounted count of all pulls as the total.
+pub struct DiscountedUcbAgent<T> {
+    /// The initial guess of the Bandit arm values, the estimate of an arm never pulled.
+    q_init: Vec<f64>,
+
+    /// Factor every past reward is weighted by after each round.
+    gamma: f64,
+
+    /// Discounted sum of each arm's rewards.
+    sums: Vec<f64>,
+
+    /// Discounted sum of each arm's squared rewards.
+    squares: Vec<f64>,
+
+    /// Discounted number of times each arm was pulled.
+    counts: Vec<f64>,
+
+    /// The rule computing each arm's upper confidence bound.
+    index: Box<dyn UcbIndex>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for DiscountedUcbAgent<T> {
+    /// The action chosen by the Agent. The first arm not yet pulled, or the arm with the highest
+    /// upper confidence bound once every arm has been pulled.
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
+        match self.counts.iter().position(|&n| n == 0.0) {
+            Some(arm) => arm,
+            None => {
+                let total = self.counts.iter().sum();
+                (0..self.counts.len())
+                    .map(|arm| {
+                        let (mean, variance) = self.moments(arm);
+                        self.index
+                            .index(mean, variance, self.counts[arm], total, self.counts.len())
+                    })
+                    .collect::<Vec<f64>>()
+                    .arg_max()
+            }
+        }
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_init.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm, the discounted mean of its
+    /// rewards.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.moments(arm).0
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        let arms = q_init.len();
+        self.q_init = q_init.to_owned();
+        self.sums = vec![0.0; arms];
+        self.squares = vec![0.0; arms];
+        self.counts = vec![0.0; arms]
+    }
+
+    /// Forget the rewards of a Bandit arm, which is pulled again before any other arm.
+    fn reset_arm(&mut self, arm: usize, q_init: f64) -> bool {
+        self.q_init[arm] = q_init;
+        self.sums[arm] = 0.0;
+        self.squares[arm] = 0.0;
+        self.counts[arm] = 0.0;
+        true
+    }
+
+    /// Discount every arm's statistics and add a given reward to those of a Bandit arm.
+    fn step(&mut self, arm: usize, reward: T) {
+        let (x, gamma) = (reward.to_f64().unwrap(), self.gamma);
+        for stats in [&mut self.sums, &mut self.squares, &mut self.counts] {
+            stats.iter_mut().for_each(|s| *s *= gamma)
+        }
+        self.sums[arm] += x;
+        self.squares[arm] += x * x;
+        self.counts[arm] += 1.0
+    }
+}
+
+impl<T> DiscountedUcbAgent<T> {
+    /// Initializes a new Discounted UCB agent. Panics if the parameters are invalid; see
+    /// `try_new`.
+    pub fn new(q_init: Vec<f64>, gamma: f64, index: Box<dyn UcbIndex>) -> DiscountedUcbAgent<T> {
+        DiscountedUcbAgent::try_new(q_init, gamma, index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Discounted UCB agent, failing if there are no arms or the discount
+    /// factor `gamma` is not in `(0, 1]`. A factor of 1 never forgets, like `UcbAgent`.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        gamma: f64,
+        index: Box<dyn UcbIndex>,
+    ) -> Result<DiscountedUcbAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check("gamma", gamma, "must be in (0, 1]", |g| g > 0.0 && g <= 1.0)?;
+        let arms = q_init.len();
+        Ok(DiscountedUcbAgent {
+            q_init,
+            gamma,
+            sums: vec![0.0; arms],
+            squares: vec![0.0; arms],
+            counts: vec![0.0; arms],
+            index,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the discounted number of times each arm was pulled.
+    pub fn counts(&self) -> &Vec<f64> {
+        &self.counts
+    }
+
+    /// Returns the discounted mean and variance of an arm's rewards, or its initial guess and
+    /// zero if it was never pulled.
+    fn moments(&self, arm: usize) -> (f64, f64) {
+        let n = self.counts[arm];
+        if n == 0.0 {
+            (self.q_init[arm], 0.0)
+        } else {
+            let mean = self.sums[arm] / n;
+            (mean, (self.squares[arm] / n - mean * mean).max(0.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::Ucb1Index;
+
+    use super::{Agent, DiscountedUcbAgent};
+
+    #[test]
+    fn test_step() {
+        let mut d: DiscountedUcbAgent<f64> =
+            DiscountedUcbAgent::new(vec![0.0; 2], 0.5, Box::new(Ucb1Index::new()));
+        d.step(0, 2.0);
+        d.step(1, 1.0);
+        d.step(0, 4.0);
+        assert_eq!(d.counts(), &vec![1.25, 0.5]);
+        assert_approx_eq!(d.current_estimate(0), 4.5 / 1.25);
+        assert_approx_eq!(d.current_estimate(1), 1.0);
+        assert_approx_eq!(d.moments(1).1, 0.0)
+    }
+
+    #[test]
+    fn test_action() {
+        let mut d: DiscountedUcbAgent<f64> =
+            DiscountedUcbAgent::new(vec![0.0; 2], 0.5, Box::new(Ucb1Index::new()));
+        assert_eq!(d.action(&mut thread_rng()), 0);
+        d.step(0, 1.0);
+        assert_eq!(d.action(&mut thread_rng()), 1);
+        d.step(1, 0.0);
+        for _ in 0..5 {
+            d.step(0, 1.0)
+        }
+        assert_eq!(d.action(&mut thread_rng()), 1)
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut d: DiscountedUcbAgent<u32> =
+            DiscountedUcbAgent::new(vec![0.0; 3], 0.9, Box::new(Ucb1Index::new()));
+        d.step(1, 3);
+        d.reset(&[1.0, 2.0, 3.0]);
+        assert_eq!(d.counts(), &vec![0.0; 3]);
+        assert_eq!(d.current_estimate(1), 2.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_gamma() {
+        let _d: DiscountedUcbAgent<u32> =
+            DiscountedUcbAgent::new(vec![0.0; 3], 1.5, Box::new(Ucb1Index::new()));
+    }
+}
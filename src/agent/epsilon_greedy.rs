@@ -2,11 +2,12 @@ use std::marker::PhantomData;
 
 use num_traits::ToPrimitive;
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::uniform::Uniform;
 
 use crate::agent::agent::update;
-use crate::Stepper;
+use crate::error::check;
+use crate::{ConstantSchedule, RatelError, Schedule, Stepper};
 
 use super::{Agent, ArgBounds};
 
@@ -14,7 +15,7 @@ use super::{Agent, ArgBounds};
 ///
 /// A fixed (usually small) percentage of the
 /// time it picks a random arm; the rest of the time it picks the arm with the highest expected
-/// reward.
+/// reward. The percentage can also follow a schedule that decays over time.
 pub struct EpsilonGreedyAgent<T> {
     /// The current estimates of the Bandit arm values.
     q_star: Vec<f64>,
@@ -22,8 +23,11 @@ pub struct EpsilonGreedyAgent<T> {
     /// The Agent's rule for step size updates.
     stepper: Box<dyn Stepper>,
 
-    /// The fraction of times a random arm is chosen.
-    epsilon: f64,
+    /// The fraction of times a random arm is chosen in each round.
+    epsilon: Box<dyn Schedule>,
+
+    /// Total number of rounds the Agent has played.
+    rounds: u32,
 
     /// A random uniform distribution to determine if a random action should be chosen.
     uniform: Uniform<f64>,
@@ -36,9 +40,9 @@ pub struct EpsilonGreedyAgent<T> {
 impl<T: ToPrimitive> Agent<T> for EpsilonGreedyAgent<T> {
     /// The action chosen by the Agent. A random action with probability `epsilon` and the greedy
     /// action otherwise.
-    fn action(&self) -> usize {
-        if self.uniform.sample(&mut thread_rng()) < self.epsilon {
-            self.pick_arm.sample(&mut thread_rng())
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        if self.uniform.sample(rng) < self.epsilon() {
+            self.pick_arm.sample(rng)
         } else {
             self.q_star.arg_max()
         }
@@ -57,84 +61,159 @@ impl<T: ToPrimitive> Agent<T> for EpsilonGreedyAgent<T> {
     /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
     fn reset(&mut self, q_init: &[f64]) {
         self.q_star = q_init.to_owned();
+        self.rounds = 0;
         self.stepper.reset()
     }
 
     /// Update the Agent's estimate of a Bandit arm based on a given reward.
     fn step(&mut self, arm: usize, reward: T) {
-        self.q_star[arm] += update(&mut self.stepper, &self.q_star, arm, reward)
+        self.q_star[arm] += update(&mut self.stepper, &self.q_star, arm, reward);
+        self.rounds += 1
     }
 }
 
 impl<T> EpsilonGreedyAgent<T> {
-    /// Initializes a new Epsilon-Greedy agent.
+    /// Initializes a new Epsilon-Greedy agent. Panics if `epsilon` is invalid; see `try_new`.
     pub fn new(q_init: Vec<f64>, stepper: Box<dyn Stepper>, epsilon: f64) -> EpsilonGreedyAgent<T> {
-        assert!(epsilon > 0.0);
-        assert!(epsilon < 1.0);
+        EpsilonGreedyAgent::try_new(q_init, stepper, epsilon).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Epsilon-Greedy agent, failing if there are no arms or `epsilon` is not
+    /// strictly between 0 and 1.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        stepper: Box<dyn Stepper>,
+        epsilon: f64,
+    ) -> Result<EpsilonGreedyAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check("epsilon", epsilon, "must be between 0 and 1", |e| {
+            e > 0.0 && e < 1.0
+        })?;
+        let epsilon = ConstantSchedule::try_new(epsilon)?;
+        EpsilonGreedyAgent::try_with_schedule(q_init, stepper, Box::new(epsilon))
+    }
+
+    /// Initializes a new Epsilon-Greedy agent whose epsilon in round `t` is the schedule's value
+    /// in that round. Values above 1 always pick a random arm. Panics if there are no arms.
+    pub fn with_schedule(
+        q_init: Vec<f64>,
+        stepper: Box<dyn Stepper>,
+        epsilon: Box<dyn Schedule>,
+    ) -> EpsilonGreedyAgent<T> {
+        EpsilonGreedyAgent::try_with_schedule(q_init, stepper, epsilon)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Epsilon-Greedy agent with an epsilon schedule, failing if there are no
+    /// arms.
+    pub fn try_with_schedule(
+        q_init: Vec<f64>,
+        stepper: Box<dyn Stepper>,
+        epsilon: Box<dyn Schedule>,
+    ) -> Result<EpsilonGreedyAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
         let l = q_init.len();
-        EpsilonGreedyAgent {
+        Ok(EpsilonGreedyAgent {
             q_star: q_init,
             stepper,
             epsilon,
+            rounds: 0,
             uniform: Uniform::new(0.0, 1.0),
             pick_arm: Uniform::new(0usize, l),
             phantom: PhantomData,
-        }
+        })
+    }
+
+    /// Returns the probability of picking a random arm in the current round.
+    pub fn epsilon(&self) -> f64 {
+        self.epsilon.value(self.rounds + 1)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::HarmonicStepper;
+    use crate::{HarmonicStepper, InverseSchedule, RatelError};
 
     use super::{Agent, EpsilonGreedyAgent};
 
     #[test]
     fn test_new() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let eps = 0.1;
         let epsilon: EpsilonGreedyAgent<u32> =
-            EpsilonGreedyAgent::new(Q_INIT, Box::new(stepper), eps);
-        assert_eq!(epsilon.epsilon, eps);
+            EpsilonGreedyAgent::new(q_init, Box::new(stepper), eps);
+        assert_eq!(epsilon.epsilon(), eps);
         assert_eq!(epsilon.q_star, vec![0.5, 0.61, 0.7, 0.12, 0.37])
     }
 
     #[test]
     #[should_panic]
     fn test_new_big_epsilon() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let eps = 1.3;
-        let epsilon: EpsilonGreedyAgent<u32> =
-            EpsilonGreedyAgent::new(Q_INIT, Box::new(stepper), eps);
+        let _epsilon: EpsilonGreedyAgent<u32> =
+            EpsilonGreedyAgent::new(q_init, Box::new(stepper), eps);
     }
 
     #[test]
     #[should_panic]
     fn test_new_small_epsilon() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let eps = -0.3;
-        let epsilon: EpsilonGreedyAgent<u32> =
-            EpsilonGreedyAgent::new(Q_INIT, Box::new(stepper), eps);
+        let _epsilon: EpsilonGreedyAgent<u32> =
+            EpsilonGreedyAgent::new(q_init, Box::new(stepper), eps);
+    }
+
+    #[test]
+    fn test_try_new() {
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let epsilon: Result<EpsilonGreedyAgent<u32>, RatelError> =
+            EpsilonGreedyAgent::try_new(q_init, Box::new(stepper), f64::NAN);
+        assert_eq!(
+            epsilon.err().unwrap().to_string(),
+            "epsilon = NaN must be between 0 and 1"
+        )
+    }
+
+    #[test]
+    fn test_schedule() {
+        let q_init = vec![0.5, 0.61, 0.7];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let mut epsilon: EpsilonGreedyAgent<u32> = EpsilonGreedyAgent::with_schedule(
+            q_init.clone(),
+            Box::new(stepper),
+            Box::new(InverseSchedule::new(2.0)),
+        );
+        assert_eq!(epsilon.epsilon(), 2.0);
+        (0..3).for_each(|arm| epsilon.step(arm, 1));
+        assert_eq!(epsilon.epsilon(), 0.5);
+        epsilon.reset(&q_init);
+        assert_eq!(epsilon.epsilon(), 2.0)
     }
 
     #[test]
     fn test_q_star() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let epsilon: EpsilonGreedyAgent<u32> =
-            EpsilonGreedyAgent::new(Q_INIT, Box::new(stepper), 0.1);
+            EpsilonGreedyAgent::new(q_init, Box::new(stepper), 0.1);
         assert_eq!(epsilon.q_star, vec![0.5, 0.61, 0.7, 0.12, 0.37])
     }
 
     #[test]
     fn test_reset() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let mut epsilon: EpsilonGreedyAgent<u32> =
-            EpsilonGreedyAgent::new(Q_INIT, Box::new(stepper), 0.1);
+            EpsilonGreedyAgent::new(q_init, Box::new(stepper), 0.1);
         let new_q = vec![0.01, 0.86, 0.43, 0.65, 0.66];
         epsilon.reset(&new_q);
         assert_eq!(epsilon.q_star, new_q)
@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+
+use crate::agent::agent::{pick, softmax};
+use crate::error::check;
+use crate::{RatelError, RewardScale};
+
+use super::Agent;
+
+/// Agent that follows the EXP3 Algorithm of Auer, Cesa-Bianchi, Freund and Schapire (2002).
+///
+/// Keeps an exponential weight for each arm and picks arms from the normalized weights mixed with
+/// a uniform distribution, with mixing rate `gamma`. After each reward, the weight of the arm
+/// pulled grows with the importance-weighted reward, the reward divided by the probability of
+/// the arm. Makes no assumptions about how rewards are generated, so it also plays adversarial
+/// reward sequences, but rewards must be scaled to `[0, 1]`.
+pub struct Exp3Agent<T> {
+    /// The logarithms of the arm weights.
+    log_weights: Vec<f64>,
+
+    /// Probability of picking an arm uniformly at random.
+    gamma: f64,
+
+    /// Maps rewards onto `[0, 1]`.
+    scale: RewardScale,
+
+    /// A random uniform distribution to pick an arm from the weights.
+    uniform: Uniform<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for Exp3Agent<T> {
+    /// The action chosen by the Agent. An arm drawn from the mixed weights.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        pick(&self.probabilities(), self.uniform.sample(rng))
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.log_weights.len()
+    }
+
+    /// The probability that the Agent picks a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.probabilities()[arm]
+    }
+
+    /// Reset the Agent's weights. Only the number of arms in the initial guess is used.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.log_weights = vec![0.0; q_init.len()]
+    }
+
+    /// Update the weight of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = self.scale.scale(reward.to_f64().unwrap());
+        let p = self.probabilities()[arm];
+        let arms = self.log_weights.len() as f64;
+        self.log_weights[arm] += self.gamma * x / (p * arms)
+    }
+}
+
+impl<T> Exp3Agent<T> {
+    /// Initializes a new EXP3 agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(arms: usize, gamma: f64, scale: RewardScale) -> Exp3Agent<T> {
+        Exp3Agent::try_new(arms, gamma, scale).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new EXP3 agent with equal weights on every arm, failing if there are no arms
+    /// or `gamma` is not in `(0, 1]`.
+    pub fn try_new(
+        arms: usize,
+        gamma: f64,
+        scale: RewardScale,
+    ) -> Result<Exp3Agent<T>, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("gamma", gamma, "must be in (0, 1]", |g| g > 0.0 && g <= 1.0)?;
+        Ok(Exp3Agent {
+            log_weights: vec![0.0; arms],
+            gamma,
+            scale,
+            uniform: Uniform::new(0.0, 1.0),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the logarithms of the arm weights.
+    pub fn log_weights(&self) -> &Vec<f64> {
+        &self.log_weights
+    }
+
+    /// Returns the probability of picking each arm.
+    pub fn probabilities(&self) -> Vec<f64> {
+        let arms = self.log_weights.len() as f64;
+        softmax(&self.log_weights, 1.0)
+            .iter()
+            .map(|p| (1.0 - self.gamma) * p + self.gamma / arms)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::RewardScale;
+
+    use super::{Agent, Exp3Agent};
+
+    #[test]
+    fn test_probabilities() {
+        let exp3: Exp3Agent<u32> = Exp3Agent::new(4, 0.2, RewardScale::default());
+        assert_eq!(exp3.probabilities(), vec![0.25; 4]);
+        assert_eq!(exp3.arms(), 4)
+    }
+
+    #[test]
+    fn test_step() {
+        let mut exp3: Exp3Agent<f64> = Exp3Agent::new(2, 0.5, RewardScale::new(0.0, 2.0));
+        exp3.step(0, 1.0);
+        assert_approx_eq!(exp3.log_weights()[0], 0.25);
+        assert_eq!(exp3.log_weights()[1], 0.0);
+        let p = 0.5 * 0.25f64.exp() / (0.25f64.exp() + 1.0) + 0.25;
+        assert_approx_eq!(exp3.current_estimate(0), p);
+        exp3.step(1, 5.0);
+        assert_approx_eq!(exp3.log_weights()[1], 0.25 / (1.0 - p))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut exp3: Exp3Agent<f64> = Exp3Agent::new(3, 0.1, RewardScale::default());
+        exp3.step(2, 1.0);
+        exp3.reset(&[0.0; 3]);
+        assert_eq!(exp3.log_weights(), &vec![0.0; 3])
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_gamma() {
+        let _exp3: Exp3Agent<u32> = Exp3Agent::new(3, 0.0, RewardScale::default());
+    }
+}
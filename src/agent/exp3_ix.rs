@@ -0,0 +1,158 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+
+use crate::agent::agent::{pick, softmax};
+use crate::error::check;
+use crate::{RatelError, RewardScale};
+
+use super::Agent;
+
+/// Agent that follows the EXP3-IX Algorithm of Kocák, Neu, Valko and Munos (2014).
+///
+/// Like EXP3, but explores implicitly: arms are picked from the normalized weights alone, and the
+/// loss of the arm pulled, one minus its scaled reward, is divided by its probability plus
+/// `gamma`. The slightly biased loss estimates have a much smaller variance, which bounds the
+/// regret with high probability as well as in expectation.
+pub struct Exp3IxAgent<T> {
+    /// The logarithms of the arm weights.
+    log_weights: Vec<f64>,
+
+    /// Learning rate of the weight updates.
+    eta: f64,
+
+    /// Implicit exploration added to the probabilities the losses are divided by.
+    gamma: f64,
+
+    /// Maps rewards onto `[0, 1]`.
+    scale: RewardScale,
+
+    /// A random uniform distribution to pick an arm from the weights.
+    uniform: Uniform<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for Exp3IxAgent<T> {
+    /// The action chosen by the Agent. An arm drawn from the normalized weights.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        pick(&self.probabilities(), self.uniform.sample(rng))
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.log_weights.len()
+    }
+
+    /// The probability that the Agent picks a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.probabilities()[arm]
+    }
+
+    /// Reset the Agent's weights. Only the number of arms in the initial guess is used.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.log_weights = vec![0.0; q_init.len()]
+    }
+
+    /// Update the weight of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        let loss = 1.0 - self.scale.scale(reward.to_f64().unwrap());
+        let p = self.probabilities()[arm];
+        self.log_weights[arm] -= self.eta * loss / (p + self.gamma)
+    }
+}
+
+impl<T> Exp3IxAgent<T> {
+    /// Initializes a new EXP3-IX agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(arms: usize, eta: f64, gamma: f64, scale: RewardScale) -> Exp3IxAgent<T> {
+        Exp3IxAgent::try_new(arms, eta, gamma, scale).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new EXP3-IX agent with equal weights on every arm, failing if there are no
+    /// arms, `eta` is not positive or `gamma` is negative.
+    ///
+    /// Kocák et al. suggest `eta = sqrt(2 ln(K) / (K n))` for `K` arms and `n` rounds, and
+    /// `gamma = eta / 2`.
+    pub fn try_new(
+        arms: usize,
+        eta: f64,
+        gamma: f64,
+        scale: RewardScale,
+    ) -> Result<Exp3IxAgent<T>, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("eta", eta, "must be positive and finite", |e| {
+            e > 0.0 && e.is_finite()
+        })?;
+        check("gamma", gamma, "must be non-negative and finite", |g| {
+            g >= 0.0 && g.is_finite()
+        })?;
+        Ok(Exp3IxAgent {
+            log_weights: vec![0.0; arms],
+            eta,
+            gamma,
+            scale,
+            uniform: Uniform::new(0.0, 1.0),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the logarithms of the arm weights.
+    pub fn log_weights(&self) -> &Vec<f64> {
+        &self.log_weights
+    }
+
+    /// Returns the probability of picking each arm.
+    pub fn probabilities(&self) -> Vec<f64> {
+        softmax(&self.log_weights, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::RewardScale;
+
+    use super::{Agent, Exp3IxAgent};
+
+    #[test]
+    fn test_step() {
+        let mut ix: Exp3IxAgent<f64> = Exp3IxAgent::new(2, 0.2, 0.5, RewardScale::new(-1.0, 1.0));
+        ix.step(0, 0.0);
+        assert_approx_eq!(ix.log_weights()[0], -0.1);
+        ix.step(1, 1.0);
+        assert_eq!(ix.log_weights()[1], 0.0);
+        assert!(ix.current_estimate(1) > 0.5)
+    }
+
+    #[test]
+    fn test_action() {
+        let mut ix: Exp3IxAgent<u32> = Exp3IxAgent::new(3, 1.0, 0.0, RewardScale::default());
+        for _ in 0..20 {
+            ix.step(0, 0);
+            ix.step(2, 0)
+        }
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..100).all(|_| ix.action(&mut rng) == 1))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ix: Exp3IxAgent<u32> = Exp3IxAgent::new(2, 0.1, 0.05, RewardScale::default());
+        ix.step(1, 0);
+        ix.reset(&[0.0; 2]);
+        assert_eq!(ix.probabilities(), vec![0.5; 2])
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_eta() {
+        let _ix: Exp3IxAgent<u32> = Exp3IxAgent::new(2, -0.1, 0.05, RewardScale::default());
+    }
+}
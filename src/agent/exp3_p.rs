@@ -0,0 +1,178 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+
+use crate::agent::agent::{pick, softmax};
+use crate::error::check;
+use crate::{RatelError, RewardScale};
+
+use super::Agent;
+
+/// Agent that follows the EXP3.P Algorithm of Auer, Cesa-Bianchi, Freund and Schapire (2002).
+///
+/// Like EXP3, but every arm's weight also grows by a confidence bonus inversely proportional to
+/// its probability, so rarely picked arms are not written off. With the parameters set from a
+/// horizon of `n` rounds and a confidence `delta`, the regret against the best arm is
+/// `O(sqrt(K n ln(K n / delta)))` with probability at least `1 - delta`.
+pub struct Exp3PAgent<T> {
+    /// The logarithms of the arm weights.
+    log_weights: Vec<f64>,
+
+    /// Multiple of the confidence bonus.
+    alpha: f64,
+
+    /// Probability of picking an arm uniformly at random.
+    gamma: f64,
+
+    /// Number of rounds the parameters are tuned for.
+    horizon: u32,
+
+    /// Maps rewards onto `[0, 1]`.
+    scale: RewardScale,
+
+    /// A random uniform distribution to pick an arm from the weights.
+    uniform: Uniform<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for Exp3PAgent<T> {
+    /// The action chosen by the Agent. An arm drawn from the mixed weights.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        pick(&self.probabilities(), self.uniform.sample(rng))
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.log_weights.len()
+    }
+
+    /// The probability that the Agent picks a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.probabilities()[arm]
+    }
+
+    /// Reset the Agent's weights. Only the number of arms in the initial guess is used.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.log_weights = vec![0.0; q_init.len()]
+    }
+
+    /// Update the weights of every arm based on the reward of a given Bandit arm.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = self.scale.scale(reward.to_f64().unwrap());
+        let arms = self.log_weights.len() as f64;
+        let bonus = self.alpha / (arms * f64::from(self.horizon)).sqrt();
+        let rate = self.gamma / (3.0 * arms);
+        let probabilities = self.probabilities();
+        for (a, (w, p)) in self.log_weights.iter_mut().zip(probabilities).enumerate() {
+            let estimate = if a == arm { x / p } else { 0.0 };
+            *w += rate * (estimate + bonus / p)
+        }
+    }
+}
+
+impl<T> Exp3PAgent<T> {
+    /// Initializes a new EXP3.P agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(arms: usize, horizon: u32, delta: f64, scale: RewardScale) -> Exp3PAgent<T> {
+        Exp3PAgent::try_new(arms, horizon, delta, scale).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new EXP3.P agent with equal weights on every arm, tuned for `horizon`
+    /// rounds and a failure probability `delta`. Fails if there are no arms, the horizon is zero
+    /// or `delta` is not in `(0, 1)`.
+    ///
+    /// Following Auer et al., `alpha = 2 sqrt(ln(K n / delta))` and
+    /// `gamma = min(3 / 5, 2 sqrt(3 K ln(K) / (5 n)))` for `K` arms and a horizon of `n`.
+    pub fn try_new(
+        arms: usize,
+        horizon: u32,
+        delta: f64,
+        scale: RewardScale,
+    ) -> Result<Exp3PAgent<T>, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("horizon", horizon, "must be positive", |n| n > 0.0)?;
+        check("delta", delta, "must be in (0, 1)", |d| d > 0.0 && d < 1.0)?;
+        let k = arms as f64;
+        let n = f64::from(horizon);
+        Ok(Exp3PAgent {
+            log_weights: vec![0.0; arms],
+            alpha: 2.0 * (k * n / delta).ln().sqrt(),
+            gamma: f64::min(0.6, 2.0 * (0.6 * k * k.ln() / n).sqrt()),
+            horizon,
+            scale,
+            uniform: Uniform::new(0.0, 1.0),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the multiple of the confidence bonus.
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the probability of picking an arm uniformly at random.
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// Returns a reference to the logarithms of the arm weights.
+    pub fn log_weights(&self) -> &Vec<f64> {
+        &self.log_weights
+    }
+
+    /// Returns the probability of picking each arm.
+    pub fn probabilities(&self) -> Vec<f64> {
+        let arms = self.log_weights.len() as f64;
+        softmax(&self.log_weights, 1.0)
+            .iter()
+            .map(|p| (1.0 - self.gamma) * p + self.gamma / arms)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::RewardScale;
+
+    use super::{Agent, Exp3PAgent};
+
+    #[test]
+    fn test_new() {
+        let exp3p: Exp3PAgent<u32> = Exp3PAgent::new(2, 1000, 0.05, RewardScale::default());
+        assert_approx_eq!(exp3p.alpha(), 2.0 * 40000f64.ln().sqrt());
+        assert_approx_eq!(exp3p.gamma(), 2.0 * (1.2 * 2f64.ln() / 1000.0).sqrt());
+        let short: Exp3PAgent<u32> = Exp3PAgent::new(10, 10, 0.05, RewardScale::default());
+        assert_eq!(short.gamma(), 0.6)
+    }
+
+    #[test]
+    fn test_step() {
+        let mut exp3p: Exp3PAgent<u32> = Exp3PAgent::new(2, 8, 0.5, RewardScale::default());
+        let (alpha, gamma) = (exp3p.alpha(), exp3p.gamma());
+        exp3p.step(1, 1);
+        let bonus = gamma / 6.0 * alpha / 4.0 / 0.5;
+        assert_approx_eq!(exp3p.log_weights()[0], bonus);
+        assert_approx_eq!(exp3p.log_weights()[1], bonus + gamma / 6.0 / 0.5);
+        assert!(exp3p.current_estimate(1) > 0.5)
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut exp3p: Exp3PAgent<u32> = Exp3PAgent::new(3, 100, 0.1, RewardScale::default());
+        exp3p.step(0, 1);
+        exp3p.reset(&[0.0; 3]);
+        assert_eq!(exp3p.log_weights(), &vec![0.0; 3])
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_delta() {
+        let _exp3p: Exp3PAgent<u32> = Exp3PAgent::new(3, 100, 1.0, RewardScale::default());
+    }
+}
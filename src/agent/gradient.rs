@@ -0,0 +1,183 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+
+use crate::agent::agent::{pick, softmax};
+use crate::error::check;
+use crate::{RatelError, Stepper};
+
+use super::Agent;
+
+/// Agent that follows the Gradient Bandit Algorithm of Sutton and Barto.
+///
+/// Keeps a numerical preference for each arm and picks arms at random from the softmax of the
+/// preferences. After each reward, the preference of the arm pulled rises and the others fall in
+/// proportion to how far the reward beats a baseline, the running average of all rewards.
+pub struct GradientAgent<T> {
+    /// The current preferences for the Bandit arms.
+    preferences: Vec<f64>,
+
+    /// Step size of the preference updates.
+    alpha: f64,
+
+    /// The running average reward that rewards are compared against.
+    baseline: f64,
+
+    /// The rule for baseline step size updates. Only arm 0 of the stepper is used.
+    stepper: Box<dyn Stepper>,
+
+    /// A random uniform distribution to pick an arm from the softmax policy.
+    uniform: Uniform<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for GradientAgent<T> {
+    /// The action chosen by the Agent. An arm drawn from the softmax of the preferences.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        pick(&self.probabilities(), self.uniform.sample(rng))
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.preferences.len()
+    }
+
+    /// The probability that the Agent picks a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.probabilities()[arm]
+    }
+
+    /// Reset the Agent's history and set its preferences to the initial guess of the Bandit's arm
+    /// values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.preferences = q_init.to_owned();
+        self.baseline = 0.0;
+        self.stepper.reset()
+    }
+
+    /// Update the baseline and the Agent's preferences based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        let r = reward.to_f64().unwrap();
+        self.baseline += self.stepper.step(0) * (r - self.baseline);
+        let advantage = self.alpha * (r - self.baseline);
+        let probabilities = self.probabilities();
+        for (a, (h, p)) in self.preferences.iter_mut().zip(probabilities).enumerate() {
+            if a == arm {
+                *h += advantage * (1.0 - p)
+            } else {
+                *h -= advantage * p
+            }
+        }
+    }
+}
+
+impl<T> GradientAgent<T> {
+    /// Initializes a new Gradient agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(q_init: Vec<f64>, alpha: f64, stepper: Box<dyn Stepper>) -> GradientAgent<T> {
+        GradientAgent::try_new(q_init, alpha, stepper).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Gradient agent whose initial preferences are the initial guess, failing
+    /// if there are no arms or the step size `alpha` is not positive.
+    ///
+    /// The baseline's step sizes come from arm 0 of the stepper, so a `HarmonicStepper` makes the
+    /// baseline the average of all rewards and a `ConstantStepper` weights recent rewards more.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        alpha: f64,
+        stepper: Box<dyn Stepper>,
+    ) -> Result<GradientAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check("alpha", alpha, "must be positive and finite", |a| {
+            a > 0.0 && a.is_finite()
+        })?;
+        Ok(GradientAgent {
+            preferences: q_init,
+            alpha,
+            baseline: 0.0,
+            stepper,
+            uniform: Uniform::new(0.0, 1.0),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the current baseline.
+    pub fn baseline(&self) -> f64 {
+        self.baseline
+    }
+
+    /// Returns a reference to the current preferences for the Bandit arms.
+    pub fn preferences(&self) -> &Vec<f64> {
+        &self.preferences
+    }
+
+    /// Returns the probability of picking each arm, the softmax of the preferences.
+    pub fn probabilities(&self) -> Vec<f64> {
+        softmax(&self.preferences, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{ConstantStepper, HarmonicStepper};
+
+    use super::{Agent, GradientAgent};
+
+    #[test]
+    fn test_probabilities() {
+        let q_init = vec![0.0, 2f64.ln(), 0.0];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let gradient: GradientAgent<u32> = GradientAgent::new(q_init, 0.1, Box::new(stepper));
+        assert_approx_eq!(gradient.current_estimate(0), 0.25);
+        assert_approx_eq!(gradient.current_estimate(1), 0.5);
+        assert_approx_eq!(gradient.probabilities().iter().sum::<f64>(), 1.0)
+    }
+
+    #[test]
+    fn test_action() {
+        let q_init = vec![0.0, 50.0, 0.0];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let gradient: GradientAgent<u32> = GradientAgent::new(q_init, 0.1, Box::new(stepper));
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..100).all(|_| gradient.action(&mut rng) == 1))
+    }
+
+    #[test]
+    fn test_step() {
+        let stepper = ConstantStepper::new(0.5);
+        let mut gradient: GradientAgent<f64> =
+            GradientAgent::new(vec![0.0; 2], 0.2, Box::new(stepper));
+        gradient.step(0, 4.0);
+        assert_approx_eq!(gradient.baseline(), 2.0);
+        assert_approx_eq!(gradient.preferences()[0], 0.2);
+        assert_approx_eq!(gradient.preferences()[1], -0.2)
+    }
+
+    #[test]
+    fn test_reset() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let mut gradient: GradientAgent<f64> =
+            GradientAgent::new(vec![0.0; 2], 0.2, Box::new(stepper));
+        gradient.step(1, 3.0);
+        gradient.reset(&[1.0, 2.0]);
+        assert_eq!(gradient.preferences(), &vec![1.0, 2.0]);
+        assert_eq!(gradient.baseline(), 0.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_alpha() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let _gradient: GradientAgent<u32> =
+            GradientAgent::new(vec![0.0; 2], 0.0, Box::new(stepper));
+    }
+}
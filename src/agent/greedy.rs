@@ -1,6 +1,7 @@
 use std::marker::PhantomData;
 
 use num_traits::ToPrimitive;
+use rand::RngCore;
 
 use crate::agent::agent::update;
 use crate::Stepper;
@@ -21,7 +22,7 @@ pub struct GreedyAgent<T> {
 
 impl<T: ToPrimitive> Agent<T> for GreedyAgent<T> {
     /// The action chosen by the Agent. Picks the arm with the highest estimated return.
-    fn action(&self) -> usize {
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
         self.q_star.arg_max()
     }
 
@@ -60,31 +61,33 @@ impl<T> GreedyAgent<T> {
 
 #[cfg(test)]
 mod tests {
+    use rand::thread_rng;
+
     use crate::HarmonicStepper;
 
     use super::{Agent, GreedyAgent};
 
     #[test]
     fn test_action() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
-        let greedy: GreedyAgent<u32> = GreedyAgent::new(Q_INIT, Box::new(stepper));
-        assert_eq!(greedy.action(), 2)
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let greedy: GreedyAgent<u32> = GreedyAgent::new(q_init, Box::new(stepper));
+        assert_eq!(greedy.action(&mut thread_rng()), 2)
     }
 
     #[test]
     fn test_q_star() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let stepper = HarmonicStepper::new(1, Q_INIT.len());
-        let greedy: GreedyAgent<u32> = GreedyAgent::new(Q_INIT, Box::new(stepper));
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let greedy: GreedyAgent<u32> = GreedyAgent::new(q_init, Box::new(stepper));
         assert_eq!(greedy.q_star, vec![0.5, 0.61, 0.7, 0.12, 0.37])
     }
 
     #[test]
     fn test_reset() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let mut stepper = HarmonicStepper::new(1, Q_INIT.len());
-        let mut greedy: GreedyAgent<u32> = GreedyAgent::new(Q_INIT.to_vec(), Box::new(stepper));
+        let q_init = [0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let mut greedy: GreedyAgent<u32> = GreedyAgent::new(q_init.to_vec(), Box::new(stepper));
         let new_q = vec![0.01, 0.86, 0.43, 0.65, 0.66];
         greedy.reset(&new_q);
         assert_eq!(greedy.q_star, new_q)
@@ -0,0 +1,197 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::error::{check, check_len};
+use crate::{Divergence, RatelError};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows the KL-UCB Algorithm of Garivier and Cappé (2011).
+///
+/// Pulls every arm once, in order, and from then on chooses the arm with the highest upper
+/// confidence bound: the largest mean `q` whose divergence from the arm's sample mean, times the
+/// number of pulls `n`, is at most `ln(t) + c ln(ln(t))` after `t` rounds.
+pub struct KlUcbAgent<T> {
+    /// The current estimates of the Bandit arm values. The initial guess until an arm is pulled,
+    /// the sample mean of its rewards afterwards.
+    q_star: Vec<f64>,
+
+    /// Multiple of `ln(ln(t))` added to the exploration level.
+    c: f64,
+
+    /// Total number of times the Agent has played each arm.
+    arm_total: Vec<f64>,
+
+    /// Total number of rounds the Agent has played.
+    total: f64,
+
+    /// Divergence between the reward distributions of the arms.
+    divergence: Box<dyn Divergence>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for KlUcbAgent<T> {
+    /// The action chosen by the Agent. The first arm not yet pulled, or the arm with the highest
+    /// upper confidence bound once every arm has been pulled.
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
+        match self.arm_total.iter().position(|&n| n == 0.0) {
+            Some(arm) => arm,
+            None => {
+                let log = self.total.ln();
+                let level = (log + self.c * log.ln()).max(0.0);
+                (0..self.q_star.len())
+                    .map(|arm| {
+                        self.divergence.upper_bound(
+                            arm,
+                            self.q_star[arm],
+                            level / self.arm_total[arm],
+                        )
+                    })
+                    .collect::<Vec<f64>>()
+                    .arg_max()
+            }
+        }
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_star.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.q_star[arm]
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.q_star = q_init.to_owned();
+        self.arm_total = vec![0.0; q_init.len()];
+        self.total = 0.0
+    }
+
+    /// Forget the rewards of a Bandit arm, which is pulled again before any other arm.
+    fn reset_arm(&mut self, arm: usize, q_init: f64) -> bool {
+        self.total -= self.arm_total[arm];
+        self.q_star[arm] = q_init;
+        self.arm_total[arm] = 0.0;
+        true
+    }
+
+    /// Update the Agent's totals and sample mean of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = reward.to_f64().unwrap();
+        self.arm_total[arm] += 1.0;
+        self.total += 1.0;
+        if self.arm_total[arm] == 1.0 {
+            self.q_star[arm] = x
+        } else {
+            self.q_star[arm] += (x - self.q_star[arm]) / self.arm_total[arm]
+        }
+    }
+}
+
+impl<T> KlUcbAgent<T> {
+    /// Initializes a new KL-UCB agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(q_init: Vec<f64>, divergence: Box<dyn Divergence>, c: f64) -> KlUcbAgent<T> {
+        KlUcbAgent::try_new(q_init, divergence, c).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new KL-UCB agent, failing if the initial guess and the divergence have
+    /// different numbers of arms or `c` is negative.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        divergence: Box<dyn Divergence>,
+        c: f64,
+    ) -> Result<KlUcbAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check_len("q_init", q_init.len(), divergence.arms())?;
+        check("c", c, "must be non-negative and finite", |c| {
+            c >= 0.0 && c.is_finite()
+        })?;
+        let arms = q_init.len();
+        Ok(KlUcbAgent {
+            q_star: q_init,
+            c,
+            arm_total: vec![0.0; arms],
+            total: 0.0,
+            divergence,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the current number of times each arm has been pulled.
+    pub fn arm_total(&self) -> &Vec<f64> {
+        &self.arm_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::{BernoulliDivergence, GaussianDivergence, PoissonDivergence};
+
+    use super::{Agent, KlUcbAgent};
+
+    #[test]
+    fn test_round_robin() {
+        let q_init = vec![0.5, 0.61, 0.7];
+        let divergence = BernoulliDivergence::new(vec![1; 3]);
+        let mut kl: KlUcbAgent<u32> = KlUcbAgent::new(q_init, Box::new(divergence), 0.0);
+        for arm in 0..3 {
+            assert_eq!(kl.action(&mut thread_rng()), arm);
+            kl.step(arm, 1)
+        }
+        assert_eq!(kl.arm_total(), &vec![1.0; 3]);
+        assert_eq!(kl.q_star, vec![1.0; 3])
+    }
+
+    #[test]
+    fn test_action() {
+        let divergence = GaussianDivergence::new(vec![1.0; 2]);
+        let mut kl: KlUcbAgent<f64> = KlUcbAgent::new(vec![0.0; 2], Box::new(divergence), 0.0);
+        kl.step(0, 1.0);
+        kl.step(1, 0.5);
+        kl.step(0, 1.0);
+        kl.step(0, 1.0);
+        assert_eq!(kl.action(&mut thread_rng()), 1);
+        kl.step(1, 0.5);
+        kl.step(1, 0.5);
+        assert_eq!(kl.action(&mut thread_rng()), 0)
+    }
+
+    #[test]
+    fn test_step() {
+        let divergence = PoissonDivergence::new(1);
+        let mut kl: KlUcbAgent<u32> = KlUcbAgent::new(vec![5.0], Box::new(divergence), 3.0);
+        kl.step(0, 1);
+        kl.step(0, 4);
+        assert_approx_eq!(kl.current_estimate(0), 2.5);
+        assert_eq!(kl.total, 2.0)
+    }
+
+    #[test]
+    fn test_reset() {
+        let divergence = BernoulliDivergence::new(vec![1; 3]);
+        let mut kl: KlUcbAgent<u32> = KlUcbAgent::new(vec![0.0; 3], Box::new(divergence), 0.0);
+        kl.step(1, 1);
+        let new_q = vec![0.01, 0.86, 0.43];
+        kl.reset(&new_q);
+        assert_eq!(kl.q_star, new_q);
+        assert_eq!(kl.arm_total(), &vec![0.0; 3]);
+        assert_eq!(kl.action(&mut thread_rng()), 0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_arms() {
+        let divergence = BernoulliDivergence::new(vec![1; 2]);
+        let _kl: KlUcbAgent<u32> = KlUcbAgent::new(vec![0.0; 3], Box::new(divergence), 0.0);
+    }
+}
@@ -0,0 +1,153 @@
+use rand::RngCore;
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::error::check;
+use crate::RatelError;
+
+use super::contextual::{check_linear, LinearModel};
+use super::{ArgBounds, ContextualAgent};
+
+/// Agent that follows the Linear Thompson Sampling Algorithm of Agrawal and Goyal (2013).
+///
+/// Fits a separate ridge regression of each arm's rewards on the context, and picks the arm whose
+/// value under coefficients drawn from `N(theta, v^2 A^-1)` is highest, where `theta` is the arm's
+/// coefficient estimate and `A` its regularized Gram matrix. Since only the value in the current
+/// context matters, it is drawn directly from `N(theta^T x, v^2 x^T A^-1 x)`.
+pub struct LinTsAgent {
+    /// Scale of the sampling distribution around the coefficient estimates.
+    v: f64,
+
+    /// The ridge regression of each arm.
+    model: LinearModel,
+}
+
+impl ContextualAgent for LinTsAgent {
+    /// The action chosen by the Agent. Samples each arm's value in the context and picks the arm
+    /// with the highest sample.
+    fn action(&self, context: &[f64], rng: &mut dyn RngCore) -> usize {
+        self.model.check_context(context);
+        (0..self.model.arms())
+            .map(|arm| {
+                let z: f64 = StandardNormal.sample(rng);
+                self.model.mean(context, arm) + self.v * self.model.width(context, arm) * z
+            })
+            .collect::<Vec<f64>>()
+            .arg_max()
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.model.arms()
+    }
+
+    /// The number of features in each context.
+    fn dimension(&self) -> usize {
+        self.model.dimension()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm in the given context, the
+    /// ridge regression prediction.
+    fn current_estimate(&self, context: &[f64], arm: usize) -> f64 {
+        self.model.mean(context, arm)
+    }
+
+    /// Reset the Agent's history.
+    fn reset(&mut self) {
+        self.model.reset()
+    }
+
+    /// Update the ridge regression of a Bandit arm with a reward received in the given context.
+    fn step(&mut self, context: &[f64], arm: usize, reward: f64) {
+        self.model.check_context(context);
+        self.model.update(context, arm, reward)
+    }
+}
+
+impl LinTsAgent {
+    /// Initializes a new Linear Thompson Sampling agent. Panics if the parameters are invalid;
+    /// see `try_new`.
+    pub fn new(arms: usize, dimension: usize, v: f64, lambda: f64) -> LinTsAgent {
+        LinTsAgent::try_new(arms, dimension, v, lambda).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Linear Thompson Sampling agent for contexts with `dimension` features,
+    /// with ridge penalty `lambda`. Fails if there are no arms or features, `v` is negative or
+    /// `lambda` is not positive.
+    pub fn try_new(
+        arms: usize,
+        dimension: usize,
+        v: f64,
+        lambda: f64,
+    ) -> Result<LinTsAgent, RatelError> {
+        check_linear(arms, dimension, lambda)?;
+        check("v", v, "must be non-negative and finite", |v| {
+            v >= 0.0 && v.is_finite()
+        })?;
+        Ok(LinTsAgent {
+            v,
+            model: LinearModel::new(arms, dimension, lambda),
+        })
+    }
+
+    /// Returns the ridge regression estimate of an arm's coefficients.
+    pub fn theta(&self, arm: usize) -> Vec<f64> {
+        self.model.theta(arm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use super::{ContextualAgent, LinTsAgent};
+
+    #[test]
+    fn test_action() {
+        let mut lin_ts = LinTsAgent::new(2, 2, 0.1, 1.0);
+        for _ in 0..50 {
+            lin_ts.step(&[1.0, 0.0], 0, 1.0);
+            lin_ts.step(&[0.0, 1.0], 1, 1.0);
+            lin_ts.step(&[1.0, 0.0], 1, 0.0);
+            lin_ts.step(&[0.0, 1.0], 0, 0.0)
+        }
+        assert_eq!(lin_ts.action(&[1.0, 0.0], &mut thread_rng()), 0);
+        assert_eq!(lin_ts.action(&[0.0, 1.0], &mut thread_rng()), 1)
+    }
+
+    #[test]
+    fn test_sample() {
+        let lin_ts = LinTsAgent::new(2, 1, 1.0, 1.0);
+        let actions: Vec<usize> = (0..200)
+            .map(|_| lin_ts.action(&[1.0], &mut thread_rng()))
+            .collect();
+        assert!(actions.contains(&0) && actions.contains(&1))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut lin_ts = LinTsAgent::new(1, 2, 1.0, 2.0);
+        lin_ts.step(&[0.0, 2.0], 0, 1.0);
+        assert_approx_eq!(lin_ts.theta(0)[1], 1.0 / 3.0);
+        lin_ts.reset();
+        assert_eq!(lin_ts.current_estimate(&[1.0, 1.0], 0), 0.0)
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per feature")]
+    fn test_action_long_context() {
+        LinTsAgent::new(2, 2, 1.0, 1.0).action(&[1.0, 0.0, 1.0], &mut thread_rng());
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per feature")]
+    fn test_step_short_context() {
+        LinTsAgent::new(2, 2, 1.0, 1.0).step(&[1.0], 0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_lambda() {
+        LinTsAgent::new(2, 3, 1.0, 0.0);
+    }
+}
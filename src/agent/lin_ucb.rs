@@ -0,0 +1,153 @@
+use rand::RngCore;
+
+use crate::error::check;
+use crate::RatelError;
+
+use super::contextual::{check_linear, LinearModel};
+use super::{ArgBounds, ContextualAgent};
+
+/// Agent that follows the LinUCB Algorithm of Li, Chu, Langford and Schapire (2010).
+///
+/// Fits a separate ridge regression of each arm's rewards on the context, and picks the arm with
+/// the highest upper confidence bound `theta^T x + alpha sqrt(x^T A^-1 x)`, where `theta` is the
+/// arm's coefficient estimate and `A` its regularized Gram matrix.
+pub struct LinUcbAgent {
+    /// Width of the confidence bound in units of the estimate's standard deviation.
+    alpha: f64,
+
+    /// The ridge regression of each arm.
+    model: LinearModel,
+}
+
+impl ContextualAgent for LinUcbAgent {
+    /// The action chosen by the Agent. The arm with the highest upper confidence bound in the
+    /// context.
+    fn action(&self, context: &[f64], _rng: &mut dyn RngCore) -> usize {
+        self.model.check_context(context);
+        (0..self.model.arms())
+            .map(|arm| self.upper_bound(context, arm))
+            .collect::<Vec<f64>>()
+            .arg_max()
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.model.arms()
+    }
+
+    /// The number of features in each context.
+    fn dimension(&self) -> usize {
+        self.model.dimension()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm in the given context, the
+    /// ridge regression prediction.
+    fn current_estimate(&self, context: &[f64], arm: usize) -> f64 {
+        self.model.mean(context, arm)
+    }
+
+    /// Reset the Agent's history.
+    fn reset(&mut self) {
+        self.model.reset()
+    }
+
+    /// Update the ridge regression of a Bandit arm with a reward received in the given context.
+    fn step(&mut self, context: &[f64], arm: usize, reward: f64) {
+        self.model.check_context(context);
+        self.model.update(context, arm, reward)
+    }
+}
+
+impl LinUcbAgent {
+    /// Initializes a new LinUCB agent. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(arms: usize, dimension: usize, alpha: f64, lambda: f64) -> LinUcbAgent {
+        LinUcbAgent::try_new(arms, dimension, alpha, lambda).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new LinUCB agent for contexts with `dimension` features, with ridge penalty
+    /// `lambda`. Fails if there are no arms or features, `alpha` is negative or `lambda` is not
+    /// positive.
+    pub fn try_new(
+        arms: usize,
+        dimension: usize,
+        alpha: f64,
+        lambda: f64,
+    ) -> Result<LinUcbAgent, RatelError> {
+        check_linear(arms, dimension, lambda)?;
+        check("alpha", alpha, "must be non-negative and finite", |a| {
+            a >= 0.0 && a.is_finite()
+        })?;
+        Ok(LinUcbAgent {
+            alpha,
+            model: LinearModel::new(arms, dimension, lambda),
+        })
+    }
+
+    /// Returns the ridge regression estimate of an arm's coefficients.
+    pub fn theta(&self, arm: usize) -> Vec<f64> {
+        self.model.theta(arm)
+    }
+
+    /// Returns the upper confidence bound of an arm's value in a context.
+    fn upper_bound(&self, context: &[f64], arm: usize) -> f64 {
+        self.model.mean(context, arm) + self.alpha * self.model.width(context, arm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use super::{ContextualAgent, LinUcbAgent};
+
+    #[test]
+    fn test_step() {
+        let mut lin_ucb = LinUcbAgent::new(2, 2, 1.0, 1.0);
+        lin_ucb.step(&[1.0, 0.0], 1, 3.0);
+        assert_eq!(lin_ucb.theta(1), vec![1.5, 0.0]);
+        assert_approx_eq!(lin_ucb.current_estimate(&[2.0, 1.0], 1), 3.0);
+        assert_approx_eq!(lin_ucb.upper_bound(&[1.0, 0.0], 1), 1.5 + 0.5f64.sqrt());
+        lin_ucb.reset();
+        assert_eq!(lin_ucb.theta(1), vec![0.0; 2])
+    }
+
+    #[test]
+    fn test_action() {
+        let mut lin_ucb = LinUcbAgent::new(2, 2, 0.5, 1.0);
+        for _ in 0..10 {
+            lin_ucb.step(&[1.0, 0.0], 0, 1.0);
+            lin_ucb.step(&[0.0, 1.0], 1, 1.0);
+            lin_ucb.step(&[1.0, 0.0], 1, 0.0);
+            lin_ucb.step(&[0.0, 1.0], 0, 0.0)
+        }
+        assert_eq!(lin_ucb.action(&[1.0, 0.0], &mut thread_rng()), 0);
+        assert_eq!(lin_ucb.action(&[0.0, 1.0], &mut thread_rng()), 1)
+    }
+
+    #[test]
+    fn test_explore() {
+        let mut lin_ucb = LinUcbAgent::new(2, 2, 2.0, 1.0);
+        lin_ucb.step(&[1.0, 0.0], 0, 0.5);
+        lin_ucb.step(&[1.0, 0.0], 0, 0.5);
+        assert_eq!(lin_ucb.action(&[1.0, 0.0], &mut thread_rng()), 1)
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per feature")]
+    fn test_action_short_context() {
+        LinUcbAgent::new(2, 2, 1.0, 1.0).action(&[1.0], &mut thread_rng());
+    }
+
+    #[test]
+    #[should_panic(expected = "one value per feature")]
+    fn test_step_long_context() {
+        LinUcbAgent::new(2, 2, 1.0, 1.0).step(&[1.0, 0.0, 1.0], 0, 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_dimension() {
+        LinUcbAgent::new(2, 0, 1.0, 1.0);
+    }
+}
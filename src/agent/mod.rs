@@ -1,13 +1,43 @@
 //! This module contains the Agents that try to figure out the optimal strategy for playing a given Bandit.
 
 pub use agent::Agent;
+pub use change_point::{ChangePointAgent, Restart};
+pub use contextual::ContextualAgent;
+pub use discounted_ucb::DiscountedUcbAgent;
 pub use epsilon_greedy::EpsilonGreedyAgent;
+pub use exp3::Exp3Agent;
+pub use exp3_ix::Exp3IxAgent;
+pub use exp3_p::Exp3PAgent;
+pub use gradient::GradientAgent;
 pub use greedy::GreedyAgent;
+pub use kl_ucb::KlUcbAgent;
+pub use lin_ts::LinTsAgent;
+pub use lin_ucb::LinUcbAgent;
 pub use optimistic::OptimisticAgent;
+pub use robust_ucb::RobustUcbAgent;
+pub use sliding_window_ucb::SlidingWindowUcbAgent;
+pub use softmax::SoftmaxAgent;
+pub use thompson::ThompsonSamplingAgent;
+pub use ucb::UcbAgent;
 
 use super::util::ArgBounds;
 
 mod agent;
+mod change_point;
+mod contextual;
+mod discounted_ucb;
 mod epsilon_greedy;
+mod exp3;
+mod exp3_ix;
+mod exp3_p;
+mod gradient;
 mod greedy;
+mod kl_ucb;
+mod lin_ts;
+mod lin_ucb;
 mod optimistic;
+mod robust_ucb;
+mod sliding_window_ucb;
+mod softmax;
+mod thompson;
+mod ucb;
@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
 
 use num_traits::ToPrimitive;
+use rand::RngCore;
 
 use crate::agent::agent::update;
-use crate::Stepper;
+use crate::error::check;
+use crate::{RatelError, Stepper};
 
 use super::{Agent, ArgBounds};
 
@@ -31,7 +33,7 @@ pub struct OptimisticAgent<T> {
 impl<T: ToPrimitive> Agent<T> for OptimisticAgent<T> {
     /// The action chosen by the Agent. The agent chooses the action with the highest confidence
     /// bound.
-    fn action(&self) -> usize {
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
         self.q_star
             .iter()
             .zip(&self.arm_total)
@@ -65,69 +67,100 @@ impl<T: ToPrimitive> Agent<T> for OptimisticAgent<T> {
 }
 
 impl<T> OptimisticAgent<T> {
-    /// Initializes a new Optimistic agent.
+    /// Initializes a new Optimistic agent. Panics if `c` is invalid; see `try_new`.
     pub fn new(q_init: Vec<f64>, c: f64, stepper: Box<dyn Stepper>) -> OptimisticAgent<T> {
-        assert!(c > 0.0);
-        OptimisticAgent {
+        OptimisticAgent::try_new(q_init, c, stepper).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Optimistic agent, failing if there are no arms or the confidence bound
+    /// `c` is not positive.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        c: f64,
+        stepper: Box<dyn Stepper>,
+    ) -> Result<OptimisticAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check("c", c, "must be positive and finite", |c| {
+            c > 0.0 && c.is_finite()
+        })?;
+        Ok(OptimisticAgent {
             arm_total: vec![1.0; q_init.len()],
             q_star: q_init,
             c,
             total: 1.0,
             stepper,
             phantom: PhantomData,
-        }
+        })
     }
 
     /// Returns a reference to the current number of times each arm has been pulled.
-    fn arm_total(&self) -> &Vec<f64> {
+    pub fn arm_total(&self) -> &Vec<f64> {
         &self.arm_total
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::HarmonicStepper;
+    use rand::thread_rng;
+
+    use crate::{HarmonicStepper, RatelError};
 
     use super::{Agent, OptimisticAgent};
 
     #[test]
     fn test_new() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let mut stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let c = 2.0;
-        let optimistic: OptimisticAgent<u32> =
-            OptimisticAgent::new(Q_INIT, c, Box::new(stepper));
+        let optimistic: OptimisticAgent<u32> = OptimisticAgent::new(q_init, c, Box::new(stepper));
         assert_eq!(optimistic.c, c);
         assert_eq!(optimistic.q_star, vec![0.5, 0.61, 0.7, 0.12, 0.37])
     }
 
+    #[test]
+    fn test_try_new() {
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let optimistic: Result<OptimisticAgent<u32>, RatelError> =
+            OptimisticAgent::try_new(q_init, -2.0, Box::new(stepper));
+        assert_eq!(
+            optimistic.err(),
+            Some(RatelError::InvalidParameter {
+                name: "c",
+                index: None,
+                value: -2.0,
+                reason: "must be positive and finite"
+            })
+        )
+    }
+
     #[test]
     fn test_action() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let mut stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let c = 2.0;
-        let optimistic: OptimisticAgent<u32> =
-            OptimisticAgent::new(Q_INIT, c, Box::new(stepper));
-        assert_eq!(optimistic.action(), 2)
+        let optimistic: OptimisticAgent<u32> = OptimisticAgent::new(q_init, c, Box::new(stepper));
+        assert_eq!(optimistic.action(&mut thread_rng()), 2)
     }
 
     #[test]
     fn test_q_star() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let mut stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let c = 2.0;
-        let optimistic: OptimisticAgent<u32> =
-            OptimisticAgent::new(Q_INIT, c, Box::new(stepper));
+        let optimistic: OptimisticAgent<u32> = OptimisticAgent::new(q_init, c, Box::new(stepper));
         assert_eq!(optimistic.q_star, vec![0.5, 0.61, 0.7, 0.12, 0.37])
     }
 
     #[test]
     fn test_reset() {
-        let Q_INIT = vec![0.5, 0.61, 0.7, 0.12, 0.37];
-        let mut stepper = HarmonicStepper::new(1, Q_INIT.len());
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let stepper = HarmonicStepper::new(1, q_init.len());
         let c = 2.0;
         let mut optimistic: OptimisticAgent<u32> =
-            OptimisticAgent::new(Q_INIT, c, Box::new(stepper));
+            OptimisticAgent::new(q_init, c, Box::new(stepper));
         let new_q = vec![0.01, 0.86, 0.43, 0.65, 0.66];
         optimistic.reset(&new_q);
         assert_eq!(optimistic.q_star, new_q)
@@ -0,0 +1,202 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::{EstimatorState, RatelError, RobustEstimator};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows the Robust UCB Algorithm of Bubeck, Cesa-Bianchi, and Lugosi (2013).
+///
+/// Keeps every reward of each arm and, instead of a running sample mean, estimates the arm's
+/// value with a robust estimator that tolerates heavy tails. Pulls any arm without rewards
+/// first, and otherwise the arm with the highest estimate plus confidence width at level
+/// `delta = t^-2` in round `t`. The estimates are refreshed after each step, since `delta` moves
+/// every round. The median of means caches its block sums, so a step costs `O(k log k)` per arm
+/// for `k` blocks, plus a pass over the rewards whenever `k` changes, which happens about
+/// `16 ln t` times in `t` rounds. The truncated mean rereads every reward on each step.
+pub struct RobustUcbAgent<T> {
+    /// The initial guess of the Bandit arm values, the estimate of an arm with no rewards.
+    q_init: Vec<f64>,
+
+    /// Every reward received from each arm, oldest first.
+    rewards: Vec<Vec<f64>>,
+
+    /// The robust estimator of each arm's mean.
+    estimator: Box<dyn RobustEstimator>,
+
+    /// The work the estimator caches for each arm.
+    states: Vec<EstimatorState>,
+
+    /// The robust estimate of each arm's mean at the current confidence level.
+    estimates: Vec<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for RobustUcbAgent<T> {
+    /// The action chosen by the Agent. The first arm with no rewards, or the arm with the highest
+    /// upper confidence bound.
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
+        match self.rewards.iter().position(|r| r.is_empty()) {
+            Some(arm) => arm,
+            None => {
+                let delta = self.delta();
+                self.rewards
+                    .iter()
+                    .zip(&self.estimates)
+                    .map(|(r, e)| e + self.estimator.width(r.len(), delta))
+                    .collect::<Vec<f64>>()
+                    .arg_max()
+            }
+        }
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_init.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm, the robust estimate of its
+    /// mean at the current confidence level.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        if self.rewards[arm].is_empty() {
+            self.q_init[arm]
+        } else {
+            self.estimates[arm]
+        }
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.q_init = q_init.to_owned();
+        self.rewards = vec![vec![]; q_init.len()];
+        self.states = vec![EstimatorState::default(); q_init.len()];
+        self.estimates = vec![0.0; q_init.len()]
+    }
+
+    /// Forget the rewards of a Bandit arm. The arm is pulled again before any other arm.
+    fn reset_arm(&mut self, arm: usize, q_init: f64) -> bool {
+        self.q_init[arm] = q_init;
+        self.rewards[arm].clear();
+        self.states[arm] = EstimatorState::default();
+        self.refresh();
+        true
+    }
+
+    /// Record a reward received from a Bandit arm.
+    fn step(&mut self, arm: usize, reward: T) {
+        self.rewards[arm].push(reward.to_f64().unwrap());
+        self.refresh()
+    }
+}
+
+impl<T> RobustUcbAgent<T> {
+    /// Initializes a new Robust UCB agent. Panics if there are no arms.
+    pub fn new(q_init: Vec<f64>, estimator: Box<dyn RobustEstimator>) -> RobustUcbAgent<T> {
+        RobustUcbAgent::try_new(q_init, estimator).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Robust UCB agent, failing if there are no arms.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        estimator: Box<dyn RobustEstimator>,
+    ) -> Result<RobustUcbAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        let arms = q_init.len();
+        Ok(RobustUcbAgent {
+            q_init,
+            rewards: vec![vec![]; arms],
+            estimator,
+            states: vec![EstimatorState::default(); arms],
+            estimates: vec![0.0; arms],
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of times each arm was pulled.
+    pub fn counts(&self) -> Vec<usize> {
+        self.rewards.iter().map(|r| r.len()).collect()
+    }
+
+    /// Returns the confidence level `t^-2` for the round `t` about to be played.
+    fn delta(&self) -> f64 {
+        let t = self.rewards.iter().map(|r| r.len()).sum::<usize>() + 1;
+        (t as f64).powi(-2)
+    }
+
+    /// Recomputes the estimate of every arm at the confidence level of the next round.
+    fn refresh(&mut self) {
+        let delta = self.delta();
+        for ((rewards, state), estimate) in self
+            .rewards
+            .iter()
+            .zip(self.states.iter_mut())
+            .zip(self.estimates.iter_mut())
+        {
+            *estimate = self.estimator.estimate_with(rewards, delta, state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::{
+        ContaminatedGaussianBandit, Game, MedianOfMeansEstimator, StudentTBandit,
+        TruncatedMeanEstimator,
+    };
+
+    use super::{Agent, RobustUcbAgent};
+
+    #[test]
+    fn test_step() {
+        let mut ucb: RobustUcbAgent<f64> = RobustUcbAgent::new(
+            vec![0.5; 2],
+            Box::new(MedianOfMeansEstimator::new(1.0, 1.0)),
+        );
+        assert_eq!(ucb.action(&mut thread_rng()), 0);
+        ucb.step(0, 1.0);
+        assert_eq!(ucb.action(&mut thread_rng()), 1);
+        assert_eq!(ucb.current_estimate(1), 0.5);
+        ucb.step(1, 2.0);
+        ucb.step(0, 3.0);
+        assert_eq!(ucb.counts(), vec![2, 1]);
+        assert_approx_eq!(ucb.current_estimate(0), 2.0);
+        assert!(ucb.reset_arm(0, 4.0));
+        assert_eq!(ucb.action(&mut thread_rng()), 0);
+        assert_eq!(ucb.current_estimate(0), 4.0);
+        ucb.reset(&[1.0; 3]);
+        assert_eq!(ucb.counts(), vec![0; 3])
+    }
+
+    #[test]
+    fn test_contaminated() {
+        let bandit = ContaminatedGaussianBandit::new(vec![0.0, 1.0, 0.5], vec![1.0; 3], 0.2, 10.0);
+        let agent = RobustUcbAgent::new(
+            vec![0.0; 3],
+            Box::new(MedianOfMeansEstimator::new(1.0, 1.0)),
+        );
+        let mut game = Game::with_seed(Box::new(agent), Box::new(bandit), 5);
+        game.run(6000);
+        let best = game.actions().iter().filter(|&&a| a == 1).count();
+        assert!(best > 3000)
+    }
+
+    #[test]
+    fn test_student_t() {
+        let bandit = StudentTBandit::new(vec![0.0, 1.0], vec![1.0; 2], vec![1.5; 2]);
+        let agent = RobustUcbAgent::new(
+            vec![0.0; 2],
+            Box::new(TruncatedMeanEstimator::new(0.25, 4.0)),
+        );
+        let mut game = Game::with_seed(Box::new(agent), Box::new(bandit), 11);
+        game.run(3000);
+        let best = game.actions().iter().filter(|&&a| a == 1).count();
+        assert!(best > 1500)
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::error::check;
+use crate::{RatelError, UcbIndex};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows the Sliding-Window UCB Algorithm of Garivier and Moulines (2011).
+///
+/// Computes each arm's upper confidence bound from only the rewards of the last `window` rounds,
+/// so the Agent forgets old rewards and tracks arms whose means change. Pulls any arm without a
+/// reward in the window first, and otherwise the arm with the highest bound, computed by the
+/// index with the number of rounds in the window as the total.
+pub struct SlidingWindowUcbAgent<T> {
+    /// The initial guess of the Bandit arm values, the estimate of an arm with no rewards in the
+    /// window.
+    q_init: Vec<f64>,
+
+    /// Number of most recent rounds whose rewards are kept.
+    window: u32,
+
+    /// The arm pulled and the reward received in each round of the window, oldest first.
+    history: VecDeque<(usize, f64)>,
+
+    /// Sum of each arm's rewards in the window.
+    sums: Vec<f64>,
+
+    /// Sum of each arm's squared rewards in the window.
+    squares: Vec<f64>,
+
+    /// Number of times each arm was pulled in the window.
+    counts: Vec<f64>,
+
+    /// The rule computing each arm's upper confidence bound.
+    index: Box<dyn UcbIndex>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for SlidingWindowUcbAgent<T> {
+    /// The action chosen by the Agent. The first arm with no rewards in the window, or the arm
+    /// with the highest upper confidence bound.
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
+        match self.counts.iter().position(|&n| n == 0.0) {
+            Some(arm) => arm,
+            None => {
+                let total = self.history.len() as f64;
+                (0..self.counts.len())
+                    .map(|arm| {
+                        let (mean, variance) = self.moments(arm);
+                        self.index
+                            .index(mean, variance, self.counts[arm], total, self.counts.len())
+                    })
+                    .collect::<Vec<f64>>()
+                    .arg_max()
+            }
+        }
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_init.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm, the mean of its rewards in
+    /// the window.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.moments(arm).0
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        let arms = q_init.len();
+        self.q_init = q_init.to_owned();
+        self.history.clear();
+        self.sums = vec![0.0; arms];
+        self.squares = vec![0.0; arms];
+        self.counts = vec![0.0; arms]
+    }
+
+    /// Drop the rewards of a Bandit arm from the window. The arm is pulled again before any
+    /// other arm.
+    fn reset_arm(&mut self, arm: usize, q_init: f64) -> bool {
+        self.history.retain(|&(a, _)| a != arm);
+        self.q_init[arm] = q_init;
+        self.sums[arm] = 0.0;
+        self.squares[arm] = 0.0;
+        self.counts[arm] = 0.0;
+        true
+    }
+
+    /// Add a given reward to the window, dropping the oldest reward once the window is full.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = reward.to_f64().unwrap();
+        self.history.push_back((arm, x));
+        self.sums[arm] += x;
+        self.squares[arm] += x * x;
+        self.counts[arm] += 1.0;
+        if self.history.len() > self.window as usize {
+            let (old, y) = self.history.pop_front().unwrap();
+            self.counts[old] -= 1.0;
+            if self.counts[old] == 0.0 {
+                self.sums[old] = 0.0;
+                self.squares[old] = 0.0
+            } else {
+                self.sums[old] -= y;
+                self.squares[old] -= y * y
+            }
+        }
+    }
+}
+
+impl<T> SlidingWindowUcbAgent<T> {
+    /// Initializes a new Sliding-Window UCB agent. Panics if the parameters are invalid; see
+    /// `try_new`.
+    pub fn new(
+        q_init: Vec<f64>,
+        window: u32,
+        index: Box<dyn UcbIndex>,
+    ) -> SlidingWindowUcbAgent<T> {
+        SlidingWindowUcbAgent::try_new(q_init, window, index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Sliding-Window UCB agent, failing if there are no arms or the window is
+    /// empty.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        window: u32,
+        index: Box<dyn UcbIndex>,
+    ) -> Result<SlidingWindowUcbAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        check("window", window, "must be positive", |w| w > 0.0)?;
+        let arms = q_init.len();
+        Ok(SlidingWindowUcbAgent {
+            q_init,
+            window,
+            history: VecDeque::with_capacity(window as usize + 1),
+            sums: vec![0.0; arms],
+            squares: vec![0.0; arms],
+            counts: vec![0.0; arms],
+            index,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the number of times each arm was pulled in the window.
+    pub fn counts(&self) -> &Vec<f64> {
+        &self.counts
+    }
+
+    /// Returns the mean and variance of an arm's rewards in the window, or its initial guess and
+    /// zero if it has none.
+    fn moments(&self, arm: usize) -> (f64, f64) {
+        let n = self.counts[arm];
+        if n == 0.0 {
+            (self.q_init[arm], 0.0)
+        } else {
+            let mean = self.sums[arm] / n;
+            (mean, (self.squares[arm] / n - mean * mean).max(0.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::Ucb1Index;
+
+    use super::{Agent, SlidingWindowUcbAgent};
+
+    #[test]
+    fn test_window() {
+        let mut sw: SlidingWindowUcbAgent<f64> =
+            SlidingWindowUcbAgent::new(vec![0.5; 2], 3, Box::new(Ucb1Index::new()));
+        sw.step(0, 1.0);
+        sw.step(1, 2.0);
+        sw.step(0, 3.0);
+        assert_eq!(sw.counts(), &vec![2.0, 1.0]);
+        assert_approx_eq!(sw.current_estimate(0), 2.0);
+        sw.step(0, 5.0);
+        assert_eq!(sw.counts(), &vec![2.0, 1.0]);
+        assert_approx_eq!(sw.current_estimate(0), 4.0);
+        assert_approx_eq!(sw.moments(0).1, 1.0);
+        sw.step(0, 5.0);
+        assert_eq!(sw.counts(), &vec![3.0, 0.0]);
+        assert_eq!(sw.current_estimate(1), 0.5);
+        assert_eq!(sw.history.len(), 3)
+    }
+
+    #[test]
+    fn test_action() {
+        let mut sw: SlidingWindowUcbAgent<f64> =
+            SlidingWindowUcbAgent::new(vec![0.0; 2], 4, Box::new(Ucb1Index::new()));
+        assert_eq!(sw.action(&mut thread_rng()), 0);
+        sw.step(0, 1.0);
+        assert_eq!(sw.action(&mut thread_rng()), 1);
+        sw.step(1, 0.0);
+        sw.step(0, 1.0);
+        sw.step(0, 1.0);
+        assert_eq!(sw.action(&mut thread_rng()), 0);
+        sw.step(0, 1.0);
+        sw.step(0, 1.0);
+        assert_eq!(sw.action(&mut thread_rng()), 1)
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sw: SlidingWindowUcbAgent<u32> =
+            SlidingWindowUcbAgent::new(vec![0.0; 3], 10, Box::new(Ucb1Index::new()));
+        sw.step(2, 4);
+        sw.reset(&[1.0, 2.0, 3.0]);
+        assert_eq!(sw.counts(), &vec![0.0; 3]);
+        assert!(sw.history.is_empty());
+        assert_eq!(sw.current_estimate(2), 3.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_window() {
+        let _sw: SlidingWindowUcbAgent<u32> =
+            SlidingWindowUcbAgent::new(vec![0.0; 3], 0, Box::new(Ucb1Index::new()));
+    }
+}
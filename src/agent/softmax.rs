@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::uniform::Uniform;
+
+use crate::agent::agent::{pick, softmax, update};
+use crate::{RatelError, Schedule, Stepper};
+
+use super::Agent;
+
+/// Agent that follows the Boltzmann, or Softmax, Exploration Algorithm.
+///
+/// Picks each arm with probability proportional to `exp(q_star[arm] / tau)`. High temperatures
+/// `tau` explore almost uniformly; as the temperature falls the Agent becomes greedy.
+pub struct SoftmaxAgent<T> {
+    /// The current estimates of the Bandit arm values.
+    q_star: Vec<f64>,
+
+    /// The Agent's rule for step size updates.
+    stepper: Box<dyn Stepper>,
+
+    /// The temperature in each round.
+    temperature: Box<dyn Schedule>,
+
+    /// Total number of rounds the Agent has played.
+    rounds: u32,
+
+    /// A random uniform distribution to pick an arm from the softmax policy.
+    uniform: Uniform<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for SoftmaxAgent<T> {
+    /// The action chosen by the Agent. An arm drawn from the softmax of the estimates at the
+    /// current temperature.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        pick(&self.probabilities(), self.uniform.sample(rng))
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_star.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.q_star[arm]
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.q_star = q_init.to_owned();
+        self.rounds = 0;
+        self.stepper.reset()
+    }
+
+    /// Update the Agent's estimate of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        self.q_star[arm] += update(&mut self.stepper, &self.q_star, arm, reward);
+        self.rounds += 1
+    }
+}
+
+impl<T> SoftmaxAgent<T> {
+    /// Initializes a new Softmax agent. Panics if there are no arms.
+    pub fn new(
+        q_init: Vec<f64>,
+        stepper: Box<dyn Stepper>,
+        temperature: Box<dyn Schedule>,
+    ) -> SoftmaxAgent<T> {
+        SoftmaxAgent::try_new(q_init, stepper, temperature).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Softmax agent, failing if there are no arms.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        stepper: Box<dyn Stepper>,
+        temperature: Box<dyn Schedule>,
+    ) -> Result<SoftmaxAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        Ok(SoftmaxAgent {
+            q_star: q_init,
+            stepper,
+            temperature,
+            rounds: 0,
+            uniform: Uniform::new(0.0, 1.0),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns the probability of picking each arm in the current round.
+    pub fn probabilities(&self) -> Vec<f64> {
+        softmax(&self.q_star, self.temperature())
+    }
+
+    /// Returns the temperature in the current round.
+    pub fn temperature(&self) -> f64 {
+        self.temperature.value(self.rounds + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::{ConstantSchedule, ExponentialSchedule, HarmonicStepper};
+
+    use super::{Agent, SoftmaxAgent};
+
+    #[test]
+    fn test_probabilities() {
+        let q_init = vec![0.0, 2f64.ln(), 0.0];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let schedule = ConstantSchedule::new(0.5);
+        let softmax: SoftmaxAgent<u32> =
+            SoftmaxAgent::new(q_init, Box::new(stepper), Box::new(schedule));
+        let p = softmax.probabilities();
+        assert_approx_eq!(p[0], 1.0 / 6.0);
+        assert_approx_eq!(p[1], 4.0 / 6.0)
+    }
+
+    #[test]
+    fn test_action() {
+        let q_init = vec![0.0, 1.0, 0.0];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let schedule = ConstantSchedule::new(0.01);
+        let softmax: SoftmaxAgent<u32> =
+            SoftmaxAgent::new(q_init, Box::new(stepper), Box::new(schedule));
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..100).all(|_| softmax.action(&mut rng) == 1))
+    }
+
+    #[test]
+    fn test_temperature() {
+        let q_init = vec![0.0; 2];
+        let stepper = HarmonicStepper::new(1, q_init.len());
+        let schedule = ExponentialSchedule::new(1.0, 0.5, 0.0);
+        let mut softmax: SoftmaxAgent<u32> =
+            SoftmaxAgent::new(q_init, Box::new(stepper), Box::new(schedule));
+        assert_eq!(softmax.temperature(), 1.0);
+        softmax.step(0, 1);
+        softmax.step(1, 0);
+        assert_eq!(softmax.temperature(), 0.25);
+        assert_eq!(softmax.q_star, vec![1.0, 0.0]);
+        softmax.reset(&[0.0, 0.0]);
+        assert_eq!(softmax.temperature(), 1.0)
+    }
+}
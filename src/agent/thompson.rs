@@ -0,0 +1,133 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::error::check_len;
+use crate::{Posterior, RatelError};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows the Thompson Sampling Algorithm.
+///
+/// Keeps a conjugate posterior of each arm's average reward and picks the arm whose sample from the
+/// posterior is highest.
+pub struct ThompsonSamplingAgent<T> {
+    /// The Agent's posteriors of the Bandit arm values.
+    posterior: Box<dyn Posterior>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for ThompsonSamplingAgent<T> {
+    /// The action chosen by the Agent. Samples each arm's posterior and picks the arm with the
+    /// highest sample.
+    fn action(&self, rng: &mut dyn RngCore) -> usize {
+        (0..self.posterior.arms())
+            .map(|arm| self.posterior.sample(arm, rng))
+            .collect::<Vec<f64>>()
+            .arg_max()
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.posterior.arms()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm. The posterior mean.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.posterior.mean(arm)
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.posterior.reset(q_init)
+    }
+
+    /// Update the Agent's posterior of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        self.posterior.update(arm, reward.to_f64().unwrap())
+    }
+}
+
+impl<T> ThompsonSamplingAgent<T> {
+    /// Initializes a new Thompson Sampling agent. Panics if the initial guess and the posterior
+    /// have different numbers of arms.
+    pub fn new(q_init: Vec<f64>, posterior: Box<dyn Posterior>) -> ThompsonSamplingAgent<T> {
+        ThompsonSamplingAgent::try_new(q_init, posterior).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Thompson Sampling agent, failing if the initial guess and the posterior
+    /// have different numbers of arms.
+    pub fn try_new(
+        q_init: Vec<f64>,
+        mut posterior: Box<dyn Posterior>,
+    ) -> Result<ThompsonSamplingAgent<T>, RatelError> {
+        check_len("q_init", q_init.len(), posterior.arms())?;
+        posterior.reset(&q_init);
+        Ok(ThompsonSamplingAgent {
+            posterior,
+            phantom: PhantomData,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::{BetaPosterior, NormalGammaPosterior};
+
+    use super::{Agent, ThompsonSamplingAgent};
+
+    #[test]
+    fn test_action() {
+        let q_init = vec![0.0, 0.0, 0.0];
+        let posterior = BetaPosterior::new(vec![1, 1, 1], 0.0);
+        let mut thompson: ThompsonSamplingAgent<u32> =
+            ThompsonSamplingAgent::new(q_init, Box::new(posterior));
+        for _ in 0..200 {
+            thompson.step(1, 1);
+            thompson.step(0, 0);
+            thompson.step(2, 0);
+        }
+        assert_eq!(thompson.action(&mut thread_rng()), 1)
+    }
+
+    #[test]
+    fn test_current_estimate() {
+        let q_init = vec![0.5, -0.3, 1.2];
+        let posterior = NormalGammaPosterior::new(3, 1.0, 1.0, 1.0);
+        let mut thompson: ThompsonSamplingAgent<f64> =
+            ThompsonSamplingAgent::new(q_init, Box::new(posterior));
+        assert_eq!(thompson.current_estimate(1), -0.3);
+        thompson.step(1, 0.7);
+        assert_approx_eq!(thompson.current_estimate(1), 0.2)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_wrong_size() {
+        let q_init = vec![0.5, 0.61, 0.7];
+        let posterior = NormalGammaPosterior::new(4, 1.0, 1.0, 1.0);
+        let _thompson: ThompsonSamplingAgent<f64> =
+            ThompsonSamplingAgent::new(q_init, Box::new(posterior));
+    }
+
+    #[test]
+    fn test_reset() {
+        let q_init = vec![0.5, -0.3, 1.2];
+        let posterior = NormalGammaPosterior::new(3, 1.0, 1.0, 1.0);
+        let mut thompson: ThompsonSamplingAgent<f64> =
+            ThompsonSamplingAgent::new(q_init, Box::new(posterior));
+        thompson.step(2, 4.0);
+        let new_q = vec![0.01, 0.86, 0.43];
+        thompson.reset(&new_q);
+        assert_eq!(
+            (0..3)
+                .map(|arm| thompson.current_estimate(arm))
+                .collect::<Vec<f64>>(),
+            new_q
+        )
+    }
+}
@@ -0,0 +1,200 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::{RatelError, UcbIndex};
+
+use super::{Agent, ArgBounds};
+
+/// Agent that follows an Upper Confidence Bound Algorithm.
+///
+/// Pulls every arm once, in order, and from then on chooses the arm with the highest upper
+/// confidence bound, computed by the index from the sample mean and variance of the arm's rewards.
+pub struct UcbAgent<T> {
+    /// The current estimates of the Bandit arm values. The initial guess until an arm is pulled,
+    /// the sample mean of its rewards afterwards.
+    q_star: Vec<f64>,
+
+    /// Sum of squared deviations of each arm's rewards from their sample mean.
+    m2: Vec<f64>,
+
+    /// Total number of times the Agent has played each arm.
+    arm_total: Vec<f64>,
+
+    /// Total number of rounds the Agent has played.
+    total: f64,
+
+    /// The rule computing each arm's upper confidence bound.
+    index: Box<dyn UcbIndex>,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ToPrimitive> Agent<T> for UcbAgent<T> {
+    /// The action chosen by the Agent. The first arm not yet pulled, or the arm with the highest
+    /// upper confidence bound once every arm has been pulled.
+    fn action(&self, _rng: &mut dyn RngCore) -> usize {
+        match self.arm_total.iter().position(|&n| n == 0.0) {
+            Some(arm) => arm,
+            None => (0..self.q_star.len())
+                .map(|arm| {
+                    let n = self.arm_total[arm];
+                    let arms = self.q_star.len();
+                    self.index
+                        .index(self.q_star[arm], self.m2[arm] / n, n, self.total, arms)
+                })
+                .collect::<Vec<f64>>()
+                .arg_max(),
+        }
+    }
+
+    /// The number of arms in the Bandit the Agent is playing.
+    fn arms(&self) -> usize {
+        self.q_star.len()
+    }
+
+    /// The Agent's current estimate of the value of a Bandit's arm.
+    fn current_estimate(&self, arm: usize) -> f64 {
+        self.q_star[arm]
+    }
+
+    /// Reset the Agent's history and give it a new initial guess of the Bandit's arm values.
+    fn reset(&mut self, q_init: &[f64]) {
+        self.q_star = q_init.to_owned();
+        self.m2 = vec![0.0; q_init.len()];
+        self.arm_total = vec![0.0; q_init.len()];
+        self.total = 0.0
+    }
+
+    /// Forget the rewards of a Bandit arm, which is pulled again before any other arm.
+    fn reset_arm(&mut self, arm: usize, q_init: f64) -> bool {
+        self.total -= self.arm_total[arm];
+        self.q_star[arm] = q_init;
+        self.m2[arm] = 0.0;
+        self.arm_total[arm] = 0.0;
+        true
+    }
+
+    /// Update the Agent's totals and sample statistics of a Bandit arm based on a given reward.
+    fn step(&mut self, arm: usize, reward: T) {
+        let x = reward.to_f64().unwrap();
+        self.arm_total[arm] += 1.0;
+        self.total += 1.0;
+        if self.arm_total[arm] == 1.0 {
+            self.q_star[arm] = x;
+        } else {
+            let delta = x - self.q_star[arm];
+            self.q_star[arm] += delta / self.arm_total[arm];
+            self.m2[arm] += delta * (x - self.q_star[arm]);
+        }
+    }
+}
+
+impl<T> UcbAgent<T> {
+    /// Initializes a new UCB agent. Panics if there are no arms.
+    pub fn new(q_init: Vec<f64>, index: Box<dyn UcbIndex>) -> UcbAgent<T> {
+        UcbAgent::try_new(q_init, index).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new UCB agent, failing if there are no arms.
+    pub fn try_new(q_init: Vec<f64>, index: Box<dyn UcbIndex>) -> Result<UcbAgent<T>, RatelError> {
+        if q_init.is_empty() {
+            return Err(RatelError::Empty { name: "q_init" });
+        }
+        let arms = q_init.len();
+        Ok(UcbAgent {
+            q_star: q_init,
+            m2: vec![0.0; arms],
+            arm_total: vec![0.0; arms],
+            total: 0.0,
+            index,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the current number of times each arm has been pulled.
+    pub fn arm_total(&self) -> &Vec<f64> {
+        &self.arm_total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::{MossIndex, Ucb1Index, Ucb1TunedIndex};
+
+    use super::{Agent, UcbAgent};
+
+    #[test]
+    fn test_round_robin() {
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let mut ucb: UcbAgent<u32> = UcbAgent::new(q_init, Box::new(Ucb1Index::new()));
+        for arm in 0..5 {
+            assert_eq!(ucb.action(&mut thread_rng()), arm);
+            ucb.step(arm, 1)
+        }
+        assert_eq!(ucb.arm_total(), &vec![1.0; 5]);
+        assert_eq!(ucb.q_star, vec![1.0; 5])
+    }
+
+    #[test]
+    fn test_action() {
+        let mut ucb: UcbAgent<f64> = UcbAgent::new(vec![0.0; 2], Box::new(Ucb1Index::new()));
+        ucb.step(0, 0.1);
+        ucb.step(1, 0.0);
+        ucb.step(0, 0.1);
+        ucb.step(0, 0.1);
+        assert_eq!(ucb.action(&mut thread_rng()), 1);
+        let mut moss: UcbAgent<f64> = UcbAgent::new(vec![0.0; 2], Box::new(MossIndex::new(2)));
+        moss.step(0, 1.0);
+        moss.step(1, 0.0);
+        assert_eq!(moss.action(&mut thread_rng()), 0)
+    }
+
+    #[test]
+    fn test_step() {
+        let mut ucb: UcbAgent<f64> = UcbAgent::new(vec![5.0], Box::new(Ucb1TunedIndex::new()));
+        ucb.step(0, 1.0);
+        assert_eq!(ucb.current_estimate(0), 1.0);
+        ucb.step(0, 3.0);
+        ucb.step(0, 5.0);
+        assert_approx_eq!(ucb.q_star[0], 3.0);
+        assert_approx_eq!(ucb.m2[0], 8.0);
+        assert_eq!(ucb.total, 3.0)
+    }
+
+    #[test]
+    fn test_reset() {
+        let q_init = vec![0.5, 0.61, 0.7, 0.12, 0.37];
+        let mut ucb: UcbAgent<u32> = UcbAgent::new(q_init, Box::new(Ucb1Index::new()));
+        ucb.step(2, 4);
+        let new_q = vec![0.01, 0.86, 0.43, 0.65, 0.66];
+        ucb.reset(&new_q);
+        assert_eq!(ucb.q_star, new_q);
+        assert_eq!(ucb.arm_total(), &vec![0.0; 5]);
+        assert_eq!(ucb.total, 0.0);
+        assert_eq!(ucb.action(&mut thread_rng()), 0)
+    }
+
+    #[test]
+    fn test_reset_arm() {
+        let mut ucb: UcbAgent<f64> = UcbAgent::new(vec![0.0; 3], Box::new(Ucb1Index::new()));
+        for arm in 0..3 {
+            ucb.step(arm, 1.0);
+            ucb.step(arm, 2.0)
+        }
+        assert!(ucb.reset_arm(1, 0.5));
+        assert_eq!(ucb.arm_total(), &vec![2.0, 0.0, 2.0]);
+        assert_eq!(ucb.total, 4.0);
+        assert_eq!(ucb.current_estimate(1), 0.5);
+        assert_eq!(ucb.action(&mut thread_rng()), 1)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_empty() {
+        let _ucb: UcbAgent<u32> = UcbAgent::new(vec![], Box::new(Ucb1Index::new()));
+    }
+}
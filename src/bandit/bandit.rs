@@ -1,4 +1,5 @@
 use num_traits::ToPrimitive;
+use rand::RngCore;
 
 /// A trait for common members of the Bandits
 pub trait Bandit<T: ToPrimitive> {
@@ -21,8 +22,16 @@ pub trait Bandit<T: ToPrimitive> {
         (0..self.arms()).map(|arm| self.mean(arm)).collect()
     }
 
-    /// The reward from a pull of a given arm.
-    fn reward(&self, arm: usize) -> T;
+    /// The reward from a pull of a given arm, drawn using the given random number generator.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> T;
+
+    /// Moves the Bandit on by one pull, drawing any change in its arms from the given random
+    /// number generator. Stationary Bandits, whose arms never change, do nothing.
+    fn advance(&mut self, _rng: &mut dyn RngCore) {}
+
+    /// Returns the Bandit's arms to their state before the first pull. Stationary Bandits do
+    /// nothing.
+    fn reset(&mut self) {}
 
     /// The standard deviation of a given arm.
     fn std(&self, arm: usize) -> f64;
@@ -0,0 +1,169 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Bernoulli;
+
+use crate::error::{check_each, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms pay one with some probability and zero otherwise.
+pub struct BernoulliBandit {
+    /// Vector of the probabilities each arm pays one.
+    probs: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Bernoulli>,
+}
+
+impl BernoulliBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Bernoulli
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(probs: Vec<f64>) -> BernoulliBandit {
+        BernoulliBandit::try_new(probs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a Bernoulli
+    /// distribution. Fails if there are no probabilities or a probability is outside `[0, 1]`.
+    pub fn try_new(probs: Vec<f64>) -> Result<BernoulliBandit, RatelError> {
+        check_each("probs", &probs, "must be between 0 and 1", |p| {
+            (0.0..=1.0).contains(&p)
+        })?;
+        let dist = probs.iter().map(|&p| Bernoulli::new(p).unwrap()).collect();
+        let arms = probs.len();
+        let best_arm = probs.arg_max();
+        Ok(BernoulliBandit {
+            probs,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> BernoulliBanditBuilder {
+        BernoulliBanditBuilder::default()
+    }
+}
+
+/// Builder for a Bernoulli Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct BernoulliBanditBuilder {
+    /// Success probability of each arm added.
+    probs: Vec<f64>,
+}
+
+impl BernoulliBanditBuilder {
+    /// Adds an arm with the given success probability.
+    pub fn arm(mut self, prob: f64) -> Self {
+        self.probs.push(prob);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<BernoulliBandit, RatelError> {
+        BernoulliBandit::try_new(self.probs)
+    }
+}
+
+impl Bandit<u32> for BernoulliBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm, its success probability.
+    fn mean(&self, arm: usize) -> f64 {
+        self.probs[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> u32 {
+        u32::from(self.distributions[arm].sample(rng))
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        (self.probs[arm] * (1.0 - self.probs[arm])).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::BernoulliBandit;
+
+    lazy_static! {
+        static ref PROBS_VEC: Vec<f64> = vec![0.1, 0.7, 0.3, 0.5];
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bernoulli = BernoulliBandit::new(PROBS_VEC.to_vec());
+        assert_eq!(bernoulli.arms(), 4);
+        assert_eq!(bernoulli.best_arm(), 1);
+        assert_eq!(bernoulli.max_reward(), 0.7)
+    }
+
+    #[test]
+    fn test_std() {
+        let bernoulli = BernoulliBandit::new(PROBS_VEC.to_vec());
+        assert_eq!(bernoulli.means(), PROBS_VEC.to_vec());
+        assert_approx_eq!(bernoulli.std(3), 0.5);
+        assert_approx_eq!(bernoulli.std(0), 0.3)
+    }
+
+    #[test]
+    fn test_reward() {
+        let bernoulli = BernoulliBandit::new(vec![0.0, 1.0, 0.5]);
+        let mut rng = thread_rng();
+        assert_eq!(bernoulli.reward(0, &mut rng), 0);
+        assert_eq!(bernoulli.reward(1, &mut rng), 1);
+        let total: u32 = (0..1000).map(|_| bernoulli.reward(2, &mut rng)).sum();
+        assert!(total > 400 && total < 600)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = BernoulliBandit::builder()
+            .arm(0.2)
+            .arm(0.9)
+            .build()
+            .unwrap();
+        assert_eq!(bandit.probs, vec![0.2, 0.9])
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            BernoulliBandit::try_new(vec![0.5, 1.5]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "probs",
+                index: Some(1),
+                value: 1.5,
+                reason: "must be between 0 and 1"
+            })
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_empty() {
+        BernoulliBandit::new(vec![]);
+    }
+}
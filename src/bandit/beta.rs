@@ -0,0 +1,179 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Beta;
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards in `[0, 1]` according to beta distributions.
+pub struct BetaBandit {
+    /// Vector of the first shape parameters.
+    alphas: Vec<f64>,
+
+    /// Vector of the second shape parameters.
+    betas: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Beta<f64>>,
+}
+
+impl BetaBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a beta
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(alphas: Vec<f64>, betas: Vec<f64>) -> BetaBandit {
+        BetaBandit::try_new(alphas, betas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a beta
+    /// distribution. Fails if the shape parameters differ in length or a shape parameter is not
+    /// positive.
+    pub fn try_new(alphas: Vec<f64>, betas: Vec<f64>) -> Result<BetaBandit, RatelError> {
+        check_each("alphas", &alphas, "must be positive and finite", |a| {
+            a > 0.0 && a.is_finite()
+        })?;
+        check_len("betas", betas.len(), alphas.len())?;
+        check_each("betas", &betas, "must be positive and finite", |b| {
+            b > 0.0 && b.is_finite()
+        })?;
+        let dist = alphas
+            .iter()
+            .zip(&betas)
+            .map(|(&a, &b)| Beta::new(a, b).unwrap())
+            .collect();
+        let arms = alphas.len();
+        let best_arm = alphas
+            .iter()
+            .zip(&betas)
+            .map(|(a, b)| a / (a + b))
+            .collect::<Vec<f64>>()
+            .arg_max();
+        Ok(BetaBandit {
+            alphas,
+            betas,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> BetaBanditBuilder {
+        BetaBanditBuilder::default()
+    }
+}
+
+/// Builder for a beta Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct BetaBanditBuilder {
+    /// First shape parameter of each arm added.
+    alphas: Vec<f64>,
+    /// Second shape parameter of each arm added.
+    betas: Vec<f64>,
+}
+
+impl BetaBanditBuilder {
+    /// Adds an arm with the given shape parameters.
+    pub fn arm(mut self, alpha: f64, beta: f64) -> Self {
+        self.alphas.push(alpha);
+        self.betas.push(beta);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<BetaBandit, RatelError> {
+        BetaBandit::try_new(self.alphas, self.betas)
+    }
+}
+
+impl Bandit<f64> for BetaBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        self.alphas[arm] / (self.alphas[arm] + self.betas[arm])
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        let (a, b) = (self.alphas[arm], self.betas[arm]);
+        (a * b / ((a + b) * (a + b) * (a + b + 1.0))).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::BetaBandit;
+
+    fn beta() -> BetaBandit {
+        BetaBandit::new(vec![1.0, 2.0, 6.0], vec![1.0, 6.0, 2.0])
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = beta();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 2);
+        assert_eq!(bandit.means(), vec![0.5, 0.25, 0.75])
+    }
+
+    #[test]
+    fn test_std() {
+        let bandit = beta();
+        assert_approx_eq!(bandit.std(0), 0.28867513);
+        assert_approx_eq!(bandit.std(2), 0.14433757)
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = beta();
+        let mut rng = thread_rng();
+        let total: f64 = (0..1000).map(|_| bandit.reward(2, &mut rng)).sum();
+        assert!((0..100).all(|_| (0.0..=1.0).contains(&bandit.reward(1, &mut rng))));
+        assert!(total > 700.0 && total < 800.0)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = BetaBandit::builder().arm(3.0, 1.0).build().unwrap();
+        assert_eq!(bandit.mean(0), 0.75)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            BetaBandit::try_new(vec![1.0, 1.0], vec![1.0, -1.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "betas",
+                index: Some(1),
+                value: -1.0,
+                reason: "must be positive and finite"
+            })
+        )
+    }
+}
@@ -1,7 +1,9 @@
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::Binomial;
 
+use crate::error::{check_each, check_len, RatelError};
+
 use super::{ArgBounds, Bandit};
 
 /// A bandit whose arms distribute rewards according to the binomial distributions.
@@ -24,12 +26,20 @@ pub struct BinomialBandit {
 
 impl BinomialBandit {
     /// Initializes a new Bandit where each arm distributes rewards according to a binomial
-    /// distribution.
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
     pub fn new(nums: Vec<u32>, probs: Vec<f64>) -> Self {
-        assert_eq!(nums.len(), probs.len());
-        assert!(probs.val_max() <= 1.0);
-        assert!(probs.val_min() >= 0.0);
-        assert!(nums.val_min() > 0);
+        BinomialBandit::try_new(nums, probs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a binomial
+    /// distribution. Fails if the numbers of trials and probabilities differ in length, if a
+    /// number of trials is zero, or if a probability is outside `[0, 1]`.
+    pub fn try_new(nums: Vec<u32>, probs: Vec<f64>) -> Result<Self, RatelError> {
+        check_each("nums", &nums, "must be positive", |n| n > 0.0)?;
+        check_len("probs", probs.len(), nums.len())?;
+        check_each("probs", &probs, "must be between 0 and 1", |p| {
+            (0.0..=1.0).contains(&p)
+        })?;
         let dist = nums
             .iter()
             .zip(&probs)
@@ -42,13 +52,41 @@ impl BinomialBandit {
             .collect::<Vec<f64>>()
             .arg_max();
         let arms = nums.len();
-        BinomialBandit {
+        Ok(BinomialBandit {
             nums,
             probs,
             arms,
             best_arm,
             distributions: dist,
-        }
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> BinomialBanditBuilder {
+        BinomialBanditBuilder::default()
+    }
+}
+
+/// Builder for a binomial Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct BinomialBanditBuilder {
+    /// Number of trials of each arm added.
+    nums: Vec<u32>,
+    /// Success probability of each arm added.
+    probs: Vec<f64>,
+}
+
+impl BinomialBanditBuilder {
+    /// Adds an arm with the given number of trials and success probability.
+    pub fn arm(mut self, num: u32, prob: f64) -> Self {
+        self.nums.push(num);
+        self.probs.push(prob);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<BinomialBandit, RatelError> {
+        BinomialBandit::try_new(self.nums, self.probs)
     }
 }
 
@@ -69,8 +107,8 @@ impl Bandit<u32> for BinomialBandit {
     }
 
     /// Determines the reward for pulling a given arm.
-    fn reward(&self, arm: usize) -> u32 {
-        self.distributions[arm].sample(&mut thread_rng()) as u32
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> u32 {
+        self.distributions[arm].sample(rng) as u32
     }
 
     /// Computes the standard deviations of each arm.
@@ -82,6 +120,9 @@ impl Bandit<u32> for BinomialBandit {
 #[cfg(test)]
 mod tests {
     use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
 
     use super::BinomialBandit;
     use super::super::Bandit;
@@ -154,13 +195,36 @@ mod tests {
         BinomialBandit::new(nums_vec, p);
     }
 
+    #[test]
+    fn test_builder() {
+        let bandit = BinomialBandit::builder().arm(5, 0.2).arm(4, 0.9).build().unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.nums, vec![5, 4])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let nums_vec: Vec<u32> = vec![5, 4, 0, 8, 10];
+        let probs_vec: Vec<f64> = vec![0.97, 0.91, 0.77, 0.66, 0.57];
+        assert_eq!(
+            BinomialBandit::try_new(nums_vec, probs_vec).err(),
+            Some(RatelError::InvalidParameter {
+                name: "nums",
+                index: Some(2),
+                value: 0.0,
+                reason: "must be positive"
+            })
+        )
+    }
+
     #[test]
     fn test_reward() {
         let nums_vec: Vec<u32> = vec![5, 4, 1, 8, 10];
         let probs_vec: Vec<f64> = vec![0.97, 0.91, 0.77, 0.66, 0.57];
         let binom: BinomialBandit = BinomialBandit::new(nums_vec, probs_vec);
+        let mut rng = thread_rng();
         for _ in 0..1000 {
-            binom.reward(2);
+            binom.reward(2, &mut rng);
         }
     }
 
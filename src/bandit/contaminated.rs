@@ -0,0 +1,232 @@
+use rand::distributions::Distribution;
+use rand::{Rng, RngCore};
+use rand_distr::{Cauchy, Normal};
+
+use crate::error::{check, check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms pay Gaussian rewards contaminated by occasional Cauchy outliers.
+///
+/// With probability `epsilon` a pull pays the arm's mean plus Cauchy noise of the given scale,
+/// and otherwise a Gaussian reward. The Cauchy outliers have no mean, so the reported mean of an
+/// arm is the center of its rewards, the value a robust estimator recovers.
+pub struct ContaminatedGaussianBandit {
+    /// Centers of the arms.
+    means: Vec<f64>,
+
+    /// Standard deviations of the uncontaminated rewards.
+    stds: Vec<f64>,
+
+    /// Probability a reward is an outlier.
+    epsilon: f64,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the uncontaminated rewards.
+    distributions: Vec<Normal<f64>>,
+
+    /// Distribution of the outlier noise.
+    outliers: Cauchy<f64>,
+}
+
+impl ContaminatedGaussianBandit {
+    /// Initializes a new Bandit with contaminated Gaussian arms. Panics if the parameters are
+    /// invalid; see `try_new`.
+    pub fn new(
+        means: Vec<f64>,
+        stds: Vec<f64>,
+        epsilon: f64,
+        scale: f64,
+    ) -> ContaminatedGaussianBandit {
+        ContaminatedGaussianBandit::try_new(means, stds, epsilon, scale)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit with contaminated Gaussian arms. Fails if the means and standard
+    /// deviations are invalid as for a Gaussian Bandit, if `epsilon` is not a probability, or if
+    /// the outlier scale is not positive.
+    pub fn try_new(
+        means: Vec<f64>,
+        stds: Vec<f64>,
+        epsilon: f64,
+        scale: f64,
+    ) -> Result<ContaminatedGaussianBandit, RatelError> {
+        check_each("means", &means, "must be finite", f64::is_finite)?;
+        check_len("stds", stds.len(), means.len())?;
+        check_each("stds", &stds, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        check("epsilon", epsilon, "must be between 0 and 1", |e| {
+            (0.0..=1.0).contains(&e)
+        })?;
+        check("scale", scale, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        let dist = means
+            .iter()
+            .zip(&stds)
+            .map(|(&m, &s)| Normal::new(m, s).unwrap())
+            .collect();
+        let arms = means.len();
+        let best_arm = means.arg_max();
+        Ok(ContaminatedGaussianBandit {
+            means,
+            stds,
+            epsilon,
+            arms,
+            best_arm,
+            distributions: dist,
+            outliers: Cauchy::new(0.0, scale).unwrap(),
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> ContaminatedGaussianBanditBuilder {
+        ContaminatedGaussianBanditBuilder::default()
+    }
+}
+
+/// Builder for a contaminated Gaussian Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct ContaminatedGaussianBanditBuilder {
+    /// Center of each arm added.
+    means: Vec<f64>,
+    /// Standard deviation of the uncontaminated rewards of each arm added.
+    stds: Vec<f64>,
+    /// Probability a reward is an outlier.
+    epsilon: Option<f64>,
+    /// Scale of the outlier noise.
+    scale: Option<f64>,
+}
+
+impl ContaminatedGaussianBanditBuilder {
+    /// Adds an arm with the given center and standard deviation of its uncontaminated rewards.
+    pub fn arm(mut self, mean: f64, std: f64) -> Self {
+        self.means.push(mean);
+        self.stds.push(std);
+        self
+    }
+
+    /// Sets the probability a reward is an outlier.
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Some(epsilon);
+        self
+    }
+
+    /// Sets the scale of the outlier noise.
+    pub fn scale(mut self, scale: f64) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Builds the Bandit, failing if the outlier probability or scale was not set, no arms were
+    /// added, or a parameter is invalid.
+    pub fn build(self) -> Result<ContaminatedGaussianBandit, RatelError> {
+        let epsilon = self
+            .epsilon
+            .ok_or(RatelError::Missing { name: "epsilon" })?;
+        let scale = self.scale.ok_or(RatelError::Missing { name: "scale" })?;
+        ContaminatedGaussianBandit::try_new(self.means, self.stds, epsilon, scale)
+    }
+}
+
+impl Bandit<f64> for ContaminatedGaussianBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The center of each arm's rewards.
+    fn mean(&self, arm: usize) -> f64 {
+        self.means[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        if rng.gen::<f64>() < self.epsilon {
+            self.means[arm] + self.outliers.sample(rng)
+        } else {
+            self.distributions[arm].sample(rng)
+        }
+    }
+
+    /// The standard deviations of each arm, infinite once any contamination is present.
+    fn std(&self, arm: usize) -> f64 {
+        if self.epsilon > 0.0 {
+            f64::INFINITY
+        } else {
+            self.stds[arm]
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::ContaminatedGaussianBandit;
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = ContaminatedGaussianBandit::new(vec![1.0, 3.0, 2.0], vec![1.0; 3], 0.1, 1.0);
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 1);
+        assert_eq!(bandit.max_reward(), 3.0);
+        assert_eq!(bandit.std(0), f64::INFINITY)
+    }
+
+    #[test]
+    fn test_uncontaminated() {
+        let bandit = ContaminatedGaussianBandit::new(vec![1.0], vec![0.5], 0.0, 1.0);
+        assert_eq!(bandit.std(0), 0.5);
+        let mut rng = thread_rng();
+        let total: f64 = (0..1000).map(|_| bandit.reward(0, &mut rng)).sum();
+        assert!((total / 1000.0 - 1.0).abs() < 0.1)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = ContaminatedGaussianBandit::builder()
+            .arm(1.0, 0.5)
+            .arm(2.0, 0.5)
+            .epsilon(0.1)
+            .scale(10.0)
+            .build()
+            .unwrap();
+        assert_eq!(bandit.best_arm(), 1);
+        assert_eq!(
+            ContaminatedGaussianBandit::builder()
+                .arm(1.0, 0.5)
+                .epsilon(0.1)
+                .build()
+                .err(),
+            Some(RatelError::Missing { name: "scale" })
+        )
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            ContaminatedGaussianBandit::try_new(vec![0.0], vec![1.0], 1.5, 1.0).err(),
+            Some(RatelError::InvalidParameter {
+                name: "epsilon",
+                index: None,
+                value: 1.5,
+                reason: "must be between 0 and 1"
+            })
+        )
+    }
+}
@@ -0,0 +1,68 @@
+use rand::RngCore;
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::ArgBounds;
+
+/// A trait for common members of the Bandits whose rewards depend on a context shown before each
+/// pull.
+pub trait ContextualBandit {
+    /// The number of arms of the Bandit.
+    fn arms(&self) -> usize;
+
+    /// The arm with the highest average reward in the given context.
+    fn best_arm(&self, context: &[f64]) -> usize {
+        self.means(context).arg_max()
+    }
+
+    /// The context of the next pull, drawn using the given random number generator.
+    fn context(&self, rng: &mut dyn RngCore) -> Vec<f64>;
+
+    /// The number of features in each context.
+    fn dimension(&self) -> usize;
+
+    /// The maximum average reward of all the arms in the given context.
+    fn max_reward(&self, context: &[f64]) -> f64 {
+        self.mean(context, self.best_arm(context))
+    }
+
+    /// The average reward of a given arm in the given context.
+    fn mean(&self, context: &[f64], arm: usize) -> f64;
+
+    /// The average rewards of all the arms in the given context.
+    fn means(&self, context: &[f64]) -> Vec<f64> {
+        (0..self.arms())
+            .map(|arm| self.mean(context, arm))
+            .collect()
+    }
+
+    /// The reward from a pull of a given arm in the given context, drawn using the given random
+    /// number generator.
+    fn reward(&self, context: &[f64], arm: usize, rng: &mut dyn RngCore) -> f64;
+}
+
+/// Draws a context whose features are independent Gaussians with variance `1 / dimension`, so
+/// its expected squared length is one.
+pub(crate) fn gaussian_context(dimension: usize, rng: &mut dyn RngCore) -> Vec<f64> {
+    let scale = (dimension as f64).sqrt();
+    (0..dimension)
+        .map(|_| {
+            let z: f64 = StandardNormal.sample(rng);
+            z / scale
+        })
+        .collect()
+}
+
+/// Checks that every arm has the same number of finite coefficients, returning that number.
+pub(crate) fn check_thetas(thetas: &[Vec<f64>]) -> Result<usize, RatelError> {
+    if thetas.is_empty() {
+        return Err(RatelError::Empty { name: "thetas" });
+    }
+    let dimension = thetas[0].len();
+    for theta in thetas {
+        check_len("thetas", theta.len(), dimension)?;
+        check_each("thetas", theta, "must be finite", f64::is_finite)?;
+    }
+    Ok(dimension)
+}
@@ -0,0 +1,355 @@
+use std::marker::PhantomData;
+
+use num_traits::ToPrimitive;
+use rand::distributions::Distribution;
+use rand::{Rng, RngCore};
+
+use crate::error::{check, check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards according to any distributions of the same type.
+///
+/// Each arm holds a `D: Distribution<T>`, from `rand_distr` or written by the user, together
+/// with its mean and standard deviation, either declared or estimated by sampling. Arms whose
+/// distributions have different types can be mixed by boxing each one in a `BoxedDistribution`.
+pub struct DistributionBandit<D, T> {
+    /// Distributions of the arms.
+    distributions: Vec<D>,
+
+    /// Means of the arms.
+    means: Vec<f64>,
+
+    /// Standard deviations of the arms.
+    stds: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<D: Distribution<T>, T: ToPrimitive> DistributionBandit<D, T> {
+    /// Initializes a new Bandit from the distributions of its arms and their declared means and
+    /// standard deviations. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(distributions: Vec<D>, means: Vec<f64>, stds: Vec<f64>) -> DistributionBandit<D, T> {
+        DistributionBandit::try_new(distributions, means, stds).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit from the distributions of its arms and their declared means and
+    /// standard deviations. Fails if there are no arms, if the means or standard deviations do
+    /// not match the arms in number, if a mean is not finite, or if a standard deviation is
+    /// negative. An infinite standard deviation is allowed for heavy-tailed arms.
+    pub fn try_new(
+        distributions: Vec<D>,
+        means: Vec<f64>,
+        stds: Vec<f64>,
+    ) -> Result<DistributionBandit<D, T>, RatelError> {
+        if distributions.is_empty() {
+            return Err(RatelError::Empty {
+                name: "distributions",
+            });
+        }
+        check_len("means", means.len(), distributions.len())?;
+        check_each("means", &means, "must be finite", f64::is_finite)?;
+        check_len("stds", stds.len(), distributions.len())?;
+        check_each("stds", &stds, "must be non-negative", |s| s >= 0.0)?;
+        let arms = distributions.len();
+        let best_arm = means.arg_max();
+        Ok(DistributionBandit {
+            distributions,
+            means,
+            stds,
+            arms,
+            best_arm,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Initializes a new Bandit from the distributions of its arms, estimating each arm's mean
+    /// and standard deviation from the given number of samples. Panics if the parameters are
+    /// invalid; see `try_estimated`.
+    pub fn estimated(
+        distributions: Vec<D>,
+        samples: u32,
+        rng: &mut dyn RngCore,
+    ) -> DistributionBandit<D, T> {
+        DistributionBandit::try_estimated(distributions, samples, rng)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit from the distributions of its arms, estimating each arm's mean
+    /// and standard deviation from the given number of samples drawn with the given random
+    /// number generator. Fails if there are no arms, if there are fewer than two samples, or if
+    /// an estimated mean is not finite.
+    pub fn try_estimated(
+        distributions: Vec<D>,
+        samples: u32,
+        rng: &mut dyn RngCore,
+    ) -> Result<DistributionBandit<D, T>, RatelError> {
+        check("samples", samples, "must be at least 2", |n| n >= 2.0)?;
+        let (means, stds) = distributions
+            .iter()
+            .map(|d| {
+                let (mut mean, mut m2) = (0.0, 0.0);
+                for i in 1..=samples {
+                    let x = d.sample(rng).to_f64().unwrap();
+                    let delta = x - mean;
+                    mean += delta / f64::from(i);
+                    m2 += delta * (x - mean);
+                }
+                (mean, (m2 / f64::from(samples - 1)).sqrt())
+            })
+            .unzip();
+        DistributionBandit::try_new(distributions, means, stds)
+    }
+
+    /// Initializes a new Bandit from the distributions of its arms, taking each arm's mean and
+    /// standard deviation from its distribution. Panics if the parameters are invalid; see
+    /// `try_new`.
+    pub fn from_moments(distributions: Vec<D>) -> DistributionBandit<D, T>
+    where
+        D: Moments,
+    {
+        DistributionBandit::try_from_moments(distributions).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit from the distributions of its arms, taking each arm's mean and
+    /// standard deviation from its distribution. Fails as `try_new` does.
+    pub fn try_from_moments(distributions: Vec<D>) -> Result<DistributionBandit<D, T>, RatelError>
+    where
+        D: Moments,
+    {
+        let means = distributions.iter().map(Moments::mean).collect();
+        let stds = distributions.iter().map(Moments::std).collect();
+        DistributionBandit::try_new(distributions, means, stds)
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> DistributionBanditBuilder<D, T> {
+        DistributionBanditBuilder::default()
+    }
+}
+
+/// Builder for a distribution Bandit that adds one arm at a time.
+#[derive(Clone, Debug)]
+pub struct DistributionBanditBuilder<D, T> {
+    /// Distribution of each arm added.
+    distributions: Vec<D>,
+    /// Declared mean of each arm added.
+    means: Vec<f64>,
+    /// Declared standard deviation of each arm added.
+    stds: Vec<f64>,
+    phantom: PhantomData<T>,
+}
+
+impl<D, T> Default for DistributionBanditBuilder<D, T> {
+    fn default() -> Self {
+        DistributionBanditBuilder {
+            distributions: vec![],
+            means: vec![],
+            stds: vec![],
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<D: Distribution<T>, T: ToPrimitive> DistributionBanditBuilder<D, T> {
+    /// Adds an arm with the given distribution, mean and standard deviation.
+    pub fn arm(mut self, distribution: D, mean: f64, std: f64) -> Self {
+        self.distributions.push(distribution);
+        self.means.push(mean);
+        self.stds.push(std);
+        self
+    }
+
+    /// Adds an arm whose mean and standard deviation are taken from its distribution.
+    pub fn exact_arm(self, distribution: D) -> Self
+    where
+        D: Moments,
+    {
+        let (mean, std) = (distribution.mean(), distribution.std());
+        self.arm(distribution, mean, std)
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<DistributionBandit<D, T>, RatelError> {
+        DistributionBandit::try_new(self.distributions, self.means, self.stds)
+    }
+}
+
+impl<D: Distribution<T>, T: ToPrimitive> Bandit<T> for DistributionBandit<D, T> {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        self.means[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> T {
+        self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        self.stds[arm]
+    }
+}
+
+/// A trait for reward distributions whose mean and standard deviation are known exactly.
+pub trait Moments {
+    /// The mean of the distribution.
+    fn mean(&self) -> f64;
+
+    /// The standard deviation of the distribution.
+    fn std(&self) -> f64;
+}
+
+/// Object-safe form of `Distribution`, whose `sample` is generic over the random number
+/// generator and so cannot be called through a trait object.
+trait DynDistribution<T> {
+    /// Draws a sample with the given random number generator.
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T;
+}
+
+impl<T, D: Distribution<T>> DynDistribution<T> for D {
+    fn sample_dyn(&self, rng: &mut dyn RngCore) -> T {
+        self.sample(rng)
+    }
+}
+
+/// A distribution of any type behind a box, so the arms of a `DistributionBandit` can follow
+/// distributions of different types.
+pub struct BoxedDistribution<T>(Box<dyn DynDistribution<T>>);
+
+impl<T> BoxedDistribution<T> {
+    /// Boxes the given distribution.
+    pub fn new<D: Distribution<T> + 'static>(distribution: D) -> BoxedDistribution<T> {
+        BoxedDistribution(Box::new(distribution))
+    }
+}
+
+impl<T> Distribution<T> for BoxedDistribution<T> {
+    fn sample<R: Rng + ?Sized>(&self, mut rng: &mut R) -> T {
+        self.0.sample_dyn(&mut rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_distr::{Exp, Normal, Poisson, Uniform};
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::super::{EmpiricalDistribution, MixtureDistribution};
+    use super::{BoxedDistribution, DistributionBandit};
+
+    #[test]
+    fn test_declared() {
+        let bandit = DistributionBandit::new(
+            vec![
+                Normal::new(1.0, 0.5).unwrap(),
+                Normal::new(2.0, 1.0).unwrap(),
+            ],
+            vec![1.0, 2.0],
+            vec![0.5, 1.0],
+        );
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.best_arm(), 1);
+        assert_eq!(bandit.max_reward(), 2.0);
+        assert_eq!(bandit.stds(), vec![0.5, 1.0])
+    }
+
+    #[test]
+    fn test_estimated() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bandit = DistributionBandit::estimated(
+            vec![Exp::new(2.0).unwrap(), Exp::new(0.5).unwrap()],
+            10000,
+            &mut rng,
+        );
+        assert_eq!(bandit.best_arm(), 1);
+        assert!((bandit.mean(0) - 0.5).abs() < 0.05);
+        assert!((bandit.std(1) - 2.0).abs() < 0.1);
+        let bandit =
+            DistributionBandit::estimated(vec![Normal::new(1e9, 1.0).unwrap()], 10000, &mut rng);
+        assert!((bandit.mean(0) - 1e9).abs() < 0.05);
+        assert!((bandit.std(0) - 1.0).abs() < 0.05)
+    }
+
+    #[test]
+    fn test_boxed() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let bandit: DistributionBandit<BoxedDistribution<f64>, f64> = DistributionBandit::builder()
+            .arm(BoxedDistribution::new(Uniform::new(0.0, 1.0)), 0.5, 0.3)
+            .arm(
+                BoxedDistribution::new(Poisson::new(3.0).unwrap()),
+                3.0,
+                3f64.sqrt(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(bandit.best_arm(), 1);
+        assert!((0..100).all(|_| bandit.reward(0, &mut rng) < 1.0));
+        let reward = bandit.reward(1, &mut rng);
+        assert_eq!(reward, reward.round())
+    }
+
+    #[test]
+    fn test_from_moments() {
+        let bandit = DistributionBandit::from_moments(vec![
+            EmpiricalDistribution::new(vec![1.0, 3.0]),
+            EmpiricalDistribution::new(vec![0.0, 10.0]),
+        ]);
+        assert_eq!(bandit.best_arm(), 1);
+        assert_eq!(bandit.means(), vec![2.0, 5.0]);
+        assert_eq!(bandit.stds(), vec![1.0, 5.0]);
+        let mixture = MixtureDistribution::builder()
+            .exact_component(1.0, EmpiricalDistribution::new(vec![4.0]))
+            .component(1.0, Normal::new(0.0, 1.0).unwrap(), 0.0, 1.0)
+            .build()
+            .unwrap();
+        let bandit = DistributionBandit::builder()
+            .exact_arm(mixture)
+            .build()
+            .unwrap();
+        assert_eq!(bandit.mean(0), 2.0)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            DistributionBandit::<Exp<f64>, f64>::try_new(vec![], vec![], vec![]).err(),
+            Some(RatelError::Empty {
+                name: "distributions"
+            })
+        );
+        assert_eq!(
+            DistributionBandit::try_new(vec![Exp::new(1.0).unwrap()], vec![1.0], vec![-1.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "stds",
+                index: Some(0),
+                value: -1.0,
+                reason: "must be non-negative"
+            })
+        );
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(
+            DistributionBandit::try_estimated(vec![Exp::new(1.0).unwrap()], 1, &mut rng).is_err()
+        )
+    }
+}
@@ -0,0 +1,148 @@
+use rand::RngCore;
+use rand_distr::{Distribution, StandardNormal};
+
+use crate::error::{check, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A non-stationary bandit whose arm means drift by a Gaussian random walk.
+///
+/// Wraps a stationary Bandit and adds an offset to every arm. After each pull, every offset takes
+/// an independent Gaussian step with standard deviation `drift`, shifting the whole reward
+/// distribution of the arm, so the best arm can change over time.
+pub struct DriftingBandit {
+    /// The stationary Bandit whose rewards are shifted.
+    bandit: Box<dyn Bandit<f64>>,
+
+    /// Standard deviation of each step of the random walks.
+    drift: f64,
+
+    /// Current offset of each arm's mean.
+    offsets: Vec<f64>,
+}
+
+impl DriftingBandit {
+    /// Initializes a new Bandit whose arm means drift away from those of the given Bandit.
+    /// Panics if `drift` is invalid; see `try_new`.
+    pub fn new(bandit: Box<dyn Bandit<f64>>, drift: f64) -> DriftingBandit {
+        DriftingBandit::try_new(bandit, drift).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit whose arm means drift away from those of the given Bandit,
+    /// failing if `drift` is negative or not finite.
+    pub fn try_new(bandit: Box<dyn Bandit<f64>>, drift: f64) -> Result<DriftingBandit, RatelError> {
+        check("drift", drift, "must be non-negative and finite", |d| {
+            d >= 0.0 && d.is_finite()
+        })?;
+        let arms = bandit.arms();
+        Ok(DriftingBandit {
+            bandit,
+            drift,
+            offsets: vec![0.0; arms],
+        })
+    }
+
+    /// Returns a reference to the current offset of each arm's mean.
+    pub fn offsets(&self) -> &Vec<f64> {
+        &self.offsets
+    }
+}
+
+impl Bandit<f64> for DriftingBandit {
+    /// Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.bandit.arms()
+    }
+
+    /// Returns the arm with the highest current average reward.
+    fn best_arm(&self) -> usize {
+        self.means().arg_max()
+    }
+
+    /// The current expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        self.bandit.mean(arm) + self.offsets[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.bandit.reward(arm, rng) + self.offsets[arm]
+    }
+
+    /// Moves every arm's offset one step along its random walk.
+    fn advance(&mut self, rng: &mut dyn RngCore) {
+        self.bandit.advance(rng);
+        for offset in self.offsets.iter_mut() {
+            let z: f64 = StandardNormal.sample(rng);
+            *offset += self.drift * z
+        }
+    }
+
+    /// Returns every arm to the mean of the wrapped Bandit.
+    fn reset(&mut self) {
+        self.bandit.reset();
+        self.offsets = vec![0.0; self.offsets.len()]
+    }
+
+    /// The standard deviations of each arm, unchanged by the drift.
+    fn std(&self, arm: usize) -> f64 {
+        self.bandit.std(arm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::GaussianBandit;
+
+    use super::super::Bandit;
+    use super::DriftingBandit;
+
+    #[test]
+    fn test_advance() {
+        let gauss = GaussianBandit::new(vec![0.0, 0.1, 0.2], vec![1.0; 3]);
+        let mut bandit = DriftingBandit::new(Box::new(gauss), 0.5);
+        assert_eq!(bandit.best_arm(), 2);
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            bandit.advance(&mut rng)
+        }
+        assert!(bandit.offsets().iter().all(|&o| o != 0.0));
+        assert_eq!(bandit.mean(1), 0.1 + bandit.offsets()[1]);
+        assert_eq!(
+            bandit.max_reward(),
+            bandit
+                .means()
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max)
+        );
+        assert_eq!(bandit.std(0), 1.0)
+    }
+
+    #[test]
+    fn test_reset() {
+        let gauss = GaussianBandit::new(vec![0.0, 0.1], vec![1.0; 2]);
+        let mut bandit = DriftingBandit::new(Box::new(gauss), 1.0);
+        bandit.advance(&mut StdRng::seed_from_u64(1));
+        bandit.reset();
+        assert_eq!(bandit.means(), vec![0.0, 0.1])
+    }
+
+    #[test]
+    fn test_zero_drift() {
+        let gauss = GaussianBandit::new(vec![0.0, 0.1], vec![1.0; 2]);
+        let mut bandit = DriftingBandit::new(Box::new(gauss), 0.0);
+        bandit.advance(&mut StdRng::seed_from_u64(1));
+        assert_eq!(bandit.means(), vec![0.0, 0.1])
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_drift() {
+        let gauss = GaussianBandit::new(vec![0.0, 0.1], vec![1.0; 2]);
+        DriftingBandit::new(Box::new(gauss), -1.0);
+    }
+}
@@ -0,0 +1,156 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::error::{check_each, RatelError};
+
+use super::Moments;
+
+/// The empirical distribution of a sample of rewards, resampled with replacement as in the
+/// bootstrap.
+///
+/// The mean and standard deviation are those of the sample itself, which are exactly the mean
+/// and standard deviation of the rewards drawn from it.
+#[derive(Clone, Debug)]
+pub struct EmpiricalDistribution {
+    /// The observed rewards.
+    samples: Vec<f64>,
+
+    /// Mean of the sample.
+    mean: f64,
+
+    /// Standard deviation of the sample, dividing by its size.
+    std: f64,
+}
+
+impl EmpiricalDistribution {
+    /// Initializes the empirical distribution of a sample. Panics if the sample is invalid; see
+    /// `try_new`.
+    pub fn new(samples: Vec<f64>) -> EmpiricalDistribution {
+        EmpiricalDistribution::try_new(samples).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the empirical distribution of a sample, failing if the sample is empty or a
+    /// reward is not finite.
+    pub fn try_new(samples: Vec<f64>) -> Result<EmpiricalDistribution, RatelError> {
+        check_each("samples", &samples, "must be finite", f64::is_finite)?;
+        let n = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / n;
+        let variance = samples.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        Ok(EmpiricalDistribution {
+            samples,
+            mean,
+            std: variance.sqrt(),
+        })
+    }
+
+    /// Reads a sample of rewards separated by commas, whitespace or newlines. Lines starting with
+    /// `#` are skipped. Fails with `InvalidData` if a value is not a number or the sample is
+    /// invalid.
+    pub fn from_reader<R: BufRead>(reader: R) -> io::Result<EmpiricalDistribution> {
+        let mut samples = vec![];
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim_start().starts_with('#') {
+                continue;
+            }
+            for value in line
+                .split(|c: char| c == ',' || c.is_whitespace())
+                .filter(|v| !v.is_empty())
+            {
+                samples.push(value.parse().map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("`{}` is not a reward", value),
+                    )
+                })?)
+            }
+        }
+        EmpiricalDistribution::try_new(samples)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Reads a sample of rewards from a file in the format of `from_reader`.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<EmpiricalDistribution> {
+        EmpiricalDistribution::from_reader(BufReader::new(File::open(path)?))
+    }
+
+    /// Returns the observed rewards.
+    pub fn samples(&self) -> &Vec<f64> {
+        &self.samples
+    }
+}
+
+impl Distribution<f64> for EmpiricalDistribution {
+    /// Draws one of the observed rewards uniformly at random.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.samples[rng.gen_range(0..self.samples.len())]
+    }
+}
+
+impl Moments for EmpiricalDistribution {
+    /// Returns the mean of the sample.
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the sample.
+    fn std(&self) -> f64 {
+        self.std
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use assert_approx_eq::assert_approx_eq;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use crate::RatelError;
+
+    use super::super::Moments;
+    use super::EmpiricalDistribution;
+
+    #[test]
+    fn test_moments() {
+        let empirical = EmpiricalDistribution::new(vec![1.0, 2.0, 3.0, 6.0]);
+        assert_eq!(empirical.mean(), 3.0);
+        assert_approx_eq!(empirical.std(), 3.5f64.sqrt());
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!((0..100).all(|_| empirical.samples().contains(&empirical.sample(&mut rng))))
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let text = "# latency in ms\n1.5, 2.5\n3.5 4.5\n\n";
+        let empirical = EmpiricalDistribution::from_reader(text.as_bytes()).unwrap();
+        assert_eq!(empirical.samples(), &vec![1.5, 2.5, 3.5, 4.5]);
+        let e = EmpiricalDistribution::from_reader("1.0\nslow\n".as_bytes()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(EmpiricalDistribution::from_reader("# empty\n".as_bytes()).is_err())
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            EmpiricalDistribution::try_new(vec![1.0, f64::NAN])
+                .err()
+                .map(|e| e.to_string()),
+            Some(
+                RatelError::InvalidParameter {
+                    name: "samples",
+                    index: Some(1),
+                    value: f64::NAN,
+                    reason: "must be finite"
+                }
+                .to_string()
+            )
+        )
+    }
+}
@@ -1,7 +1,9 @@
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::Exp;
 
+use crate::error::{check_each, RatelError};
+
 use super::{ArgBounds, Bandit};
 
 /// A bandit whose arms distribute rewards according to the exponential distributions.
@@ -21,18 +23,51 @@ pub struct ExponentialBandit {
 
 impl ExponentialBandit {
     /// Initializes a new Bandit where each arm distributes rewards according to an exponential
-    /// distribution.
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
     pub fn new(lambdas: Vec<f64>) -> ExponentialBandit {
-        assert!(lambdas.val_min() > 0.0);
+        ExponentialBandit::try_new(lambdas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to an exponential
+    /// distribution. Fails if there are no rates or a rate is not positive.
+    pub fn try_new(lambdas: Vec<f64>) -> Result<ExponentialBandit, RatelError> {
+        check_each("lambdas", &lambdas, "must be positive and finite", |l| {
+            l > 0.0 && l.is_finite()
+        })?;
         let dist = lambdas.iter().map(|&l| Exp::new(l).unwrap()).collect();
         let arms = lambdas.len();
         let best_arm = lambdas.arg_min();
-        ExponentialBandit {
+        Ok(ExponentialBandit {
             lambdas,
             arms,
             best_arm,
             distributions: dist,
-        }
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> ExponentialBanditBuilder {
+        ExponentialBanditBuilder::default()
+    }
+}
+
+/// Builder for an exponential Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct ExponentialBanditBuilder {
+    /// Rate of each arm added.
+    lambdas: Vec<f64>,
+}
+
+impl ExponentialBanditBuilder {
+    /// Adds an arm with the given rate.
+    pub fn arm(mut self, lambda: f64) -> Self {
+        self.lambdas.push(lambda);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<ExponentialBandit, RatelError> {
+        ExponentialBandit::try_new(self.lambdas)
     }
 }
 
@@ -53,8 +88,8 @@ impl Bandit<f64> for ExponentialBandit {
     }
 
     /// Determines the reward for pulling a given arm.
-    fn reward(&self, arm: usize) -> f64 {
-        self.distributions[arm].sample(&mut thread_rng())
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
     }
 
     /// The standard deviations of each arm.
@@ -66,6 +101,9 @@ impl Bandit<f64> for ExponentialBandit {
 #[cfg(test)]
 mod tests {
     use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
 
     use super::ExponentialBandit;
     use super::super::Bandit;
@@ -115,12 +153,28 @@ mod tests {
         ExponentialBandit::new(lambdas_vec);
     }
 
+    #[test]
+    fn test_builder() {
+        let bandit = ExponentialBandit::builder().arm(0.5).arm(2.0).build().unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.lambdas, vec![0.5, 2.0])
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            ExponentialBandit::try_new(vec![]).err(),
+            Some(RatelError::Empty { name: "lambdas" })
+        )
+    }
+
     #[test]
     fn test_reward() {
         let lambdas_vec: Vec<f64> = vec![6.1, 0.6, 5.4, 9.1, 3.5];
         let exp: ExponentialBandit = ExponentialBandit::new(lambdas_vec);
+        let mut rng = thread_rng();
         for _ in 0..1000 {
-            exp.reward(2);
+            exp.reward(2, &mut rng);
         }
     }
 
@@ -1,7 +1,9 @@
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::Gamma;
 
+use crate::error::{check_each, check_len, RatelError};
+
 use super::{ArgBounds, Bandit};
 
 /// A bandit whose arms distribute rewards according to the gamma distributions.
@@ -24,11 +26,22 @@ pub struct GammaBandit {
 
 impl GammaBandit {
     /// Initializes a new Bandit where each arm distributes rewards according to a gamma
-    /// distribution.
-    fn new(alphas: Vec<f64>, thetas: Vec<f64>) -> GammaBandit {
-        assert_eq!(alphas.len(), thetas.len());
-        assert!(alphas.val_min() > 0.0);
-        assert!(thetas.val_min() > 0.0);
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(alphas: Vec<f64>, thetas: Vec<f64>) -> GammaBandit {
+        GammaBandit::try_new(alphas, thetas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a gamma
+    /// distribution. Fails if the shapes and scales differ in length or if any of them is not
+    /// positive.
+    pub fn try_new(alphas: Vec<f64>, thetas: Vec<f64>) -> Result<GammaBandit, RatelError> {
+        check_each("alphas", &alphas, "must be positive and finite", |a| {
+            a > 0.0 && a.is_finite()
+        })?;
+        check_len("thetas", thetas.len(), alphas.len())?;
+        check_each("thetas", &thetas, "must be positive and finite", |t| {
+            t > 0.0 && t.is_finite()
+        })?;
         let dist = alphas
             .iter()
             .zip(&thetas)
@@ -41,13 +54,41 @@ impl GammaBandit {
             .collect::<Vec<f64>>()
             .arg_max();
         let arms = thetas.len();
-        GammaBandit {
+        Ok(GammaBandit {
             alphas,
             thetas,
             arms,
             best_arm,
             distributions: dist,
-        }
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> GammaBanditBuilder {
+        GammaBanditBuilder::default()
+    }
+}
+
+/// Builder for a gamma Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct GammaBanditBuilder {
+    /// Shape of each arm added.
+    alphas: Vec<f64>,
+    /// Scale of each arm added.
+    thetas: Vec<f64>,
+}
+
+impl GammaBanditBuilder {
+    /// Adds an arm with the given shape and scale.
+    pub fn arm(mut self, alpha: f64, theta: f64) -> Self {
+        self.alphas.push(alpha);
+        self.thetas.push(theta);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<GammaBandit, RatelError> {
+        GammaBandit::try_new(self.alphas, self.thetas)
     }
 }
 
@@ -68,8 +109,8 @@ impl Bandit<f64> for GammaBandit {
     }
 
     /// Determines the reward for pulling a given arm.
-    fn reward(&self, arm: usize) -> f64 {
-        self.distributions[arm].sample(&mut thread_rng())
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
     }
 
     /// The standard deviations of each arm.
@@ -81,6 +122,7 @@ impl Bandit<f64> for GammaBandit {
 #[cfg(test)]
 mod tests {
     use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
 
     use super::GammaBandit;
     use super::super::Bandit;
@@ -153,13 +195,34 @@ mod tests {
         GammaBandit::new(alphas_vec, thetas_vec);
     }
 
+    #[test]
+    fn test_builder() {
+        let bandit = GammaBandit::builder().arm(1.5, 2.0).arm(3.0, 0.5).build().unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.alphas, vec![1.5, 3.0])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let alphas_vec: Vec<f64> = vec![1.3, 9.5, 1.6, 1.9, 8.9];
+        let thetas_vec: Vec<f64> = vec![8.0, -0.7, 3.3, 0.5, 5.9];
+        assert_eq!(
+            GammaBandit::try_new(alphas_vec, thetas_vec)
+                .err()
+                .unwrap()
+                .to_string(),
+            "thetas[1] = -0.7 must be positive and finite"
+        )
+    }
+
     #[test]
     fn test_reward() {
         let alphas_vec: Vec<f64> = vec![1.3, 9.5, 1.6, 1.9, 8.9];
         let thetas_vec: Vec<f64> = vec![8.0, 0.7, 3.3, 0.5, 5.9];
         let gamma: GammaBandit = GammaBandit::new(alphas_vec, thetas_vec);
+        let mut rng = thread_rng();
         for _ in 0..1000 {
-            gamma.reward(2);
+            gamma.reward(2, &mut rng);
         }
     }
 
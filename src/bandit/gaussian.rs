@@ -1,7 +1,9 @@
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::Normal;
 
+use crate::error::{check_each, check_len, RatelError};
+
 use super::{ArgBounds, Bandit};
 
 /// A bandit whose arms distribute rewards according to the Gaussian distributions.
@@ -24,10 +26,20 @@ pub struct GaussianBandit {
 
 impl GaussianBandit {
     /// Initializes a new Bandit where each arm distributes rewards according to a Gaussian
-    /// distribution.
-    fn new(means: Vec<f64>, stds: Vec<f64>) -> GaussianBandit {
-        assert_eq!(means.len(), stds.len());
-        assert!(stds.val_min() > 0.0);
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(means: Vec<f64>, stds: Vec<f64>) -> GaussianBandit {
+        GaussianBandit::try_new(means, stds).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a Gaussian
+    /// distribution. Fails if the means and standard deviations differ in length, if a mean is not
+    /// finite, or if a standard deviation is not positive.
+    pub fn try_new(means: Vec<f64>, stds: Vec<f64>) -> Result<GaussianBandit, RatelError> {
+        check_each("means", &means, "must be finite", f64::is_finite)?;
+        check_len("stds", stds.len(), means.len())?;
+        check_each("stds", &stds, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
         let dist = means
             .iter()
             .zip(&stds)
@@ -35,13 +47,41 @@ impl GaussianBandit {
             .collect();
         let arms = means.len();
         let best_arm = means.arg_max();
-        GaussianBandit {
+        Ok(GaussianBandit {
             means,
             stds,
             arms,
             best_arm,
             distributions: dist,
-        }
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> GaussianBanditBuilder {
+        GaussianBanditBuilder::default()
+    }
+}
+
+/// Builder for a Gaussian Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct GaussianBanditBuilder {
+    /// Mean of each arm added.
+    means: Vec<f64>,
+    /// Standard deviation of each arm added.
+    stds: Vec<f64>,
+}
+
+impl GaussianBanditBuilder {
+    /// Adds an arm with the given mean and standard deviation.
+    pub fn arm(mut self, mean: f64, std: f64) -> Self {
+        self.means.push(mean);
+        self.stds.push(std);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<GaussianBandit, RatelError> {
+        GaussianBandit::try_new(self.means, self.stds)
     }
 }
 
@@ -62,8 +102,8 @@ impl Bandit<f64> for GaussianBandit {
     }
 
     /// Determines the reward for pulling a given arm.
-    fn reward(&self, arm: usize) -> f64 {
-        self.distributions[arm].sample(&mut thread_rng())
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
     }
 
     /// The standard deviations of each arm.
@@ -74,6 +114,10 @@ impl Bandit<f64> for GaussianBandit {
 
 #[cfg(test)]
 mod tests {
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
     use super::GaussianBandit;
     use super::super::Bandit;
 
@@ -133,13 +177,35 @@ mod tests {
         GaussianBandit::new(mean_vec, s);
     }
 
+    #[test]
+    fn test_builder() {
+        let bandit = GaussianBandit::builder().arm(1.0, 0.5).arm(2.0, 1.5).build().unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.means, vec![1.0, 2.0])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let mean_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
+        let s = vec![2.3, 1.25, 0.78, 1.80];
+        assert_eq!(
+            GaussianBandit::try_new(mean_vec, s).err(),
+            Some(RatelError::LengthMismatch {
+                name: "stds",
+                expected: 5,
+                found: 4
+            })
+        )
+    }
+
     #[test]
     fn test_reward() {
         let mean_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let std_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
         let gauss: GaussianBandit = GaussianBandit::new(mean_vec, std_vec);
+        let mut rng = thread_rng();
         for _ in 0..1000 {
-            gauss.reward(2);
+            gauss.reward(2, &mut rng);
         }
     }
 
@@ -0,0 +1,136 @@
+use rand::RngCore;
+use rand_distr::{Distribution, Normal};
+
+use crate::error::{check, RatelError};
+use crate::util::linalg::dot;
+
+use super::contextual::{check_thetas, gaussian_context};
+use super::ContextualBandit;
+
+/// A contextual bandit whose arms pay a linear function of the context plus Gaussian noise.
+///
+/// Each arm has its own coefficients `theta`, and pays `theta^T x` plus noise with standard
+/// deviation `std` in context `x`. Contexts have independent Gaussian features with variance
+/// `1 / dimension`.
+pub struct LinearBandit {
+    /// Coefficients of each arm.
+    thetas: Vec<Vec<f64>>,
+
+    /// Standard deviation of the noise added to every reward.
+    std: f64,
+
+    /// Number of features in each context.
+    dimension: usize,
+
+    /// Distribution of the noise.
+    noise: Normal<f64>,
+}
+
+impl LinearBandit {
+    /// Initializes a new linear contextual Bandit. Panics if the parameters are invalid; see
+    /// `try_new`.
+    pub fn new(thetas: Vec<Vec<f64>>, std: f64) -> LinearBandit {
+        LinearBandit::try_new(thetas, std).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new linear contextual Bandit with one vector of coefficients per arm. Fails
+    /// if there are no arms or coefficients, the arms have different numbers of coefficients, a
+    /// coefficient is not finite, or `std` is negative.
+    pub fn try_new(thetas: Vec<Vec<f64>>, std: f64) -> Result<LinearBandit, RatelError> {
+        let dimension = check_thetas(&thetas)?;
+        check("std", std, "must be non-negative and finite", |s| {
+            s >= 0.0 && s.is_finite()
+        })?;
+        Ok(LinearBandit {
+            thetas,
+            std,
+            dimension,
+            noise: Normal::new(0.0, std).unwrap(),
+        })
+    }
+
+    /// Returns a reference to the coefficients of each arm.
+    pub fn thetas(&self) -> &Vec<Vec<f64>> {
+        &self.thetas
+    }
+
+    /// Returns the standard deviation of the noise.
+    pub fn std(&self) -> f64 {
+        self.std
+    }
+}
+
+impl ContextualBandit for LinearBandit {
+    /// Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.thetas.len()
+    }
+
+    /// Draws the context of the next pull.
+    fn context(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        gaussian_context(self.dimension, rng)
+    }
+
+    /// Returns the number of features in each context.
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// The expected return of an arm in a context.
+    fn mean(&self, context: &[f64], arm: usize) -> f64 {
+        dot(&self.thetas[arm], context)
+    }
+
+    /// Determines the reward for pulling a given arm in a context.
+    fn reward(&self, context: &[f64], arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.mean(context, arm) + self.noise.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::{ContextualBandit, LinearBandit};
+
+    #[test]
+    fn test_mean() {
+        let bandit = LinearBandit::new(vec![vec![1.0, 0.0], vec![0.0, 2.0]], 0.0);
+        assert_eq!(bandit.means(&[1.0, 1.0]), vec![1.0, 2.0]);
+        assert_eq!(bandit.best_arm(&[1.0, 0.0]), 0);
+        assert_eq!(bandit.max_reward(&[1.0, 1.0]), 2.0);
+        assert_eq!(bandit.reward(&[3.0, 1.0], 0, &mut thread_rng()), 3.0)
+    }
+
+    #[test]
+    fn test_context() {
+        let bandit = LinearBandit::new(vec![vec![0.0; 4]], 1.0);
+        let mut rng = thread_rng();
+        let contexts: Vec<Vec<f64>> = (0..1000).map(|_| bandit.context(&mut rng)).collect();
+        assert!(contexts.iter().all(|x| x.len() == 4));
+        let length = contexts
+            .iter()
+            .map(|x| x.iter().map(|f| f * f).sum::<f64>())
+            .sum::<f64>()
+            / 1000.0;
+        assert!((length - 1.0).abs() < 0.2)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            LinearBandit::try_new(vec![vec![1.0, 0.0], vec![0.0]], 1.0).err(),
+            Some(RatelError::LengthMismatch {
+                name: "thetas",
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            LinearBandit::try_new(vec![], 1.0).err(),
+            Some(RatelError::Empty { name: "thetas" })
+        )
+    }
+}
@@ -1,16 +1,18 @@
 use rand::distributions::Distribution;
-use rand::thread_rng;
+use rand::RngCore;
 use rand_distr::LogNormal;
 
+use crate::error::{check_each, check_len, RatelError};
+
 use super::{ArgBounds, Bandit};
 
 /// A bandit whose arms distribute rewards according to the Log Normal distributions.
-pub struct LogNormalBandit<'a> {
+pub struct LogNormalBandit {
     /// Means base normal distributions
-    mus: &'a Vec<f64>,
+    mus: Vec<f64>,
 
     /// Standard deviations of the base normal distributions
-    sigmas: &'a Vec<f64>,
+    sigmas: Vec<f64>,
 
     /// Number of arms on the bandit.
     arms: usize,
@@ -22,34 +24,73 @@ pub struct LogNormalBandit<'a> {
     distributions: Vec<LogNormal<f64>>,
 }
 
-impl<'a> LogNormalBandit<'a> {
+impl LogNormalBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Log Normal
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(mus: Vec<f64>, sigmas: Vec<f64>) -> LogNormalBandit {
+        LogNormalBandit::try_new(mus, sigmas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
     /// Initializes a new Bandit where each arm distributes rewards according to a Log Normal
-    /// distribution.
-    fn new(mus: &'a Vec<f64>, sigmas: &'a Vec<f64>) -> LogNormalBandit<'a> {
-        assert_eq!(mus.len(), sigmas.len());
-        assert!(sigmas.val_min() > 0.0);
+    /// distribution. Fails if the means and standard deviations differ in length, if a mean is not
+    /// finite, or if a standard deviation is not positive.
+    pub fn try_new(mus: Vec<f64>, sigmas: Vec<f64>) -> Result<LogNormalBandit, RatelError> {
+        check_each("mus", &mus, "must be finite", f64::is_finite)?;
+        check_len("sigmas", sigmas.len(), mus.len())?;
+        check_each("sigmas", &sigmas, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
         let dist = mus
             .iter()
-            .zip(sigmas)
+            .zip(&sigmas)
             .map(|(&m, &s)| LogNormal::new(m, s).unwrap())
             .collect();
         let best_arm = mus
             .iter()
-            .zip(sigmas)
+            .zip(&sigmas)
             .map(|(&m, &s)| m + s * s / 2.0)
             .collect::<Vec<f64>>()
             .arg_max();
-        LogNormalBandit {
+        let arms = mus.len();
+        Ok(LogNormalBandit {
             mus,
             sigmas,
-            arms: mus.len(),
+            arms,
             best_arm,
             distributions: dist,
-        }
+        })
     }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> LogNormalBanditBuilder {
+        LogNormalBanditBuilder::default()
+    }
+}
+
+/// Builder for a log-normal Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct LogNormalBanditBuilder {
+    /// Mean of the underlying normal of each arm added.
+    mus: Vec<f64>,
+    /// Standard deviation of the underlying normal of each arm added.
+    sigmas: Vec<f64>,
 }
 
-impl<'a> Bandit<f64> for LogNormalBandit<'a> {
+impl LogNormalBanditBuilder {
+    /// Adds an arm whose underlying normal has the given mean and standard deviation.
+    pub fn arm(mut self, mu: f64, sigma: f64) -> Self {
+        self.mus.push(mu);
+        self.sigmas.push(sigma);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<LogNormalBandit, RatelError> {
+        LogNormalBandit::try_new(self.mus, self.sigmas)
+    }
+}
+
+impl Bandit<f64> for LogNormalBandit {
     ///Returns the number of arms on the bandit.
     fn arms(&self) -> usize {
         self.arms
@@ -66,8 +107,8 @@ impl<'a> Bandit<f64> for LogNormalBandit<'a> {
     }
 
     /// Determines the reward for pulling a given arm.
-    fn reward(&self, arm: usize) -> f64 {
-        self.distributions[arm].sample(&mut thread_rng())
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
     }
 
     /// The standard deviations of each arm.
@@ -81,6 +122,7 @@ impl<'a> Bandit<f64> for LogNormalBandit<'a> {
 #[cfg(test)]
 mod tests {
     use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
 
     use super::LogNormalBandit;
     use super::super::Bandit;
@@ -89,7 +131,7 @@ mod tests {
     fn test_arms() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         assert_eq!(log_norm.arms(), 5)
     }
 
@@ -97,7 +139,7 @@ mod tests {
     fn test_best_arm() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         assert_eq!(log_norm.best_arm(), 3)
     }
 
@@ -105,7 +147,7 @@ mod tests {
     fn test_max_reward() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         assert_approx_eq!(log_norm.max_reward(), 68.71723217)
     }
 
@@ -113,7 +155,7 @@ mod tests {
     fn test_mean() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         assert_approx_eq!(log_norm.mean(1), 0.96199118)
     }
 
@@ -121,7 +163,7 @@ mod tests {
     fn test_means() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         log_norm
             .means()
             .iter()
@@ -140,7 +182,7 @@ mod tests {
     fn test_new_wrong_size() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80];
-        LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        LogNormalBandit::new(mus_vec, sigmas_vec);
     }
 
     #[test]
@@ -148,16 +190,31 @@ mod tests {
     fn test_new_neg_sigma() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, -1.80, 1.55];
-        LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        LogNormalBandit::new(mus_vec, sigmas_vec);
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = LogNormalBandit::builder().arm(0.1, 0.5).arm(0.2, 1.0).build().unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.mus, vec![0.1, 0.2])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let mus_vec: Vec<f64> = vec![-1.83, -0.82, f64::INFINITY, 2.61, 0.39];
+        let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
+        assert!(LogNormalBandit::try_new(mus_vec, sigmas_vec).is_err())
     }
 
     #[test]
     fn test_reward() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
+        let mut rng = thread_rng();
         for _ in 0..1000 {
-            log_norm.reward(2);
+            log_norm.reward(2, &mut rng);
         }
     }
 
@@ -165,7 +222,7 @@ mod tests {
     fn test_std() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         assert_approx_eq!(log_norm.std(1), 1.86803062)
     }
 
@@ -173,7 +230,7 @@ mod tests {
     fn test_stds() {
         let mus_vec: Vec<f64> = vec![-1.83, -0.82, -1.35, 2.61, 0.39];
         let sigmas_vec: Vec<f64> = vec![2.3, 1.25, 0.78, 1.80, 1.55];
-        let log_norm: LogNormalBandit = LogNormalBandit::new(&mus_vec, &sigmas_vec);
+        let log_norm: LogNormalBandit = LogNormalBandit::new(mus_vec, sigmas_vec);
         log_norm
             .stds()
             .iter()
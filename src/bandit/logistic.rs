@@ -0,0 +1,102 @@
+use rand::RngCore;
+use rand_distr::{Bernoulli, Distribution};
+
+use crate::error::RatelError;
+use crate::util::linalg::dot;
+
+use super::contextual::{check_thetas, gaussian_context};
+use super::ContextualBandit;
+
+/// A contextual bandit whose arms pay one or zero, with a logistic model of the probability of
+/// one.
+///
+/// Each arm has its own coefficients `theta`, and pays one with probability
+/// `1 / (1 + exp(-theta^T x))` in context `x`. Contexts have independent Gaussian features with
+/// variance `1 / dimension`.
+pub struct LogisticBandit {
+    /// Coefficients of each arm.
+    thetas: Vec<Vec<f64>>,
+
+    /// Number of features in each context.
+    dimension: usize,
+}
+
+impl LogisticBandit {
+    /// Initializes a new logistic contextual Bandit. Panics if the coefficients are invalid; see
+    /// `try_new`.
+    pub fn new(thetas: Vec<Vec<f64>>) -> LogisticBandit {
+        LogisticBandit::try_new(thetas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new logistic contextual Bandit with one vector of coefficients per arm.
+    /// Fails if there are no arms or coefficients, the arms have different numbers of
+    /// coefficients, or a coefficient is not finite.
+    pub fn try_new(thetas: Vec<Vec<f64>>) -> Result<LogisticBandit, RatelError> {
+        let dimension = check_thetas(&thetas)?;
+        Ok(LogisticBandit { thetas, dimension })
+    }
+
+    /// Returns a reference to the coefficients of each arm.
+    pub fn thetas(&self) -> &Vec<Vec<f64>> {
+        &self.thetas
+    }
+}
+
+impl ContextualBandit for LogisticBandit {
+    /// Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.thetas.len()
+    }
+
+    /// Draws the context of the next pull.
+    fn context(&self, rng: &mut dyn RngCore) -> Vec<f64> {
+        gaussian_context(self.dimension, rng)
+    }
+
+    /// Returns the number of features in each context.
+    fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// The probability that an arm pays one in a context.
+    fn mean(&self, context: &[f64], arm: usize) -> f64 {
+        1.0 / (1.0 + (-dot(&self.thetas[arm], context)).exp())
+    }
+
+    /// Determines the reward for pulling a given arm in a context.
+    fn reward(&self, context: &[f64], arm: usize, rng: &mut dyn RngCore) -> f64 {
+        let win = Bernoulli::new(self.mean(context, arm)).unwrap().sample(rng);
+        f64::from(u8::from(win))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use super::{ContextualBandit, LogisticBandit};
+
+    #[test]
+    fn test_mean() {
+        let bandit = LogisticBandit::new(vec![vec![0.0, 0.0], vec![2f64.ln(), 0.0]]);
+        assert_approx_eq!(bandit.mean(&[1.0, 5.0], 0), 0.5);
+        assert_approx_eq!(bandit.mean(&[1.0, 5.0], 1), 2.0 / 3.0);
+        assert_eq!(bandit.best_arm(&[-1.0, 0.0]), 0)
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = LogisticBandit::new(vec![vec![1.0], vec![-1.0]]);
+        let mut rng = thread_rng();
+        let wins: f64 = (0..1000).map(|_| bandit.reward(&[3.0], 1, &mut rng)).sum();
+        assert!(wins < 150.0);
+        assert!([0.0, 1.0].contains(&bandit.reward(&[0.0], 0, &mut rng)))
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_finite() {
+        LogisticBandit::new(vec![vec![f64::NAN]]);
+    }
+}
@@ -0,0 +1,216 @@
+use rand::distributions::Distribution;
+use rand::Rng;
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::{BoxedDistribution, Moments};
+
+/// A finite mixture of reward distributions: each reward comes from a component chosen at
+/// random with probability proportional to its weight.
+///
+/// The components may be of any types. Their means and standard deviations are declared, and
+/// the mixture's own are computed exactly from them.
+pub struct MixtureDistribution {
+    /// Component probabilities, summing to one.
+    weights: Vec<f64>,
+
+    /// Component distributions.
+    components: Vec<BoxedDistribution<f64>>,
+
+    /// Mean of the mixture.
+    mean: f64,
+
+    /// Standard deviation of the mixture.
+    std: f64,
+}
+
+impl MixtureDistribution {
+    /// Initializes a mixture from the weights, distributions, means and standard deviations of
+    /// its components. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(
+        weights: Vec<f64>,
+        components: Vec<BoxedDistribution<f64>>,
+        means: Vec<f64>,
+        stds: Vec<f64>,
+    ) -> MixtureDistribution {
+        MixtureDistribution::try_new(weights, components, means, stds)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a mixture from the weights, distributions, means and standard deviations of
+    /// its components. The weights need not sum to one. Fails if there are no components, if the
+    /// parameters differ in length, if a weight is not positive, if a mean is not finite, or if a
+    /// standard deviation is negative.
+    pub fn try_new(
+        weights: Vec<f64>,
+        components: Vec<BoxedDistribution<f64>>,
+        means: Vec<f64>,
+        stds: Vec<f64>,
+    ) -> Result<MixtureDistribution, RatelError> {
+        check_each("weights", &weights, "must be positive and finite", |w| {
+            w > 0.0 && w.is_finite()
+        })?;
+        check_len("components", components.len(), weights.len())?;
+        check_len("means", means.len(), weights.len())?;
+        check_each("means", &means, "must be finite", f64::is_finite)?;
+        check_len("stds", stds.len(), weights.len())?;
+        check_each("stds", &stds, "must be non-negative", |s| s >= 0.0)?;
+        let total: f64 = weights.iter().sum();
+        let weights: Vec<f64> = weights.iter().map(|w| w / total).collect();
+        let mean = weights.iter().zip(&means).map(|(w, m)| w * m).sum::<f64>();
+        let second = weights
+            .iter()
+            .zip(means.iter().zip(&stds))
+            .map(|(w, (m, s))| w * (s * s + m * m))
+            .sum::<f64>();
+        Ok(MixtureDistribution {
+            weights,
+            components,
+            mean,
+            std: (second - mean * mean).max(0.0).sqrt(),
+        })
+    }
+
+    /// Returns a builder that adds the components of the mixture one at a time.
+    pub fn builder() -> MixtureDistributionBuilder {
+        MixtureDistributionBuilder::default()
+    }
+
+    /// Returns the probability of each component.
+    pub fn weights(&self) -> &Vec<f64> {
+        &self.weights
+    }
+}
+
+impl Distribution<f64> for MixtureDistribution {
+    /// Draws a component by its weight, then a reward from the component.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let mut u: f64 = rng.gen();
+        let last = self.weights.len() - 1;
+        let component = self
+            .weights
+            .iter()
+            .position(|&w| {
+                u -= w;
+                u < 0.0
+            })
+            .unwrap_or(last);
+        self.components[component].sample(rng)
+    }
+}
+
+impl Moments for MixtureDistribution {
+    /// Returns the weighted mean of the component means.
+    fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the standard deviation of the mixture, from the law of total variance.
+    fn std(&self) -> f64 {
+        self.std
+    }
+}
+
+/// Builder for a mixture that adds one component at a time.
+#[derive(Default)]
+pub struct MixtureDistributionBuilder {
+    /// Weight of each component added.
+    weights: Vec<f64>,
+    /// Distribution of each component added.
+    components: Vec<BoxedDistribution<f64>>,
+    /// Mean of each component added.
+    means: Vec<f64>,
+    /// Standard deviation of each component added.
+    stds: Vec<f64>,
+}
+
+impl MixtureDistributionBuilder {
+    /// Adds a component with the given weight, distribution, mean and standard deviation.
+    pub fn component<D: Distribution<f64> + 'static>(
+        mut self,
+        weight: f64,
+        distribution: D,
+        mean: f64,
+        std: f64,
+    ) -> Self {
+        self.weights.push(weight);
+        self.components.push(BoxedDistribution::new(distribution));
+        self.means.push(mean);
+        self.stds.push(std);
+        self
+    }
+
+    /// Adds a component whose mean and standard deviation are known exactly, such as an
+    /// empirical distribution or another mixture.
+    pub fn exact_component<D: Distribution<f64> + Moments + 'static>(
+        self,
+        weight: f64,
+        distribution: D,
+    ) -> Self {
+        let (mean, std) = (distribution.mean(), distribution.std());
+        self.component(weight, distribution, mean, std)
+    }
+
+    /// Builds the mixture, failing if no components were added or a parameter is invalid.
+    pub fn build(self) -> Result<MixtureDistribution, RatelError> {
+        MixtureDistribution::try_new(self.weights, self.components, self.means, self.stds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rand_distr::Normal;
+
+    use crate::RatelError;
+
+    use super::super::Moments;
+    use super::MixtureDistribution;
+
+    fn mixture() -> MixtureDistribution {
+        MixtureDistribution::builder()
+            .component(3.0, Normal::new(0.0, 1.0).unwrap(), 0.0, 1.0)
+            .component(1.0, Normal::new(10.0, 2.0).unwrap(), 10.0, 2.0)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_moments() {
+        let mixture = mixture();
+        assert_eq!(mixture.weights(), &vec![0.75, 0.25]);
+        assert_approx_eq!(mixture.mean(), 2.5);
+        assert_approx_eq!(mixture.std(), (0.75 + 0.25 * 104.0 - 6.25f64).sqrt())
+    }
+
+    #[test]
+    fn test_sample() {
+        let mixture = mixture();
+        let mut rng = StdRng::seed_from_u64(0);
+        let high = (0..4000).filter(|_| mixture.sample(&mut rng) > 5.0).count();
+        assert!((900..1100).contains(&high))
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            MixtureDistribution::builder()
+                .component(0.0, Normal::new(0.0, 1.0).unwrap(), 0.0, 1.0)
+                .build()
+                .err(),
+            Some(RatelError::InvalidParameter {
+                name: "weights",
+                index: Some(0),
+                value: 0.0,
+                reason: "must be positive and finite"
+            })
+        );
+        assert_eq!(
+            MixtureDistribution::builder().build().err(),
+            Some(RatelError::Empty { name: "weights" })
+        )
+    }
+}
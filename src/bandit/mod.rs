@@ -1,17 +1,51 @@
 //! This module contains the Bandits whose behavior the Agents study.
 
 pub use bandit::Bandit;
-pub use binomial::BinomialBandit;
-pub use exponential::ExponentialBandit;
-pub use gamma::GammaBandit;
-pub use gaussian::GaussianBandit;
-pub use log_normal::LogNormalBandit;
+pub use bernoulli::{BernoulliBandit, BernoulliBanditBuilder};
+pub use beta::{BetaBandit, BetaBanditBuilder};
+pub use binomial::{BinomialBandit, BinomialBanditBuilder};
+pub use contaminated::{ContaminatedGaussianBandit, ContaminatedGaussianBanditBuilder};
+pub use contextual::ContextualBandit;
+pub use distribution::{BoxedDistribution, DistributionBandit, DistributionBanditBuilder, Moments};
+pub use drifting::DriftingBandit;
+pub use empirical::EmpiricalDistribution;
+pub use exponential::{ExponentialBandit, ExponentialBanditBuilder};
+pub use gamma::{GammaBandit, GammaBanditBuilder};
+pub use gaussian::{GaussianBandit, GaussianBanditBuilder};
+pub use linear::LinearBandit;
+pub use log_normal::{LogNormalBandit, LogNormalBanditBuilder};
+pub use logistic::LogisticBandit;
+pub use mixture::{MixtureDistribution, MixtureDistributionBuilder};
+pub use pareto::{ParetoBandit, ParetoBanditBuilder};
+pub use poisson::{PoissonBandit, PoissonBanditBuilder};
+pub use replay::{LoggedEvent, ReplayBandit};
+pub use student_t::{StudentTBandit, StudentTBanditBuilder};
+pub use switching::SwitchingBandit;
+pub use uniform::{UniformBandit, UniformBanditBuilder};
+pub use weibull::{WeibullBandit, WeibullBanditBuilder};
 
 use super::util::ArgBounds;
 
 mod bandit;
+mod bernoulli;
+mod beta;
 mod binomial;
+mod contaminated;
+mod contextual;
+mod distribution;
+mod drifting;
+mod empirical;
 mod exponential;
 mod gamma;
 mod gaussian;
+mod linear;
 mod log_normal;
+mod logistic;
+mod mixture;
+mod pareto;
+mod poisson;
+mod replay;
+mod student_t;
+mod switching;
+mod uniform;
+mod weibull;
@@ -0,0 +1,193 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Pareto;
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards according to Pareto distributions.
+///
+/// An arm's rewards are at least its scale, with a power-law tail whose weight grows as the
+/// shape falls: the variance is infinite for a shape of two or less.
+pub struct ParetoBandit {
+    /// Vector of the smallest possible rewards of the arms.
+    scales: Vec<f64>,
+
+    /// Vector of the tail indices of the arms.
+    shapes: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Pareto<f64>>,
+}
+
+impl ParetoBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Pareto
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(scales: Vec<f64>, shapes: Vec<f64>) -> ParetoBandit {
+        ParetoBandit::try_new(scales, shapes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a Pareto
+    /// distribution. Fails if the scales and shapes differ in length, if a scale is not
+    /// positive, or if a shape is not above one, where the mean would be infinite.
+    pub fn try_new(scales: Vec<f64>, shapes: Vec<f64>) -> Result<ParetoBandit, RatelError> {
+        check_each("scales", &scales, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        check_len("shapes", shapes.len(), scales.len())?;
+        check_each(
+            "shapes",
+            &shapes,
+            "must be greater than 1 and finite",
+            |a| a > 1.0 && a.is_finite(),
+        )?;
+        let dist = scales
+            .iter()
+            .zip(&shapes)
+            .map(|(&s, &a)| Pareto::new(s, a).unwrap())
+            .collect();
+        let best_arm = scales
+            .iter()
+            .zip(&shapes)
+            .map(|(s, a)| a * s / (a - 1.0))
+            .collect::<Vec<f64>>()
+            .arg_max();
+        let arms = scales.len();
+        Ok(ParetoBandit {
+            scales,
+            shapes,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> ParetoBanditBuilder {
+        ParetoBanditBuilder::default()
+    }
+}
+
+/// Builder for a Pareto Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct ParetoBanditBuilder {
+    /// Scale of each arm added.
+    scales: Vec<f64>,
+    /// Shape of each arm added.
+    shapes: Vec<f64>,
+}
+
+impl ParetoBanditBuilder {
+    /// Adds an arm with the given scale and shape.
+    pub fn arm(mut self, scale: f64, shape: f64) -> Self {
+        self.scales.push(scale);
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<ParetoBandit, RatelError> {
+        ParetoBandit::try_new(self.scales, self.shapes)
+    }
+}
+
+impl Bandit<f64> for ParetoBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        let a = self.shapes[arm];
+        a * self.scales[arm] / (a - 1.0)
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm, infinite for a shape of two or less.
+    fn std(&self, arm: usize) -> f64 {
+        let a = self.shapes[arm];
+        if a > 2.0 {
+            self.scales[arm] / (a - 1.0) * (a / (a - 2.0)).sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::ParetoBandit;
+
+    fn pareto() -> ParetoBandit {
+        ParetoBandit::new(vec![1.0, 1.0, 2.0], vec![3.0, 1.5, 4.0])
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = pareto();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 1);
+        bandit
+            .means()
+            .iter()
+            .zip(vec![1.5, 3.0, 8.0 / 3.0])
+            .for_each(|(m1, m2)| assert_approx_eq!(m1, m2))
+    }
+
+    #[test]
+    fn test_std() {
+        let bandit = pareto();
+        assert_approx_eq!(bandit.std(0), 0.5 * 3f64.sqrt());
+        assert_eq!(bandit.std(1), f64::INFINITY);
+        assert_approx_eq!(bandit.std(2), 2.0 / 3.0 * 2f64.sqrt())
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = pareto();
+        let mut rng = thread_rng();
+        assert!((0..1000).all(|_| bandit.reward(2, &mut rng) >= 2.0))
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = ParetoBandit::builder().arm(1.0, 2.0).build().unwrap();
+        assert_eq!(bandit.mean(0), 2.0)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            ParetoBandit::try_new(vec![1.0], vec![1.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "shapes",
+                index: Some(0),
+                value: 1.0,
+                reason: "must be greater than 1 and finite"
+            })
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Poisson;
+
+use crate::error::{check_each, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms pay counts drawn from Poisson distributions.
+pub struct PoissonBandit {
+    /// Vector of the distribution means.
+    lambdas: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Poisson<f64>>,
+}
+
+impl PoissonBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Poisson
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(lambdas: Vec<f64>) -> PoissonBandit {
+        PoissonBandit::try_new(lambdas).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a Poisson
+    /// distribution. Fails if there are no rates or a rate is not positive.
+    pub fn try_new(lambdas: Vec<f64>) -> Result<PoissonBandit, RatelError> {
+        check_each("lambdas", &lambdas, "must be positive and finite", |l| {
+            l > 0.0 && l.is_finite()
+        })?;
+        let dist = lambdas.iter().map(|&l| Poisson::new(l).unwrap()).collect();
+        let arms = lambdas.len();
+        let best_arm = lambdas.arg_max();
+        Ok(PoissonBandit {
+            lambdas,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> PoissonBanditBuilder {
+        PoissonBanditBuilder::default()
+    }
+}
+
+/// Builder for a Poisson Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct PoissonBanditBuilder {
+    /// Rate of each arm added.
+    lambdas: Vec<f64>,
+}
+
+impl PoissonBanditBuilder {
+    /// Adds an arm with the given rate.
+    pub fn arm(mut self, lambda: f64) -> Self {
+        self.lambdas.push(lambda);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<PoissonBandit, RatelError> {
+        PoissonBandit::try_new(self.lambdas)
+    }
+}
+
+impl Bandit<u32> for PoissonBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        self.lambdas[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> u32 {
+        self.distributions[arm].sample(rng) as u32
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        self.lambdas[arm].sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::PoissonBandit;
+
+    lazy_static! {
+        static ref LAMBDAS_VEC: Vec<f64> = vec![2.0, 9.0, 0.5, 4.0];
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let poisson = PoissonBandit::new(LAMBDAS_VEC.to_vec());
+        assert_eq!(poisson.arms(), 4);
+        assert_eq!(poisson.best_arm(), 1);
+        assert_eq!(poisson.max_reward(), 9.0)
+    }
+
+    #[test]
+    fn test_std() {
+        let poisson = PoissonBandit::new(LAMBDAS_VEC.to_vec());
+        assert_eq!(poisson.mean(3), 4.0);
+        poisson
+            .stds()
+            .iter()
+            .zip(vec![2f64.sqrt(), 3.0, 0.5f64.sqrt(), 2.0])
+            .for_each(|(s1, s2)| assert_approx_eq!(s1, s2))
+    }
+
+    #[test]
+    fn test_reward() {
+        let poisson = PoissonBandit::new(vec![3.0]);
+        let mut rng = thread_rng();
+        let total: u32 = (0..1000).map(|_| poisson.reward(0, &mut rng)).sum();
+        assert!(total > 2700 && total < 3300)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = PoissonBandit::builder().arm(1.0).arm(2.0).build().unwrap();
+        assert_eq!(bandit.lambdas, vec![1.0, 2.0])
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            PoissonBandit::try_new(vec![1.0, 0.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "lambdas",
+                index: Some(1),
+                value: 0.0,
+                reason: "must be positive and finite"
+            })
+        )
+    }
+}
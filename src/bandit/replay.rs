@@ -0,0 +1,208 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use crate::error::RatelError;
+
+/// A single logged pull: the arm the logging policy chose, the reward it received, and, if
+/// recorded, the probability the logging policy had of choosing that arm.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LoggedEvent {
+    /// The arm pulled.
+    pub arm: usize,
+    /// The reward received.
+    pub reward: f64,
+    /// Probability the logging policy chose the arm. An event without one is taken to have been
+    /// logged by a uniformly random policy.
+    pub propensity: Option<f64>,
+}
+
+/// A bandit whose rewards are a log of pulls made by some other policy, for evaluating Agents
+/// offline with the replay method of Li, Chu, Langford, and Wang (2011). See `ReplayGame`.
+#[derive(Clone, Debug)]
+pub struct ReplayBandit {
+    /// Number of arms the logging policy chose between.
+    arms: usize,
+
+    /// The logged pulls, in the order they happened.
+    events: Vec<LoggedEvent>,
+}
+
+impl ReplayBandit {
+    /// Initializes a Bandit from logged pulls of the given number of arms. Panics if the log is
+    /// invalid; see `try_new`.
+    pub fn new(arms: usize, events: Vec<LoggedEvent>) -> ReplayBandit {
+        ReplayBandit::try_new(arms, events).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Bandit from logged pulls of the given number of arms. Fails if there are no
+    /// arms or no events, or if an event has an arm out of range, a reward that is not finite,
+    /// or a propensity outside `(0, 1]`.
+    pub fn try_new(arms: usize, events: Vec<LoggedEvent>) -> Result<ReplayBandit, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        if events.is_empty() {
+            return Err(RatelError::Empty { name: "events" });
+        }
+        for (index, event) in events.iter().enumerate() {
+            let invalid = |name, value, reason| RatelError::InvalidParameter {
+                name,
+                index: Some(index),
+                value,
+                reason,
+            };
+            if event.arm >= arms {
+                return Err(invalid(
+                    "arm",
+                    event.arm as f64,
+                    "must be less than the number of arms",
+                ));
+            }
+            if !event.reward.is_finite() {
+                return Err(invalid("reward", event.reward, "must be finite"));
+            }
+            if let Some(p) = event.propensity {
+                if !(p > 0.0 && p <= 1.0) {
+                    return Err(invalid("propensity", p, "must be in (0, 1]"));
+                }
+            }
+        }
+        Ok(ReplayBandit { arms, events })
+    }
+
+    /// Reads logged pulls of the given number of arms as CSV rows of `arm,reward` or
+    /// `arm,reward,propensity`, with arms numbered from zero. A header row starting with `arm`,
+    /// blank lines and lines starting with `#` are skipped. Arms the logging policy never chose
+    /// still count towards the default propensity. Fails with `InvalidData` if a row cannot be
+    /// parsed or the log is invalid; see `try_new`.
+    pub fn from_reader<R: BufRead>(arms: usize, reader: R) -> io::Result<ReplayBandit> {
+        let mut events = vec![];
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with("arm") {
+                continue;
+            }
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "line {}: `{}` is not arm,reward[,propensity]",
+                        number + 1,
+                        line
+                    ),
+                )
+            };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let event = match fields[..] {
+                [arm, reward] => LoggedEvent {
+                    arm: arm.parse().map_err(|_| invalid())?,
+                    reward: reward.parse().map_err(|_| invalid())?,
+                    propensity: None,
+                },
+                [arm, reward, propensity] => LoggedEvent {
+                    arm: arm.parse().map_err(|_| invalid())?,
+                    reward: reward.parse().map_err(|_| invalid())?,
+                    propensity: Some(propensity.parse().map_err(|_| invalid())?),
+                },
+                _ => return Err(invalid()),
+            };
+            events.push(event)
+        }
+        ReplayBandit::try_new(arms, events)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Reads logged pulls of the given number of arms from a CSV file in the format of
+    /// `from_reader`.
+    pub fn from_file<P: AsRef<Path>>(arms: usize, path: P) -> io::Result<ReplayBandit> {
+        ReplayBandit::from_reader(arms, BufReader::new(File::open(path)?))
+    }
+
+    ///Returns the number of arms on the bandit.
+    pub fn arms(&self) -> usize {
+        self.arms
+    }
+
+    /// Returns the logged pulls.
+    pub fn events(&self) -> &Vec<LoggedEvent> {
+        &self.events
+    }
+
+    /// Returns the probability the logging policy had of choosing the arm of an event.
+    pub fn propensity(&self, event: &LoggedEvent) -> f64 {
+        event.propensity.unwrap_or(1.0 / self.arms as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use crate::RatelError;
+
+    use super::{LoggedEvent, ReplayBandit};
+
+    #[test]
+    fn test_from_reader() {
+        let text = "arm,reward,propensity\n0,1.0,0.5\n# control\n2, 0.0, 0.25\n";
+        let bandit = ReplayBandit::from_reader(3, text.as_bytes()).unwrap();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(
+            bandit.events()[1],
+            LoggedEvent {
+                arm: 2,
+                reward: 0.0,
+                propensity: Some(0.25)
+            }
+        );
+        let bandit = ReplayBandit::from_reader(2, "1,2.5\n0,1.5\n".as_bytes()).unwrap();
+        assert_eq!(bandit.arms(), 2);
+        assert_eq!(bandit.propensity(&bandit.events()[0]), 0.5);
+        let e = ReplayBandit::from_reader(2, "0,1.0,0.5,7\n".as_bytes()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        let e = ReplayBandit::from_reader(2, "2,1.0\n".as_bytes()).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(ReplayBandit::from_reader(2, "arm,reward\n".as_bytes()).is_err())
+    }
+
+    #[test]
+    fn test_from_reader_unlogged_arm() {
+        // The logging policy chose uniformly among three arms but never happened to pick arm 2.
+        let bandit = ReplayBandit::from_reader(3, "0,1.0\n1,0.0\n0,0.5\n".as_bytes()).unwrap();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.propensity(&bandit.events()[0]), 1.0 / 3.0)
+    }
+
+    #[test]
+    fn test_try_new() {
+        let event = |arm, propensity| LoggedEvent {
+            arm,
+            reward: 1.0,
+            propensity,
+        };
+        assert_eq!(
+            ReplayBandit::try_new(2, vec![event(0, None), event(2, None)]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "arm",
+                index: Some(1),
+                value: 2.0,
+                reason: "must be less than the number of arms"
+            })
+        );
+        assert_eq!(
+            ReplayBandit::try_new(2, vec![event(0, Some(0.0))]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "propensity",
+                index: Some(0),
+                value: 0.0,
+                reason: "must be in (0, 1]"
+            })
+        );
+        assert_eq!(
+            ReplayBandit::try_new(2, vec![]).err(),
+            Some(RatelError::Empty { name: "events" })
+        )
+    }
+}
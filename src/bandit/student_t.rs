@@ -0,0 +1,207 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::StudentT;
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards according to shifted and scaled Student's t
+/// distributions.
+///
+/// An arm with few degrees of freedom has heavy tails: its variance is infinite for two or fewer.
+pub struct StudentTBandit {
+    /// Means of the arms.
+    means: Vec<f64>,
+
+    /// Scales of the arms.
+    scales: Vec<f64>,
+
+    /// Degrees of freedom of the arms.
+    dofs: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Standard t distributions of the arms.
+    distributions: Vec<StudentT<f64>>,
+}
+
+impl StudentTBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Student's t
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(means: Vec<f64>, scales: Vec<f64>, dofs: Vec<f64>) -> StudentTBandit {
+        StudentTBandit::try_new(means, scales, dofs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm pays its mean plus its scale times a Student's t
+    /// variable. Fails if the parameters differ in length, if a mean is not finite, if a scale
+    /// is not positive, or if a number of degrees of freedom is not finite and above one, where
+    /// the mean would not exist.
+    pub fn try_new(
+        means: Vec<f64>,
+        scales: Vec<f64>,
+        dofs: Vec<f64>,
+    ) -> Result<StudentTBandit, RatelError> {
+        check_each("means", &means, "must be finite", f64::is_finite)?;
+        check_len("scales", scales.len(), means.len())?;
+        check_each("scales", &scales, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        check_len("dofs", dofs.len(), means.len())?;
+        check_each("dofs", &dofs, "must be greater than 1 and finite", |n| {
+            n > 1.0 && n.is_finite()
+        })?;
+        let dist = dofs.iter().map(|&n| StudentT::new(n).unwrap()).collect();
+        let arms = means.len();
+        let best_arm = means.arg_max();
+        Ok(StudentTBandit {
+            means,
+            scales,
+            dofs,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> StudentTBanditBuilder {
+        StudentTBanditBuilder::default()
+    }
+}
+
+/// Builder for a Student's t Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct StudentTBanditBuilder {
+    /// Mean of each arm added.
+    means: Vec<f64>,
+    /// Scale of each arm added.
+    scales: Vec<f64>,
+    /// Degrees of freedom of each arm added.
+    dofs: Vec<f64>,
+}
+
+impl StudentTBanditBuilder {
+    /// Adds an arm with the given mean, scale and degrees of freedom.
+    pub fn arm(mut self, mean: f64, scale: f64, dof: f64) -> Self {
+        self.means.push(mean);
+        self.scales.push(scale);
+        self.dofs.push(dof);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<StudentTBandit, RatelError> {
+        StudentTBandit::try_new(self.means, self.scales, self.dofs)
+    }
+}
+
+impl Bandit<f64> for StudentTBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        self.means[arm]
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.means[arm] + self.scales[arm] * self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm, infinite for two or fewer degrees of freedom.
+    fn std(&self, arm: usize) -> f64 {
+        let n = self.dofs[arm];
+        if n > 2.0 {
+            self.scales[arm] * (n / (n - 2.0)).sqrt()
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::StudentTBandit;
+
+    fn student_t() -> StudentTBandit {
+        StudentTBandit::new(
+            vec![1.0, 2.0, 0.5],
+            vec![1.0, 2.0, 1.0],
+            vec![4.0, 1.5, 3.0],
+        )
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = student_t();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 1);
+        assert_eq!(bandit.max_reward(), 2.0)
+    }
+
+    #[test]
+    fn test_std() {
+        let bandit = student_t();
+        assert_approx_eq!(bandit.std(0), 2f64.sqrt());
+        assert_eq!(bandit.std(1), f64::INFINITY);
+        assert_approx_eq!(bandit.std(2), 3f64.sqrt())
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = StudentTBandit::new(vec![5.0], vec![0.1], vec![30.0]);
+        let mut rng = thread_rng();
+        let total: f64 = (0..1000).map(|_| bandit.reward(0, &mut rng)).sum();
+        assert!((total / 1000.0 - 5.0).abs() < 0.05)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = StudentTBandit::builder()
+            .arm(1.0, 1.0, 3.0)
+            .build()
+            .unwrap();
+        assert_eq!(bandit.dofs, vec![3.0])
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            StudentTBandit::try_new(vec![0.0, 0.0], vec![1.0, 1.0], vec![2.0, 1.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "dofs",
+                index: Some(1),
+                value: 1.0,
+                reason: "must be greater than 1 and finite"
+            })
+        );
+        assert_eq!(
+            StudentTBandit::try_new(vec![0.0], vec![1.0], vec![f64::INFINITY]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "dofs",
+                index: Some(0),
+                value: f64::INFINITY,
+                reason: "must be greater than 1 and finite"
+            })
+        )
+    }
+}
@@ -0,0 +1,192 @@
+use num_traits::ToPrimitive;
+use rand::RngCore;
+
+use crate::error::{check_len, RatelError};
+
+use super::Bandit;
+
+/// A non-stationary bandit whose arms switch abruptly at set change points.
+///
+/// Plays a sequence of Bandits with the same number of arms, moving on to the next Bandit once
+/// the number of pulls reaches the next change point. Between change points the arms are as
+/// stationary as the Bandit being played.
+pub struct SwitchingBandit<T> {
+    /// The Bandits played between change points, in order.
+    bandits: Vec<Box<dyn Bandit<T>>>,
+
+    /// Numbers of pulls after which the next Bandit takes over.
+    change_points: Vec<u32>,
+
+    /// Number of pulls so far.
+    pulls: u32,
+
+    /// Position of the Bandit being played.
+    current: usize,
+}
+
+impl<T: ToPrimitive> SwitchingBandit<T> {
+    /// Initializes a new Bandit that switches between the given Bandits. Panics if the
+    /// parameters are invalid; see `try_new`.
+    pub fn new(bandits: Vec<Box<dyn Bandit<T>>>, change_points: Vec<u32>) -> SwitchingBandit<T> {
+        SwitchingBandit::try_new(bandits, change_points).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit that plays `bandits[0]` until `change_points[0]` pulls,
+    /// `bandits[1]` until `change_points[1]` pulls, and so on. Fails if there are no Bandits, the
+    /// Bandits have different numbers of arms, there is not one fewer change point than Bandits,
+    /// or the change points are not positive and increasing.
+    pub fn try_new(
+        bandits: Vec<Box<dyn Bandit<T>>>,
+        change_points: Vec<u32>,
+    ) -> Result<SwitchingBandit<T>, RatelError> {
+        if bandits.is_empty() {
+            return Err(RatelError::Empty { name: "bandits" });
+        }
+        let arms = bandits[0].arms();
+        if let Some((index, bandit)) = bandits
+            .iter()
+            .enumerate()
+            .find(|(_, bandit)| bandit.arms() != arms)
+        {
+            return Err(RatelError::InvalidParameter {
+                name: "bandits",
+                index: Some(index),
+                value: bandit.arms() as f64,
+                reason: "arms must match the first bandit",
+            });
+        }
+        check_len("change_points", change_points.len(), bandits.len() - 1)?;
+        let mut previous = 0;
+        for (index, &point) in change_points.iter().enumerate() {
+            if point <= previous {
+                return Err(RatelError::InvalidParameter {
+                    name: "change_points",
+                    index: Some(index),
+                    value: f64::from(point),
+                    reason: "must come after the previous change point",
+                });
+            }
+            previous = point
+        }
+        Ok(SwitchingBandit {
+            bandits,
+            change_points,
+            pulls: 0,
+            current: 0,
+        })
+    }
+
+    /// Returns the position of the Bandit being played.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+}
+
+impl<T: ToPrimitive> Bandit<T> for SwitchingBandit<T> {
+    /// Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.bandits[0].arms()
+    }
+
+    /// Returns the arm with the highest average reward in the Bandit being played.
+    fn best_arm(&self) -> usize {
+        self.bandits[self.current].best_arm()
+    }
+
+    /// The expected return of each arm in the Bandit being played.
+    fn mean(&self, arm: usize) -> f64 {
+        self.bandits[self.current].mean(arm)
+    }
+
+    /// Determines the reward for pulling a given arm of the Bandit being played.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> T {
+        self.bandits[self.current].reward(arm, rng)
+    }
+
+    /// Counts the pull and switches to the next Bandit at a change point.
+    fn advance(&mut self, rng: &mut dyn RngCore) {
+        self.bandits[self.current].advance(rng);
+        self.pulls += 1;
+        if self.change_points.get(self.current) == Some(&self.pulls) {
+            self.current += 1
+        }
+    }
+
+    /// Returns to the first Bandit and resets every Bandit.
+    fn reset(&mut self) {
+        for bandit in self.bandits.iter_mut() {
+            bandit.reset()
+        }
+        self.pulls = 0;
+        self.current = 0
+    }
+
+    /// The standard deviations of each arm in the Bandit being played.
+    fn std(&self, arm: usize) -> f64 {
+        self.bandits[self.current].std(arm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::thread_rng;
+
+    use crate::{Bandit, BinomialBandit, RatelError};
+
+    use super::SwitchingBandit;
+
+    fn bandits() -> Vec<Box<dyn Bandit<u32>>> {
+        vec![
+            Box::new(BinomialBandit::new(vec![1; 2], vec![0.8, 0.2])),
+            Box::new(BinomialBandit::new(vec![1; 2], vec![0.3, 0.6])),
+            Box::new(BinomialBandit::new(vec![1; 2], vec![0.5, 0.1])),
+        ]
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut bandit = SwitchingBandit::new(bandits(), vec![2, 3]);
+        let mut best = vec![];
+        for _ in 0..5 {
+            best.push(bandit.best_arm());
+            bandit.advance(&mut thread_rng())
+        }
+        assert_eq!(best, vec![0, 0, 1, 0, 0]);
+        assert_eq!(bandit.current(), 2);
+        assert_eq!(bandit.max_reward(), 0.5)
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut bandit = SwitchingBandit::new(bandits(), vec![1, 2]);
+        bandit.advance(&mut thread_rng());
+        assert_eq!(bandit.means(), vec![0.3, 0.6]);
+        bandit.reset();
+        assert_eq!(bandit.current(), 0);
+        assert_eq!(bandit.means(), vec![0.8, 0.2])
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            SwitchingBandit::try_new(bandits(), vec![2]).err(),
+            Some(RatelError::LengthMismatch {
+                name: "change_points",
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            SwitchingBandit::try_new(bandits(), vec![2, 2]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "change_points",
+                index: Some(1),
+                value: 2.0,
+                reason: "must come after the previous change point"
+            })
+        );
+        let mut mixed = bandits();
+        mixed.push(Box::new(BinomialBandit::new(vec![1; 3], vec![0.5; 3])));
+        assert!(SwitchingBandit::try_new(mixed, vec![1, 2, 3]).is_err())
+    }
+}
@@ -0,0 +1,208 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Uniform;
+
+use crate::error::{check_each, check_len, RatelError};
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards uniformly over intervals.
+pub struct UniformBandit {
+    /// Lower ends of the arm intervals.
+    lows: Vec<f64>,
+
+    /// Upper ends of the arm intervals.
+    highs: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Uniform<f64>>,
+}
+
+impl UniformBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a uniform
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(lows: Vec<f64>, highs: Vec<f64>) -> UniformBandit {
+        UniformBandit::try_new(lows, highs).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards uniformly between `lows` and
+    /// `highs`. Fails if the ends differ in length, if an end is not finite, or if an upper end
+    /// is not above its lower end or is too far above it for the width to be finite.
+    pub fn try_new(lows: Vec<f64>, highs: Vec<f64>) -> Result<UniformBandit, RatelError> {
+        check_each("lows", &lows, "must be finite", f64::is_finite)?;
+        check_len("highs", highs.len(), lows.len())?;
+        check_each("highs", &highs, "must be finite", f64::is_finite)?;
+        if let Some(arm) = (0..lows.len()).find(|&arm| highs[arm] <= lows[arm]) {
+            return Err(RatelError::InvalidParameter {
+                name: "highs",
+                index: Some(arm),
+                value: highs[arm],
+                reason: "must be greater than the low end of the arm",
+            });
+        }
+        if let Some(arm) = (0..lows.len()).find(|&arm| !(highs[arm] - lows[arm]).is_finite()) {
+            return Err(RatelError::InvalidParameter {
+                name: "highs",
+                index: Some(arm),
+                value: highs[arm],
+                reason: "must be a finite distance from the low end of the arm",
+            });
+        }
+        let dist = lows
+            .iter()
+            .zip(&highs)
+            .map(|(&l, &h)| Uniform::new(l, h))
+            .collect();
+        let arms = lows.len();
+        let best_arm = lows
+            .iter()
+            .zip(&highs)
+            .map(|(l, h)| (l + h) / 2.0)
+            .collect::<Vec<f64>>()
+            .arg_max();
+        Ok(UniformBandit {
+            lows,
+            highs,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> UniformBanditBuilder {
+        UniformBanditBuilder::default()
+    }
+}
+
+/// Builder for a uniform Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct UniformBanditBuilder {
+    /// Lower end of each arm added.
+    lows: Vec<f64>,
+    /// Upper end of each arm added.
+    highs: Vec<f64>,
+}
+
+impl UniformBanditBuilder {
+    /// Adds an arm with rewards uniform between the given ends.
+    pub fn arm(mut self, low: f64, high: f64) -> Self {
+        self.lows.push(low);
+        self.highs.push(high);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<UniformBandit, RatelError> {
+        UniformBandit::try_new(self.lows, self.highs)
+    }
+}
+
+impl Bandit<f64> for UniformBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm, the midpoint of its interval.
+    fn mean(&self, arm: usize) -> f64 {
+        (self.lows[arm] + self.highs[arm]) / 2.0
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        (self.highs[arm] - self.lows[arm]) / 12f64.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::UniformBandit;
+
+    fn uniform() -> UniformBandit {
+        UniformBandit::new(vec![0.0, -1.0, 2.0], vec![1.0, 5.0, 3.0])
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = uniform();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 2);
+        assert_eq!(bandit.means(), vec![0.5, 2.0, 2.5])
+    }
+
+    #[test]
+    fn test_std() {
+        let bandit = uniform();
+        assert_approx_eq!(bandit.std(0), 0.28867513);
+        assert_approx_eq!(bandit.std(1), 1.73205081)
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = uniform();
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let reward = bandit.reward(1, &mut rng);
+            assert!((-1.0..5.0).contains(&reward))
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = UniformBandit::builder().arm(0.0, 2.0).build().unwrap();
+        assert_eq!(bandit.mean(0), 1.0)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            UniformBandit::try_new(vec![0.0, 1.0], vec![1.0, 1.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "highs",
+                index: Some(1),
+                value: 1.0,
+                reason: "must be greater than the low end of the arm"
+            })
+        );
+        assert_eq!(
+            UniformBandit::try_new(vec![0.0, 1.0], vec![1.0]).err(),
+            Some(RatelError::LengthMismatch {
+                name: "highs",
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            UniformBandit::try_new(vec![-f64::MAX], vec![f64::MAX]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "highs",
+                index: Some(0),
+                value: f64::MAX,
+                reason: "must be a finite distance from the low end of the arm"
+            })
+        )
+    }
+}
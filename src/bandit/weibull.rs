@@ -0,0 +1,216 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Weibull;
+
+use crate::error::{check_each, check_len, RatelError};
+use crate::util::special::ln_gamma;
+
+use super::{ArgBounds, Bandit};
+
+/// A bandit whose arms distribute rewards according to Weibull distributions.
+///
+/// A shape below one gives a tail heavier than exponential, though every moment stays finite.
+pub struct WeibullBandit {
+    /// Vector of distribution scale parameters.
+    scales: Vec<f64>,
+
+    /// Vector of distribution shape parameters.
+    shapes: Vec<f64>,
+
+    /// Number of arms on the bandit.
+    arms: usize,
+
+    /// The bandit arm with highest reward.
+    best_arm: usize,
+
+    /// Distributions of the arms.
+    distributions: Vec<Weibull<f64>>,
+}
+
+impl WeibullBandit {
+    /// Initializes a new Bandit where each arm distributes rewards according to a Weibull
+    /// distribution. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(scales: Vec<f64>, shapes: Vec<f64>) -> WeibullBandit {
+        WeibullBandit::try_new(scales, shapes).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a new Bandit where each arm distributes rewards according to a Weibull
+    /// distribution. Fails if the scales and shapes differ in length, if any of them is not
+    /// positive, or if a shape is so small that the arm's mean overflows.
+    pub fn try_new(scales: Vec<f64>, shapes: Vec<f64>) -> Result<WeibullBandit, RatelError> {
+        check_each("scales", &scales, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        check_len("shapes", shapes.len(), scales.len())?;
+        check_each("shapes", &shapes, "must be positive and finite", |k| {
+            k > 0.0 && k.is_finite()
+        })?;
+        let means = scales
+            .iter()
+            .zip(&shapes)
+            .map(|(&s, &k)| weibull_mean(s, k))
+            .collect::<Vec<f64>>();
+        if let Some(arm) = means.iter().position(|m| !m.is_finite()) {
+            return Err(RatelError::InvalidParameter {
+                name: "shapes",
+                index: Some(arm),
+                value: shapes[arm],
+                reason: "must be large enough for the mean to be finite",
+            });
+        }
+        let dist = scales
+            .iter()
+            .zip(&shapes)
+            .map(|(&s, &k)| Weibull::new(s, k).unwrap())
+            .collect();
+        let best_arm = means.arg_max();
+        let arms = scales.len();
+        Ok(WeibullBandit {
+            scales,
+            shapes,
+            arms,
+            best_arm,
+            distributions: dist,
+        })
+    }
+
+    /// Returns a builder that adds the arms of the Bandit one at a time.
+    pub fn builder() -> WeibullBanditBuilder {
+        WeibullBanditBuilder::default()
+    }
+}
+
+/// Builder for a Weibull Bandit that adds one arm at a time.
+#[derive(Clone, Debug, Default)]
+pub struct WeibullBanditBuilder {
+    /// Scale of each arm added.
+    scales: Vec<f64>,
+    /// Shape of each arm added.
+    shapes: Vec<f64>,
+}
+
+impl WeibullBanditBuilder {
+    /// Adds an arm with the given scale and shape.
+    pub fn arm(mut self, scale: f64, shape: f64) -> Self {
+        self.scales.push(scale);
+        self.shapes.push(shape);
+        self
+    }
+
+    /// Builds the Bandit, failing if no arms were added or a parameter is invalid.
+    pub fn build(self) -> Result<WeibullBandit, RatelError> {
+        WeibullBandit::try_new(self.scales, self.shapes)
+    }
+}
+
+impl Bandit<f64> for WeibullBandit {
+    ///Returns the number of arms on the bandit.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    ///Returns the arm with highest average reward.
+    fn best_arm(&self) -> usize {
+        self.best_arm
+    }
+
+    /// The expected return of each arm.
+    fn mean(&self, arm: usize) -> f64 {
+        weibull_mean(self.scales[arm], self.shapes[arm])
+    }
+
+    /// Determines the reward for pulling a given arm.
+    fn reward(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        self.distributions[arm].sample(rng)
+    }
+
+    /// The standard deviations of each arm.
+    fn std(&self, arm: usize) -> f64 {
+        let k = self.shapes[arm];
+        let ratio = (ln_gamma(1.0 + 2.0 / k) - 2.0 * ln_gamma(1.0 + 1.0 / k)).exp();
+        self.mean(arm) * (ratio - 1.0).max(0.0).sqrt()
+    }
+}
+
+/// Returns the mean `scale Γ(1 + 1 / shape)` of a Weibull distribution, computed through the
+/// log-gamma function so it overflows only when the mean itself does.
+fn weibull_mean(scale: f64, shape: f64) -> f64 {
+    (scale.ln() + ln_gamma(1.0 + 1.0 / shape)).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use crate::RatelError;
+
+    use super::super::Bandit;
+    use super::WeibullBandit;
+
+    fn weibull() -> WeibullBandit {
+        WeibullBandit::new(vec![3.0, 1.0, 1.0], vec![1.0, 0.5, 2.0])
+    }
+
+    #[test]
+    fn test_best_arm() {
+        let bandit = weibull();
+        assert_eq!(bandit.arms(), 3);
+        assert_eq!(bandit.best_arm(), 0);
+        bandit
+            .means()
+            .iter()
+            .zip(vec![3.0, 2.0, std::f64::consts::PI.sqrt() / 2.0])
+            .for_each(|(m1, m2)| assert_approx_eq!(m1, m2))
+    }
+
+    #[test]
+    fn test_std() {
+        let bandit = weibull();
+        assert_approx_eq!(bandit.std(0), 3.0);
+        assert_approx_eq!(bandit.std(1), 20f64.sqrt());
+        assert_approx_eq!(bandit.std(2), (1.0 - std::f64::consts::PI / 4.0).sqrt())
+    }
+
+    #[test]
+    fn test_reward() {
+        let bandit = weibull();
+        let mut rng = thread_rng();
+        let total: f64 = (0..2000).map(|_| bandit.reward(2, &mut rng)).sum();
+        assert!((total / 2000.0 - 0.886).abs() < 0.05)
+    }
+
+    #[test]
+    fn test_builder() {
+        let bandit = WeibullBandit::builder().arm(3.0, 1.0).build().unwrap();
+        assert_approx_eq!(bandit.mean(0), 3.0)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            WeibullBandit::try_new(vec![1.0, 1.0], vec![1.0]).err(),
+            Some(RatelError::LengthMismatch {
+                name: "shapes",
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            WeibullBandit::try_new(vec![1.0], vec![1.0 / 200.0]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "shapes",
+                index: Some(0),
+                value: 1.0 / 200.0,
+                reason: "must be large enough for the mean to be finite"
+            })
+        )
+    }
+
+    #[test]
+    fn test_small_shape() {
+        let bandit = WeibullBandit::new(vec![1.0], vec![1.0 / 150.0]);
+        assert_approx_eq!(bandit.mean(0) / 5.713_383_956_445_855e262, 1.0, 1e-10);
+        assert_approx_eq!(bandit.std(0) / 1.749_449_948_459e307, 1.0, 1e-8)
+    }
+}
@@ -0,0 +1,1466 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::ops::AddAssign;
+use std::path::Path;
+
+use num_traits::{Num, ToPrimitive};
+use rand_distr::Normal;
+use serde::{Deserialize, Deserializer};
+
+use crate::error::{check_each, check_len};
+
+use super::{
+    Agent, Bandit, BernoulliBandit, BernoulliDivergence, BetaBandit, BetaPosterior, BinomialBandit,
+    BoxedDistribution, ChangeDetector, ChangePointAgent, ConstantSchedule, ConstantStepper,
+    ContaminatedGaussianBandit, CusumDetector, DiscountedUcbAgent, DistributionBandit, Divergence,
+    DriftingBandit, EmpiricalDistribution, EpsilonGreedyAgent, Exp3Agent, Exp3IxAgent, Exp3PAgent,
+    ExperimentSummary, ExponentialBandit, ExponentialDivergence, ExponentialSchedule, Game,
+    GammaBandit, GaussianBandit, GaussianDivergence, GlrDetector, GradientAgent, GreedyAgent,
+    HarmonicStepper, InverseSchedule, KlUcbAgent, LinearSchedule, LogNormalBandit,
+    LogarithmicSchedule, MedianOfMeansEstimator, MixtureDistribution, MossIndex,
+    NormalGammaPosterior, OptimisticAgent, PageHinkleyDetector, ParallelExperiment, ParetoBandit,
+    PoissonBandit, PoissonDivergence, Posterior, RatelError, Restart, RewardScale, RobustEstimator,
+    RobustUcbAgent, Schedule, SlidingWindowUcbAgent, SoftmaxAgent, Stepper, StudentTBandit,
+    ThompsonSamplingAgent, TruncatedMeanEstimator, Ucb1Index, Ucb1TunedIndex, UcbAgent, UcbIndex,
+    UcbVIndex, UniformBandit, WeibullBandit,
+};
+
+/// Errors raised while loading or running an experiment config.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file could not be read.
+    Io(std::io::Error),
+    /// The config file is not valid JSON for an experiment.
+    Json(serde_json::Error),
+    /// The config file is not valid TOML for an experiment.
+    Toml(toml::de::Error),
+    /// The config file extension is neither `.json` nor `.toml`.
+    Format(String),
+    /// The config parses but describes an experiment that cannot be built.
+    Invalid(String),
+    /// A Bandit, Agent, or Stepper parameter in the config is out of range.
+    Parameter(RatelError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "could not read config: {}", e),
+            ConfigError::Json(e) => write!(f, "invalid JSON config: {}", e),
+            ConfigError::Toml(e) => write!(f, "invalid TOML config: {}", e),
+            ConfigError::Format(ext) => write!(f, "unknown config format `{}`", ext),
+            ConfigError::Invalid(msg) => write!(f, "invalid config: {}", msg),
+            ConfigError::Parameter(e) => write!(f, "invalid config: {}", e),
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Json(e) => Some(e),
+            ConfigError::Toml(e) => Some(e),
+            ConfigError::Parameter(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<RatelError> for ConfigError {
+    fn from(e: RatelError) -> Self {
+        ConfigError::Parameter(e)
+    }
+}
+
+/// The distribution family of the Bandit arms and their parameters.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "family", rename_all = "kebab-case")]
+pub enum BanditConfig {
+    /// Arms with binomial rewards. See `BinomialBandit::new`.
+    Binomial { nums: Vec<u32>, probs: Vec<f64> },
+    /// Arms with Bernoulli rewards. See `BernoulliBandit::new`.
+    Bernoulli { probs: Vec<f64> },
+    /// Arms with Poisson rewards. See `PoissonBandit::new`.
+    Poisson { lambdas: Vec<f64> },
+    /// Arms with Gaussian rewards. See `GaussianBandit::new`.
+    Gaussian { means: Vec<f64>, stds: Vec<f64> },
+    /// Arms with exponential rewards. See `ExponentialBandit::new`.
+    Exponential { lambdas: Vec<f64> },
+    /// Arms with gamma rewards. See `GammaBandit::new`.
+    Gamma { alphas: Vec<f64>, thetas: Vec<f64> },
+    /// Arms with log-normal rewards. See `LogNormalBandit::new`.
+    LogNormal { mus: Vec<f64>, sigmas: Vec<f64> },
+    /// Arms with uniform rewards. See `UniformBandit::new`.
+    Uniform { lows: Vec<f64>, highs: Vec<f64> },
+    /// Arms with beta rewards. See `BetaBandit::new`.
+    Beta { alphas: Vec<f64>, betas: Vec<f64> },
+    /// Arms with shifted and scaled Student's t rewards. See `StudentTBandit::new`.
+    StudentT {
+        means: Vec<f64>,
+        scales: Vec<f64>,
+        dofs: Vec<f64>,
+    },
+    /// Arms with Pareto rewards. See `ParetoBandit::new`.
+    Pareto { scales: Vec<f64>, shapes: Vec<f64> },
+    /// Arms with Weibull rewards. See `WeibullBandit::new`.
+    Weibull { scales: Vec<f64>, shapes: Vec<f64> },
+    /// Arms with Gaussian rewards contaminated by Cauchy outliers. See
+    /// `ContaminatedGaussianBandit::new`.
+    Contaminated {
+        means: Vec<f64>,
+        stds: Vec<f64>,
+        epsilon: f64,
+        scale: f64,
+    },
+    /// Arms whose rewards are resampled from the samples in the given files, one file per arm.
+    /// See `EmpiricalDistribution::from_file`.
+    Empirical { paths: Vec<String> },
+    /// Arms with rewards from mixtures of Gaussians, with the weights, means and standard
+    /// deviations of each arm's components. See `MixtureDistribution::new`.
+    GaussianMixture {
+        weights: Vec<Vec<f64>>,
+        means: Vec<Vec<f64>>,
+        stds: Vec<Vec<f64>>,
+    },
+}
+
+impl BanditConfig {
+    /// Returns the number of arms described.
+    pub fn arms(&self) -> usize {
+        match self {
+            BanditConfig::Binomial { nums, .. } => nums.len(),
+            BanditConfig::Bernoulli { probs } => probs.len(),
+            BanditConfig::Poisson { lambdas } => lambdas.len(),
+            BanditConfig::Gaussian { means, .. } => means.len(),
+            BanditConfig::Exponential { lambdas } => lambdas.len(),
+            BanditConfig::Gamma { alphas, .. } => alphas.len(),
+            BanditConfig::LogNormal { mus, .. } => mus.len(),
+            BanditConfig::Uniform { lows, .. } => lows.len(),
+            BanditConfig::Beta { alphas, .. } => alphas.len(),
+            BanditConfig::StudentT { means, .. } => means.len(),
+            BanditConfig::Pareto { scales, .. } => scales.len(),
+            BanditConfig::Weibull { scales, .. } => scales.len(),
+            BanditConfig::Contaminated { means, .. } => means.len(),
+            BanditConfig::Empirical { paths } => paths.len(),
+            BanditConfig::GaussianMixture { weights, .. } => weights.len(),
+        }
+    }
+
+    /// Returns the range nearly all rewards fall in: every outcome of binomial, Bernoulli, uniform
+    /// and beta arms, and three standard deviations either side of the means of Gaussian arms.
+    /// The other families have tails too long for a default range.
+    pub fn reward_range(&self) -> Option<(f64, f64)> {
+        match self {
+            BanditConfig::Binomial { nums, .. } => {
+                Some((0.0, f64::from(nums.iter().cloned().max().unwrap_or(1))))
+            }
+            BanditConfig::Gaussian { means, stds } => Some(
+                means
+                    .iter()
+                    .zip(stds)
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), (m, s)| {
+                        (low.min(m - 3.0 * s), high.max(m + 3.0 * s))
+                    }),
+            ),
+            BanditConfig::Bernoulli { .. } | BanditConfig::Beta { .. } => Some((0.0, 1.0)),
+            BanditConfig::Uniform { lows, highs } => Some((
+                lows.iter().cloned().fold(f64::INFINITY, f64::min),
+                highs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The Agent's rule for step size updates.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum StepperConfig {
+    /// See `HarmonicStepper::new`.
+    Harmonic {
+        #[serde(default = "default_harmonic_step")]
+        step_size: u32,
+    },
+    /// See `ConstantStepper::new`.
+    Constant { step_size: f64 },
+}
+
+fn default_harmonic_step() -> u32 {
+    1
+}
+
+impl Default for StepperConfig {
+    /// The sample average, a harmonic stepper starting at 1.
+    fn default() -> Self {
+        StepperConfig::Harmonic { step_size: 1 }
+    }
+}
+
+impl StepperConfig {
+    /// Builds the Stepper for a Bandit with the given number of arms.
+    pub fn build(&self, arms: usize) -> Result<Box<dyn Stepper>, RatelError> {
+        Ok(match *self {
+            StepperConfig::Harmonic { step_size } => {
+                Box::new(HarmonicStepper::try_new(step_size, arms)?)
+            }
+            StepperConfig::Constant { step_size } => Box::new(ConstantStepper::try_new(step_size)?),
+        })
+    }
+}
+
+/// How an Agent hyperparameter changes from round to round.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum ScheduleConfig {
+    /// See `ConstantSchedule::new`.
+    Constant { value: f64 },
+    /// See `ExponentialSchedule::new`.
+    Exponential {
+        initial: f64,
+        rate: f64,
+        #[serde(default)]
+        minimum: f64,
+    },
+    /// See `InverseSchedule::new`.
+    Inverse { scale: f64 },
+    /// See `LinearSchedule::new`. Each point is a round and the value in that round.
+    Linear { points: Vec<(u32, f64)> },
+    /// See `LogarithmicSchedule::new`.
+    Logarithmic { scale: f64 },
+}
+
+impl ScheduleConfig {
+    /// Builds the schedule.
+    pub fn build(&self) -> Result<Box<dyn Schedule>, RatelError> {
+        Ok(match self {
+            ScheduleConfig::Constant { value } => Box::new(ConstantSchedule::try_new(*value)?),
+            ScheduleConfig::Exponential {
+                initial,
+                rate,
+                minimum,
+            } => Box::new(ExponentialSchedule::try_new(*initial, *rate, *minimum)?),
+            ScheduleConfig::Inverse { scale } => Box::new(InverseSchedule::try_new(*scale)?),
+            ScheduleConfig::Linear { points } => Box::new(LinearSchedule::try_new(points.clone())?),
+            ScheduleConfig::Logarithmic { scale } => {
+                Box::new(LogarithmicSchedule::try_new(*scale)?)
+            }
+        })
+    }
+}
+
+/// Reads a schedule, or a bare number as a constant schedule.
+fn schedule_or_value<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ScheduleConfig, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ScheduleOrValue {
+        Value(f64),
+        Schedule(ScheduleConfig),
+    }
+    Ok(match ScheduleOrValue::deserialize(deserializer)? {
+        ScheduleOrValue::Value(value) => ScheduleConfig::Constant { value },
+        ScheduleOrValue::Schedule(schedule) => schedule,
+    })
+}
+
+/// The rule an upper confidence bound Agent uses to bound each arm's value.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum IndexConfig {
+    /// See `Ucb1Index`.
+    Ucb1,
+    /// See `Ucb1TunedIndex`.
+    Ucb1Tuned,
+    /// See `UcbVIndex::new`.
+    UcbV {
+        #[serde(default = "default_range")]
+        range: f64,
+    },
+    /// See `MossIndex::new`.
+    Moss { horizon: u32 },
+}
+
+fn default_range() -> f64 {
+    1.0
+}
+
+impl Default for IndexConfig {
+    /// The UCB1 index.
+    fn default() -> Self {
+        IndexConfig::Ucb1
+    }
+}
+
+impl IndexConfig {
+    /// Builds the index.
+    pub fn build(&self) -> Result<Box<dyn UcbIndex>, RatelError> {
+        Ok(match *self {
+            IndexConfig::Ucb1 => Box::new(Ucb1Index::new()),
+            IndexConfig::Ucb1Tuned => Box::new(Ucb1TunedIndex::new()),
+            IndexConfig::UcbV { range } => Box::new(UcbVIndex::try_new(range)?),
+            IndexConfig::Moss { horizon } => Box::new(MossIndex::try_new(horizon)?),
+        })
+    }
+}
+
+/// The robust estimator a Robust UCB Agent uses for each arm's value.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum EstimatorConfig {
+    /// See `TruncatedMeanEstimator::new`.
+    TruncatedMean {
+        #[serde(default = "default_epsilon")]
+        epsilon: f64,
+        u: f64,
+    },
+    /// See `MedianOfMeansEstimator::new`.
+    MedianOfMeans {
+        #[serde(default = "default_epsilon")]
+        epsilon: f64,
+        v: f64,
+    },
+}
+
+fn default_epsilon() -> f64 {
+    1.0
+}
+
+impl EstimatorConfig {
+    /// Builds the estimator.
+    pub fn build(&self) -> Result<Box<dyn RobustEstimator>, RatelError> {
+        Ok(match *self {
+            EstimatorConfig::TruncatedMean { epsilon, u } => {
+                Box::new(TruncatedMeanEstimator::try_new(epsilon, u)?)
+            }
+            EstimatorConfig::MedianOfMeans { epsilon, v } => {
+                Box::new(MedianOfMeansEstimator::try_new(epsilon, v)?)
+            }
+        })
+    }
+}
+
+/// The test a change-point Agent runs on each arm's rewards.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum DetectorConfig {
+    /// See `CusumDetector::new`.
+    Cusum {
+        #[serde(default)]
+        epsilon: f64,
+        threshold: f64,
+        warmup: u32,
+    },
+    /// See `PageHinkleyDetector::new`.
+    PageHinkley {
+        #[serde(default)]
+        delta: f64,
+        threshold: f64,
+    },
+    /// See `GlrDetector::with_window`. The divergence is chosen from the bandit as for `KlUcb`.
+    Glr {
+        #[serde(default = "default_delta")]
+        delta: f64,
+        #[serde(default = "default_glr_window")]
+        window: usize,
+    },
+}
+
+impl DetectorConfig {
+    /// Builds the detector for the given Bandit.
+    pub fn build(
+        &self,
+        bandit: &BanditConfig,
+        arms: usize,
+    ) -> Result<Box<dyn ChangeDetector>, ConfigError> {
+        Ok(match *self {
+            DetectorConfig::Cusum {
+                epsilon,
+                threshold,
+                warmup,
+            } => Box::new(CusumDetector::try_new(arms, epsilon, threshold, warmup)?),
+            DetectorConfig::PageHinkley { delta, threshold } => {
+                Box::new(PageHinkleyDetector::try_new(arms, delta, threshold)?)
+            }
+            DetectorConfig::Glr { delta, window } => Box::new(GlrDetector::try_with_window(
+                divergence("glr detector", bandit, arms)?,
+                delta,
+                window,
+            )?),
+        })
+    }
+}
+
+/// The Agent's strategy and its hyperparameters.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum AgentConfig {
+    /// See `GreedyAgent::new`.
+    Greedy {
+        #[serde(default)]
+        stepper: StepperConfig,
+    },
+    /// See `EpsilonGreedyAgent::new`, or `EpsilonGreedyAgent::with_schedule` when `epsilon` is a
+    /// schedule rather than a number.
+    EpsilonGreedy {
+        #[serde(deserialize_with = "schedule_or_value")]
+        epsilon: ScheduleConfig,
+        #[serde(default)]
+        stepper: StepperConfig,
+    },
+    /// See `OptimisticAgent::new`.
+    Optimistic {
+        c: f64,
+        #[serde(default)]
+        stepper: StepperConfig,
+    },
+    /// See `ThompsonSamplingAgent::new`. Uses a `BetaPosterior` with `prior_weight` for binomial
+    /// bandits and a `NormalGammaPosterior` with `lambda`, `alpha` and `beta` for Gaussian bandits.
+    Thompson {
+        #[serde(default)]
+        prior_weight: f64,
+        #[serde(default = "default_prior")]
+        lambda: f64,
+        #[serde(default = "default_prior")]
+        alpha: f64,
+        #[serde(default = "default_prior")]
+        beta: f64,
+    },
+    /// See `UcbAgent::new`.
+    Ucb {
+        #[serde(default)]
+        index: IndexConfig,
+    },
+    /// See `SlidingWindowUcbAgent::new`.
+    SlidingWindowUcb {
+        window: u32,
+        #[serde(default)]
+        index: IndexConfig,
+    },
+    /// See `DiscountedUcbAgent::new`.
+    DiscountedUcb {
+        gamma: f64,
+        #[serde(default)]
+        index: IndexConfig,
+    },
+    /// See `RobustUcbAgent::new`.
+    RobustUcb { estimator: EstimatorConfig },
+    /// See `KlUcbAgent::new`. Uses a `BernoulliDivergence` for binomial, Bernoulli and beta
+    /// bandits, a `GaussianDivergence` for Gaussian bandits, a `PoissonDivergence` for Poisson
+    /// bandits and an `ExponentialDivergence` for exponential bandits.
+    KlUcb {
+        #[serde(default)]
+        c: f64,
+    },
+    /// See `SoftmaxAgent::new`.
+    Softmax {
+        #[serde(deserialize_with = "schedule_or_value")]
+        temperature: ScheduleConfig,
+        #[serde(default)]
+        stepper: StepperConfig,
+    },
+    /// See `GradientAgent::new`. The stepper sets the step sizes of the baseline.
+    Gradient {
+        alpha: f64,
+        #[serde(default)]
+        stepper: StepperConfig,
+    },
+    /// See `Exp3Agent::new`. Rewards are scaled to `[0, 1]` from `reward_range`, which defaults
+    /// to `BanditConfig::reward_range`.
+    Exp3 {
+        gamma: f64,
+        #[serde(default)]
+        reward_range: Option<(f64, f64)>,
+    },
+    /// See `Exp3IxAgent::new`. `gamma` defaults to `eta / 2`, and rewards are scaled as for
+    /// `Exp3`.
+    Exp3Ix {
+        eta: f64,
+        #[serde(default)]
+        gamma: Option<f64>,
+        #[serde(default)]
+        reward_range: Option<(f64, f64)>,
+    },
+    /// See `Exp3PAgent::new`. Rewards are scaled as for `Exp3`.
+    Exp3P {
+        horizon: u32,
+        #[serde(default = "default_delta")]
+        delta: f64,
+        #[serde(default)]
+        reward_range: Option<(f64, f64)>,
+    },
+    /// See `ChangePointAgent::new`. The wrapped Agent is reset to the experiment's initial guess.
+    ChangePoint {
+        agent: Box<AgentConfig>,
+        detector: DetectorConfig,
+        #[serde(default)]
+        restart: Restart,
+        #[serde(default)]
+        alpha: f64,
+    },
+}
+
+fn default_prior() -> f64 {
+    1.0
+}
+
+fn default_delta() -> f64 {
+    0.05
+}
+
+fn default_glr_window() -> usize {
+    GlrDetector::DEFAULT_WINDOW
+}
+
+/// Builds the scale mapping rewards onto `[0, 1]` for an adversarial Agent, falling back on the
+/// Bandit's reward range.
+fn reward_scale(
+    kind: &str,
+    reward_range: &Option<(f64, f64)>,
+    bandit: &BanditConfig,
+) -> Result<RewardScale, ConfigError> {
+    match reward_range.or_else(|| bandit.reward_range()) {
+        Some((low, high)) => Ok(RewardScale::try_new(low, high)?),
+        None => Err(ConfigError::Invalid(format!(
+            "the {} agent needs a reward_range for this bandit",
+            kind
+        ))),
+    }
+}
+
+/// Builds a Bandit whose arms are mixtures of Gaussians.
+fn gaussian_mixture(
+    weights: &[Vec<f64>],
+    means: &[Vec<f64>],
+    stds: &[Vec<f64>],
+) -> Result<DistributionBandit<MixtureDistribution, f64>, RatelError> {
+    check_len("means", means.len(), weights.len())?;
+    check_len("stds", stds.len(), weights.len())?;
+    let arms = weights
+        .iter()
+        .zip(means.iter().zip(stds))
+        .map(|(w, (m, s))| {
+            check_len("means", m.len(), w.len())?;
+            check_each("means", m, "must be finite", f64::is_finite)?;
+            check_len("stds", s.len(), w.len())?;
+            check_each("stds", s, "must be positive and finite", |s| {
+                s > 0.0 && s.is_finite()
+            })?;
+            let components = m
+                .iter()
+                .zip(s)
+                .map(|(&m, &s)| BoxedDistribution::new(Normal::new(m, s).unwrap()))
+                .collect();
+            MixtureDistribution::try_new(w.clone(), components, m.clone(), s.clone())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    DistributionBandit::try_from_moments(arms)
+}
+
+/// Chooses the divergence of the Bandit's reward family for a KL-based strategy.
+fn divergence(
+    what: &str,
+    bandit: &BanditConfig,
+    arms: usize,
+) -> Result<Box<dyn Divergence>, ConfigError> {
+    Ok(match bandit {
+        BanditConfig::Binomial { nums, .. } => {
+            Box::new(BernoulliDivergence::try_new(nums.clone())?)
+        }
+        BanditConfig::Gaussian { stds, .. } => Box::new(GaussianDivergence::try_new(stds.clone())?),
+        BanditConfig::Bernoulli { .. } | BanditConfig::Beta { .. } => {
+            Box::new(BernoulliDivergence::try_new(vec![1; arms])?)
+        }
+        BanditConfig::Poisson { .. } => Box::new(PoissonDivergence::new(arms)),
+        BanditConfig::Exponential { .. } => Box::new(ExponentialDivergence::new(arms)),
+        _ => {
+            return Err(ConfigError::Invalid(format!(
+                "the {} needs a binomial, bernoulli, beta, gaussian, poisson or exponential bandit",
+                what
+            )))
+        }
+    })
+}
+
+impl AgentConfig {
+    /// Builds the Agent for the given Bandit with the given initial guess.
+    pub fn build<T: ToPrimitive + 'static>(
+        &self,
+        bandit: &BanditConfig,
+        q_init: &[f64],
+    ) -> Result<Box<dyn Agent<T>>, ConfigError> {
+        let q_init = q_init.to_vec();
+        let arms = q_init.len();
+        Ok(match self {
+            AgentConfig::Greedy { stepper } => {
+                Box::new(GreedyAgent::new(q_init, stepper.build(arms)?))
+            }
+            AgentConfig::EpsilonGreedy { epsilon, stepper } => {
+                let stepper = stepper.build(arms)?;
+                Box::new(match epsilon {
+                    ScheduleConfig::Constant { value } => {
+                        EpsilonGreedyAgent::try_new(q_init, stepper, *value)?
+                    }
+                    schedule => {
+                        EpsilonGreedyAgent::try_with_schedule(q_init, stepper, schedule.build()?)?
+                    }
+                })
+            }
+            AgentConfig::Optimistic { c, stepper } => {
+                Box::new(OptimisticAgent::try_new(q_init, *c, stepper.build(arms)?)?)
+            }
+            AgentConfig::Thompson {
+                prior_weight,
+                lambda,
+                alpha,
+                beta,
+            } => {
+                let posterior: Box<dyn Posterior> = match bandit {
+                    BanditConfig::Binomial { nums, .. } => {
+                        Box::new(BetaPosterior::try_new(nums.clone(), *prior_weight)?)
+                    }
+                    BanditConfig::Bernoulli { .. } => {
+                        Box::new(BetaPosterior::try_new(vec![1; arms], *prior_weight)?)
+                    }
+                    BanditConfig::Gaussian { .. } => {
+                        Box::new(NormalGammaPosterior::try_new(arms, *lambda, *alpha, *beta)?)
+                    }
+                    _ => {
+                        return Err(ConfigError::Invalid(
+                            "the thompson agent needs a binomial, bernoulli or gaussian bandit"
+                                .to_owned(),
+                        ))
+                    }
+                };
+                Box::new(ThompsonSamplingAgent::try_new(q_init, posterior)?)
+            }
+            AgentConfig::Ucb { index } => Box::new(UcbAgent::try_new(q_init, index.build()?)?),
+            AgentConfig::SlidingWindowUcb { window, index } => Box::new(
+                SlidingWindowUcbAgent::try_new(q_init, *window, index.build()?)?,
+            ),
+            AgentConfig::DiscountedUcb { gamma, index } => {
+                Box::new(DiscountedUcbAgent::try_new(q_init, *gamma, index.build()?)?)
+            }
+            AgentConfig::RobustUcb { estimator } => {
+                Box::new(RobustUcbAgent::try_new(q_init, estimator.build()?)?)
+            }
+            AgentConfig::KlUcb { c } => Box::new(KlUcbAgent::try_new(
+                q_init,
+                divergence("kl-ucb agent", bandit, arms)?,
+                *c,
+            )?),
+            AgentConfig::Softmax {
+                temperature,
+                stepper,
+            } => Box::new(SoftmaxAgent::try_new(
+                q_init,
+                stepper.build(arms)?,
+                temperature.build()?,
+            )?),
+            AgentConfig::Gradient { alpha, stepper } => Box::new(GradientAgent::try_new(
+                q_init,
+                *alpha,
+                stepper.build(arms)?,
+            )?),
+            AgentConfig::Exp3 {
+                gamma,
+                reward_range,
+            } => Box::new(Exp3Agent::try_new(
+                arms,
+                *gamma,
+                reward_scale(self.kind(), reward_range, bandit)?,
+            )?),
+            AgentConfig::Exp3Ix {
+                eta,
+                gamma,
+                reward_range,
+            } => Box::new(Exp3IxAgent::try_new(
+                arms,
+                *eta,
+                gamma.unwrap_or(eta / 2.0),
+                reward_scale(self.kind(), reward_range, bandit)?,
+            )?),
+            AgentConfig::Exp3P {
+                horizon,
+                delta,
+                reward_range,
+            } => Box::new(Exp3PAgent::try_new(
+                arms,
+                *horizon,
+                *delta,
+                reward_scale(self.kind(), reward_range, bandit)?,
+            )?),
+            AgentConfig::ChangePoint {
+                agent,
+                detector,
+                restart,
+                alpha,
+            } => Box::new(ChangePointAgent::try_new(
+                agent.build(bandit, &q_init)?,
+                detector.build(bandit, arms)?,
+                q_init,
+                *restart,
+                *alpha,
+            )?),
+        })
+    }
+
+    /// Returns the name of the Agent's strategy.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AgentConfig::Greedy { .. } => "greedy",
+            AgentConfig::EpsilonGreedy { .. } => "epsilon-greedy",
+            AgentConfig::Optimistic { .. } => "optimistic",
+            AgentConfig::Thompson { .. } => "thompson",
+            AgentConfig::Ucb { .. } => "ucb",
+            AgentConfig::SlidingWindowUcb { .. } => "sliding-window-ucb",
+            AgentConfig::DiscountedUcb { .. } => "discounted-ucb",
+            AgentConfig::RobustUcb { .. } => "robust-ucb",
+            AgentConfig::KlUcb { .. } => "kl-ucb",
+            AgentConfig::Softmax { .. } => "softmax",
+            AgentConfig::Gradient { .. } => "gradient",
+            AgentConfig::Exp3 { .. } => "exp3",
+            AgentConfig::Exp3Ix { .. } => "exp3-ix",
+            AgentConfig::Exp3P { .. } => "exp3-p",
+            AgentConfig::ChangePoint { .. } => "change-point",
+        }
+    }
+}
+
+/// An Agent to run in the experiment, with an optional name to report its results under.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct AgentSpec {
+    /// Name of the Agent's results. Defaults to the Agent's strategy.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// The Agent's strategy and its hyperparameters.
+    #[serde(flatten)]
+    pub agent: AgentConfig,
+}
+
+impl AgentSpec {
+    /// Returns the name the Agent's results are reported under.
+    pub fn name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| self.agent.kind().to_owned())
+    }
+}
+
+/// A whole study: a Bandit, the Agents that play it, and how long and how often to play.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct ExperimentConfig {
+    /// The Bandit every Agent plays.
+    pub bandit: BanditConfig,
+    /// The Agents to compare.
+    pub agents: Vec<AgentSpec>,
+    /// Initial guess of the arm values, one per arm or a single value for every arm.
+    #[serde(default = "default_q_init")]
+    pub q_init: Vec<f64>,
+    /// Number of arm pulls in each run.
+    pub steps: u32,
+    /// Number of independent runs of each Agent.
+    pub runs: u32,
+    /// Seed of the runs. Every Agent is run with the same seed.
+    #[serde(default)]
+    pub seed: u64,
+    /// Standard deviation of the Gaussian random walk every arm mean takes after each pull. Zero,
+    /// the default, keeps the Bandit stationary. See `DriftingBandit::new`.
+    #[serde(default)]
+    pub drift: f64,
+    /// Number of worker threads. Defaults to one per available core.
+    #[serde(default)]
+    pub threads: Option<usize>,
+}
+
+fn default_q_init() -> Vec<f64> {
+    vec![0.0]
+}
+
+impl ExperimentConfig {
+    /// Loads a config from a `.toml` or `.json` file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<ExperimentConfig, ConfigError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => ExperimentConfig::from_json(&text),
+            Some("toml") => ExperimentConfig::from_toml(&text),
+            ext => Err(ConfigError::Format(ext.unwrap_or("").to_owned())),
+        }
+    }
+
+    /// Parses a config from JSON.
+    pub fn from_json(text: &str) -> Result<ExperimentConfig, ConfigError> {
+        serde_json::from_str(text).map_err(ConfigError::Json)
+    }
+
+    /// Parses a config from TOML.
+    pub fn from_toml(text: &str) -> Result<ExperimentConfig, ConfigError> {
+        toml::from_str(text).map_err(ConfigError::Toml)
+    }
+
+    /// Returns the initial guess for every arm, expanding a single value to all arms.
+    pub fn q_init(&self) -> Result<Vec<f64>, ConfigError> {
+        let arms = self.bandit.arms();
+        match self.q_init.len() {
+            1 => Ok(vec![self.q_init[0]; arms]),
+            n if n == arms => Ok(self.q_init.clone()),
+            n => Err(ConfigError::Invalid(format!(
+                "q_init has {} values for {} arms",
+                n, arms
+            ))),
+        }
+    }
+
+    /// Runs every Agent against the Bandit and returns each Agent's name and averaged curves.
+    ///
+    /// Every parameter is checked before any run starts, so an invalid config returns an error
+    /// rather than panicking.
+    pub fn run(&self) -> Result<Vec<(String, ExperimentSummary)>, ConfigError> {
+        if self.runs == 0 {
+            return Err(ConfigError::Invalid("runs must be positive".to_owned()));
+        }
+        if self.threads == Some(0) {
+            return Err(ConfigError::Invalid("threads must be positive".to_owned()));
+        }
+        match &self.bandit {
+            BanditConfig::Binomial { .. }
+            | BanditConfig::Bernoulli { .. }
+            | BanditConfig::Poisson { .. }
+                if self.drift != 0.0 =>
+            {
+                Err(ConfigError::Invalid(
+                    "drift needs a bandit with real-valued rewards".to_owned(),
+                ))
+            }
+            BanditConfig::Binomial { nums, probs } => self.run_agents(|| {
+                BinomialBandit::try_new(nums.clone(), probs.clone())
+                    .map(|b| Box::new(b) as Box<dyn Bandit<u32>>)
+            }),
+            BanditConfig::Bernoulli { probs } => self.run_agents(|| {
+                BernoulliBandit::try_new(probs.clone()).map(|b| Box::new(b) as Box<dyn Bandit<u32>>)
+            }),
+            BanditConfig::Poisson { lambdas } => self.run_agents(|| {
+                PoissonBandit::try_new(lambdas.clone()).map(|b| Box::new(b) as Box<dyn Bandit<u32>>)
+            }),
+            BanditConfig::Gaussian { means, stds } => self.run_agents(|| {
+                GaussianBandit::try_new(means.clone(), stds.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Exponential { lambdas } => self.run_agents(|| {
+                ExponentialBandit::try_new(lambdas.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Gamma { alphas, thetas } => self.run_agents(|| {
+                GammaBandit::try_new(alphas.clone(), thetas.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::LogNormal { mus, sigmas } => self.run_agents(|| {
+                LogNormalBandit::try_new(mus.clone(), sigmas.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Uniform { lows, highs } => self.run_agents(|| {
+                UniformBandit::try_new(lows.clone(), highs.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Beta { alphas, betas } => self.run_agents(|| {
+                BetaBandit::try_new(alphas.clone(), betas.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::StudentT {
+                means,
+                scales,
+                dofs,
+            } => self.run_agents(|| {
+                StudentTBandit::try_new(means.clone(), scales.clone(), dofs.clone())
+                    .and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Pareto { scales, shapes } => self.run_agents(|| {
+                ParetoBandit::try_new(scales.clone(), shapes.clone()).and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Weibull { scales, shapes } => self.run_agents(|| {
+                WeibullBandit::try_new(scales.clone(), shapes.clone())
+                    .and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Contaminated {
+                means,
+                stds,
+                epsilon,
+                scale,
+            } => self.run_agents(|| {
+                ContaminatedGaussianBandit::try_new(means.clone(), stds.clone(), *epsilon, *scale)
+                    .and_then(|b| self.drifting(b))
+            }),
+            BanditConfig::Empirical { paths } => {
+                let arms = paths
+                    .iter()
+                    .map(EmpiricalDistribution::from_file)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(ConfigError::Io)?;
+                self.run_agents(|| {
+                    DistributionBandit::try_from_moments(arms.clone())
+                        .and_then(|b| self.drifting(b))
+                })
+            }
+            BanditConfig::GaussianMixture {
+                weights,
+                means,
+                stds,
+            } => self.run_agents(|| {
+                gaussian_mixture(weights, means, stds).and_then(|b| self.drifting(b))
+            }),
+        }
+    }
+
+    /// Wraps a Bandit in a `DriftingBandit` if the config asks for drift.
+    fn drifting<B: Bandit<f64> + 'static>(
+        &self,
+        bandit: B,
+    ) -> Result<Box<dyn Bandit<f64>>, RatelError> {
+        if self.drift == 0.0 {
+            Ok(Box::new(bandit))
+        } else {
+            Ok(Box::new(DriftingBandit::try_new(
+                Box::new(bandit),
+                self.drift,
+            )?))
+        }
+    }
+
+    /// Runs every Agent against Bandits built by `bandit`.
+    fn run_agents<T, B>(&self, bandit: B) -> Result<Vec<(String, ExperimentSummary)>, ConfigError>
+    where
+        T: AddAssign + Copy + Num + ToPrimitive + 'static,
+        B: Fn() -> Result<Box<dyn Bandit<T>>, RatelError> + Sync,
+    {
+        let q_init = self.q_init()?;
+        bandit()?;
+        self.agents
+            .iter()
+            .map(|spec| {
+                Game::try_new(spec.agent.build::<T>(&self.bandit, &q_init)?, bandit()?)?;
+                // Both builds succeeded above, so the factory cannot fail.
+                let factory = || {
+                    let agent = spec.agent.build(&self.bandit, &q_init).unwrap();
+                    Game::new(agent, bandit().unwrap())
+                };
+                let mut experiment = ParallelExperiment::new(
+                    factory,
+                    q_init.clone(),
+                    self.runs,
+                    self.steps,
+                    self.seed,
+                );
+                if let Some(threads) = self.threads {
+                    experiment.set_threads(threads)
+                }
+                Ok((spec.name(), experiment.run()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{RatelError, Restart};
+
+    use super::{
+        AgentConfig, AgentSpec, BanditConfig, ConfigError, DetectorConfig, EstimatorConfig,
+        ExperimentConfig, IndexConfig, ScheduleConfig, StepperConfig,
+    };
+
+    const TOML: &str = r#"
+        steps = 50
+        runs = 20
+        seed = 3
+
+        [bandit]
+        family = "binomial"
+        nums = [5, 5, 5]
+        probs = [0.2, 0.5, 0.4]
+
+        [[agents]]
+        name = "eps"
+        kind = "epsilon-greedy"
+        epsilon = 0.1
+        stepper = { kind = "constant", step_size = 0.1 }
+
+        [[agents]]
+        kind = "thompson"
+        prior_weight = 2.0
+    "#;
+
+    #[test]
+    fn test_from_json() {
+        let config = ExperimentConfig::from_json(
+            r#"{
+                "bandit": {"family": "log-normal", "mus": [0.1, 0.2], "sigmas": [1.0, 1.0]},
+                "agents": [{"kind": "optimistic", "c": 2.0}],
+                "q_init": [1.0, 2.0],
+                "steps": 10,
+                "runs": 4
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.seed, 0);
+        assert_eq!(
+            config.agents[0].agent,
+            AgentConfig::Optimistic {
+                c: 2.0,
+                stepper: StepperConfig::Harmonic { step_size: 1 }
+            }
+        );
+        assert_eq!(config.q_init().unwrap(), vec![1.0, 2.0])
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let config = ExperimentConfig::from_toml(TOML).unwrap();
+        assert_eq!(
+            config.bandit,
+            BanditConfig::Binomial {
+                nums: vec![5, 5, 5],
+                probs: vec![0.2, 0.5, 0.4]
+            }
+        );
+        assert_eq!(config.agents[0].name(), "eps");
+        assert_eq!(config.agents[1].name(), "thompson");
+        assert_eq!(config.q_init().unwrap(), vec![0.0; 3])
+    }
+
+    #[test]
+    fn test_ucb() {
+        let text = format!(
+            "{}\n{}",
+            TOML, "[[agents]]\nkind = \"ucb\"\nindex = { kind = \"moss\", horizon = 50 }"
+        );
+        let config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[2].agent,
+            AgentConfig::Ucb {
+                index: IndexConfig::Moss { horizon: 50 }
+            }
+        );
+        assert_eq!(config.run().unwrap()[2].0, "ucb")
+    }
+
+    #[test]
+    fn test_forgetting_ucb() {
+        let text = TOML.replace(
+            "kind = \"thompson\"",
+            "kind = \"sliding-window-ucb\"\nwindow = 20",
+        );
+        let mut config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[1].agent,
+            AgentConfig::SlidingWindowUcb {
+                window: 20,
+                index: IndexConfig::Ucb1
+            }
+        );
+        config.agents[0].agent = AgentConfig::DiscountedUcb {
+            gamma: 0.95,
+            index: IndexConfig::Ucb1Tuned,
+        };
+        let results = config.run().unwrap();
+        assert_eq!(results[1].0, "sliding-window-ucb");
+        assert_eq!(results[0].1.runs(), 20);
+        config.agents[0].agent = AgentConfig::DiscountedUcb {
+            gamma: 0.0,
+            index: IndexConfig::Ucb1,
+        };
+        assert!(matches!(config.run(), Err(ConfigError::Parameter(_))))
+    }
+
+    #[test]
+    fn test_change_point() {
+        let text = TOML.replace(
+            "kind = \"thompson\"",
+            "kind = \"change-point\"\nagent = { kind = \"ucb\" }\n\
+             detector = { kind = \"cusum\", threshold = 5.0, warmup = 10 }",
+        );
+        let mut config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[1].agent,
+            AgentConfig::ChangePoint {
+                agent: Box::new(AgentConfig::Ucb {
+                    index: IndexConfig::Ucb1
+                }),
+                detector: DetectorConfig::Cusum {
+                    epsilon: 0.0,
+                    threshold: 5.0,
+                    warmup: 10
+                },
+                restart: Restart::Global,
+                alpha: 0.0
+            }
+        );
+        assert_eq!(config.run().unwrap()[1].0, "change-point");
+        config.agents[1].agent = AgentConfig::ChangePoint {
+            agent: Box::new(AgentConfig::KlUcb { c: 0.0 }),
+            detector: DetectorConfig::Glr {
+                delta: 0.05,
+                window: 1000,
+            },
+            restart: Restart::PerArm,
+            alpha: 0.05,
+        };
+        assert_eq!(config.run().unwrap()[1].1.runs(), 20);
+        config.bandit = BanditConfig::Gamma {
+            alphas: vec![1.0; 3],
+            thetas: vec![1.0; 3],
+        };
+        config.agents[1].agent = AgentConfig::ChangePoint {
+            agent: Box::new(AgentConfig::Ucb {
+                index: IndexConfig::Ucb1,
+            }),
+            detector: DetectorConfig::Glr {
+                delta: 0.05,
+                window: 1000,
+            },
+            restart: Restart::Global,
+            alpha: 0.0,
+        };
+        config.agents.remove(0);
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))))
+    }
+
+    #[test]
+    fn test_kl_ucb() {
+        let mut config = ExperimentConfig::from_toml(TOML).unwrap();
+        config.agents[1].agent = AgentConfig::KlUcb { c: 0.0 };
+        assert_eq!(config.run().unwrap()[1].0, "kl-ucb");
+        config.bandit = BanditConfig::Gamma {
+            alphas: vec![1.0; 3],
+            thetas: vec![1.0; 3],
+        };
+        config.agents.remove(0);
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))))
+    }
+
+    #[test]
+    fn test_gradient() {
+        let text = TOML.replace("kind = \"thompson\"", "kind = \"gradient\"\nalpha = 0.1");
+        let config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[1].agent,
+            AgentConfig::Gradient {
+                alpha: 0.1,
+                stepper: StepperConfig::default()
+            }
+        );
+        assert_eq!(config.run().unwrap()[1].0, "gradient")
+    }
+
+    #[test]
+    fn test_softmax() {
+        let text = TOML.replace(
+            "kind = \"thompson\"",
+            "kind = \"softmax\"\ntemperature = { kind = \"logarithmic\", scale = 0.5 }",
+        );
+        let config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[1].agent,
+            AgentConfig::Softmax {
+                temperature: ScheduleConfig::Logarithmic { scale: 0.5 },
+                stepper: StepperConfig::default()
+            }
+        );
+        assert_eq!(config.run().unwrap()[1].0, "softmax")
+    }
+
+    #[test]
+    fn test_epsilon_schedule() {
+        let config = ExperimentConfig::from_toml(TOML).unwrap();
+        assert_eq!(
+            config.agents[0].agent,
+            AgentConfig::EpsilonGreedy {
+                epsilon: ScheduleConfig::Constant { value: 0.1 },
+                stepper: StepperConfig::Constant { step_size: 0.1 }
+            }
+        );
+        let text = TOML.replace(
+            "epsilon = 0.1",
+            "epsilon = { kind = \"linear\", points = [[1, 1.0], [20, 0.05]] }",
+        );
+        let config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[0].agent,
+            AgentConfig::EpsilonGreedy {
+                epsilon: ScheduleConfig::Linear {
+                    points: vec![(1, 1.0), (20, 0.05)]
+                },
+                stepper: StepperConfig::Constant { step_size: 0.1 }
+            }
+        );
+        assert_eq!(config.run().unwrap()[0].1.runs(), 20)
+    }
+
+    #[test]
+    fn test_exp3() {
+        let text = TOML.replace("kind = \"thompson\"", "kind = \"exp3-ix\"\neta = 0.1");
+        let mut config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.agents[1].agent,
+            AgentConfig::Exp3Ix {
+                eta: 0.1,
+                gamma: None,
+                reward_range: None
+            }
+        );
+        assert_eq!(config.run().unwrap()[1].0, "exp3-ix");
+        config.bandit = BanditConfig::Exponential {
+            lambdas: vec![1.0; 3],
+        };
+        config.agents = vec![AgentSpec {
+            name: None,
+            agent: AgentConfig::Exp3 {
+                gamma: 0.1,
+                reward_range: None,
+            },
+        }];
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))));
+        config.agents[0].agent = AgentConfig::Exp3P {
+            horizon: 50,
+            delta: 0.05,
+            reward_range: Some((0.0, 5.0)),
+        };
+        assert_eq!(config.run().unwrap()[0].0, "exp3-p")
+    }
+
+    #[test]
+    fn test_reward_range() {
+        let bandit = BanditConfig::Gaussian {
+            means: vec![1.0, 2.0],
+            stds: vec![2.0, 0.5],
+        };
+        assert_eq!(bandit.reward_range(), Some((-5.0, 7.0)));
+        let bandit = BanditConfig::Uniform {
+            lows: vec![-1.0, 2.0],
+            highs: vec![0.5, 3.0],
+        };
+        assert_eq!(bandit.reward_range(), Some((-1.0, 3.0)))
+    }
+
+    #[test]
+    fn test_families() {
+        let text = TOML.replace(
+            "family = \"binomial\"\n        nums = [5, 5, 5]",
+            "family = \"bernoulli\"",
+        );
+        let mut config = ExperimentConfig::from_toml(&text).unwrap();
+        assert_eq!(
+            config.bandit,
+            BanditConfig::Bernoulli {
+                probs: vec![0.2, 0.5, 0.4]
+            }
+        );
+        assert_eq!(config.run().unwrap()[1].0, "thompson");
+        config.agents[1].agent = AgentConfig::KlUcb { c: 0.0 };
+        config.bandit = BanditConfig::Poisson {
+            lambdas: vec![1.0, 3.0, 2.0],
+        };
+        assert_eq!(config.run().unwrap()[1].1.runs(), 20);
+        config.bandit = BanditConfig::Beta {
+            alphas: vec![1.0, 2.0, 3.0],
+            betas: vec![3.0; 3],
+        };
+        assert_eq!(config.run().unwrap()[1].1.runs(), 20);
+        config.bandit = BanditConfig::Uniform {
+            lows: vec![0.0; 3],
+            highs: vec![1.0, 2.0, 0.0],
+        };
+        config.agents.remove(1);
+        assert!(matches!(config.run(), Err(ConfigError::Parameter(_))))
+    }
+
+    #[test]
+    fn test_heavy_tails() {
+        let mut config = ExperimentConfig::from_json(
+            r#"{
+                "bandit": {"family": "student-t", "means": [0.0, 1.0], "scales": [1.0, 1.0], "dofs": [1.5, 3.0]},
+                "agents": [
+                    {"kind": "robust-ucb", "estimator": {"kind": "median-of-means", "v": 2.0}},
+                    {"kind": "robust-ucb", "estimator": {"kind": "truncated-mean", "epsilon": 0.5, "u": 4.0}}
+                ],
+                "steps": 50,
+                "runs": 5
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.agents[0].agent,
+            AgentConfig::RobustUcb {
+                estimator: EstimatorConfig::MedianOfMeans {
+                    epsilon: 1.0,
+                    v: 2.0
+                }
+            }
+        );
+        assert_eq!(config.agents[1].name(), "robust-ucb");
+        assert_eq!(config.run().unwrap()[1].1.runs(), 5);
+        config.bandit = BanditConfig::Pareto {
+            scales: vec![1.0, 2.0],
+            shapes: vec![1.5, 3.0],
+        };
+        assert_eq!(config.run().unwrap()[0].1.runs(), 5);
+        config.bandit = BanditConfig::Weibull {
+            scales: vec![1.0, 2.0],
+            shapes: vec![0.5, 2.0],
+        };
+        assert_eq!(config.run().unwrap()[0].1.runs(), 5);
+        config.bandit = BanditConfig::Contaminated {
+            means: vec![0.0, 1.0],
+            stds: vec![1.0; 2],
+            epsilon: 0.1,
+            scale: 5.0,
+        };
+        assert_eq!(config.bandit.reward_range(), None);
+        assert_eq!(config.run().unwrap()[0].1.runs(), 5);
+        config.agents[0].agent = AgentConfig::RobustUcb {
+            estimator: EstimatorConfig::TruncatedMean {
+                epsilon: 2.0,
+                u: 1.0,
+            },
+        };
+        assert!(matches!(config.run(), Err(ConfigError::Parameter(_))))
+    }
+
+    #[test]
+    fn test_calibrated() {
+        let dir = std::env::temp_dir();
+        let paths: Vec<String> = [("low", "1.0\n2.0\n3.0\n"), ("high", "2.0, 4.0, 100.0\n")]
+            .iter()
+            .map(|(name, text)| {
+                let path = dir.join(format!(
+                    "ratel_test_calibrated_{}_{}.txt",
+                    std::process::id(),
+                    name
+                ));
+                std::fs::write(&path, text).unwrap();
+                path.to_str().unwrap().to_owned()
+            })
+            .collect();
+        let mut config = ExperimentConfig::from_toml(&format!(
+            r#"
+            q_init = [0.0]
+            steps = 50
+            runs = 5
+
+            [bandit]
+            family = "empirical"
+            paths = ["{}", "{}"]
+
+            [[agents]]
+            kind = "ucb"
+            "#,
+            paths[0], paths[1]
+        ))
+        .unwrap();
+        assert_eq!(config.bandit.arms(), 2);
+        assert_eq!(config.run().unwrap()[0].1.runs(), 5);
+        config.bandit = BanditConfig::Empirical {
+            paths: vec![paths[0].clone(), format!("{}.missing", paths[1])],
+        };
+        assert!(matches!(config.run(), Err(ConfigError::Io(_))));
+        paths
+            .iter()
+            .for_each(|path| std::fs::remove_file(path).unwrap());
+        config.bandit = BanditConfig::GaussianMixture {
+            weights: vec![vec![1.0], vec![0.9, 0.1]],
+            means: vec![vec![1.0], vec![0.0, 20.0]],
+            stds: vec![vec![1.0], vec![1.0, 5.0]],
+        };
+        assert_eq!(config.run().unwrap()[0].1.runs(), 5);
+        config.bandit = BanditConfig::GaussianMixture {
+            weights: vec![vec![1.0], vec![0.9, 0.1]],
+            means: vec![vec![1.0], vec![0.0]],
+            stds: vec![vec![1.0], vec![1.0, 5.0]],
+        };
+        assert!(matches!(
+            config.run(),
+            Err(ConfigError::Parameter(RatelError::LengthMismatch {
+                name: "means",
+                expected: 2,
+                found: 1
+            }))
+        ))
+    }
+
+    #[test]
+    fn test_drift() {
+        let mut config = ExperimentConfig::from_toml(&format!("drift = 0.1\n{}", TOML)).unwrap();
+        assert_eq!(config.drift, 0.1);
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))));
+        config.bandit = BanditConfig::Gaussian {
+            means: vec![0.0, 0.5, 1.0],
+            stds: vec![1.0; 3],
+        };
+        config.agents.remove(1);
+        assert_eq!(config.run().unwrap()[0].1.runs(), 20);
+        config.drift = -1.0;
+        assert!(matches!(config.run(), Err(ConfigError::Parameter(_))))
+    }
+
+    #[test]
+    fn test_invalid_q_init() {
+        let mut config = ExperimentConfig::from_toml(TOML).unwrap();
+        config.q_init = vec![0.0, 1.0];
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))))
+    }
+
+    #[test]
+    fn test_invalid_parameter() {
+        let text = TOML.replace("0.5, 0.4", "1.5, 0.4");
+        let config = ExperimentConfig::from_toml(&text).unwrap();
+        assert!(matches!(
+            config.run(),
+            Err(ConfigError::Parameter(RatelError::InvalidParameter {
+                name: "probs",
+                index: Some(1),
+                ..
+            }))
+        ));
+        let mut config = ExperimentConfig::from_toml(TOML).unwrap();
+        config.agents[0].agent = AgentConfig::EpsilonGreedy {
+            epsilon: ScheduleConfig::Constant { value: 1.5 },
+            stepper: StepperConfig::default(),
+        };
+        assert_eq!(
+            config.run().unwrap_err().to_string(),
+            "invalid config: epsilon = 1.5 must be between 0 and 1"
+        )
+    }
+
+    #[test]
+    fn test_invalid_runs() {
+        let mut config = ExperimentConfig::from_toml(TOML).unwrap();
+        config.runs = 0;
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))))
+    }
+
+    #[test]
+    fn test_invalid_thompson() {
+        let mut config = ExperimentConfig::from_toml(TOML).unwrap();
+        config.bandit = BanditConfig::Exponential {
+            lambdas: vec![1.0, 2.0, 3.0],
+        };
+        assert!(matches!(config.run(), Err(ConfigError::Invalid(_))))
+    }
+
+    #[test]
+    fn test_run() {
+        let config = ExperimentConfig::from_toml(TOML).unwrap();
+        let results = config.run().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, "eps");
+        assert_eq!(results[1].1.runs(), 20);
+        assert_eq!(results[1].1.rewards().mean().len(), 50);
+        assert_eq!(results, config.run().unwrap())
+    }
+
+    #[test]
+    fn test_unknown_family() {
+        let text = TOML.replace("binomial", "cauchy");
+        assert!(matches!(
+            ExperimentConfig::from_toml(&text),
+            Err(ConfigError::Toml(_))
+        ))
+    }
+}
@@ -0,0 +1,260 @@
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
+
+use super::error::check_len;
+use super::{ContextualAgent, ContextualBandit, Counter, RatelError, RecordCounter};
+
+/// Structure to make a contextual Agent interact with a contextual Bandit.
+///
+/// Each round the Bandit draws a context, the Agent chooses an arm for it, and the Agent learns
+/// from the reward, with wins and regret measured against the best arm for that context.
+pub struct ContextualGame {
+    /// Agent learning about bandit.
+    agent: Box<dyn ContextualAgent>,
+    /// Bandit used by agent.
+    bandit: Box<dyn ContextualBandit>,
+    /// Records the arm chosen in each arm pull.
+    actions: RecordCounter<usize>,
+    /// Records wins and losses from each arm pull. Win means pulling the best arm for the context.
+    wins: RecordCounter<u32>,
+    /// Records rewards from each arm pull.
+    rewards: RecordCounter<f64>,
+    /// Records the pseudo-regret of each arm pull, the gap between the best arm's mean and the
+    /// mean of the arm pulled in the context of the pull.
+    regret: RecordCounter<f64>,
+    /// Records the running total of the pseudo-regret after each arm pull.
+    cumulative_regret: RecordCounter<f64>,
+    /// Random number generator used by the agent to choose arms.
+    agent_rng: StdRng,
+    /// Random number generator used by the bandit to draw contexts and rewards.
+    bandit_rng: StdRng,
+}
+
+impl ContextualGame {
+    /// Initializes a Game with an Agent, Bandit, and new counters. Randomness is seeded from the
+    /// operating system, so runs cannot be replayed.
+    pub fn new(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+    ) -> ContextualGame {
+        ContextualGame::with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game with an Agent, Bandit, and new counters, failing if the Agent and the
+    /// Bandit have different numbers of arms or features.
+    pub fn try_new(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+    ) -> Result<ContextualGame, RatelError> {
+        ContextualGame::try_with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed.
+    pub fn with_seed(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+        seed: u64,
+    ) -> ContextualGame {
+        ContextualGame::with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed, failing if the
+    /// Agent and the Bandit have different numbers of arms or features.
+    pub fn try_with_seed(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+        seed: u64,
+    ) -> Result<ContextualGame, RatelError> {
+        ContextualGame::try_with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator.
+    ///
+    /// Independent streams for the Agent and the Bandit are split from the generator, so the
+    /// contexts and rewards drawn do not depend on how much randomness the Agent consumes.
+    pub fn with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+        rng: &mut R,
+    ) -> ContextualGame {
+        ContextualGame::try_with_rng(agent, bandit, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator,
+    /// failing if the Agent and the Bandit have different numbers of arms or features.
+    pub fn try_with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn ContextualAgent>,
+        bandit: Box<dyn ContextualBandit>,
+        rng: &mut R,
+    ) -> Result<ContextualGame, RatelError> {
+        if agent.arms() != bandit.arms() {
+            return Err(RatelError::ArmMismatch {
+                agent: agent.arms(),
+                bandit: bandit.arms(),
+            });
+        }
+        check_len("context", bandit.dimension(), agent.dimension())?;
+        Ok(ContextualGame {
+            agent,
+            bandit,
+            actions: RecordCounter::new(),
+            wins: RecordCounter::new(),
+            rewards: RecordCounter::new(),
+            regret: RecordCounter::new(),
+            cumulative_regret: RecordCounter::new(),
+            agent_rng: StdRng::from_rng(&mut *rng).unwrap(),
+            bandit_rng: StdRng::from_rng(&mut *rng).unwrap(),
+        })
+    }
+
+    /// Returns vector of the arms chosen.
+    pub fn actions(&self) -> &Vec<usize> {
+        self.actions.record()
+    }
+
+    /// Returns the number of bandit arms.
+    pub fn arms(&self) -> usize {
+        self.bandit.arms()
+    }
+
+    /// Bandit draws a context, Agent chooses an arm to pull for it and updates based on reward.
+    fn pull_arm(&mut self) {
+        let context = self.bandit.context(&mut self.bandit_rng);
+        let current_action = self.agent.action(&context, &mut self.agent_rng);
+        self.actions.update(current_action);
+        self.wins
+            .update((current_action == self.bandit.best_arm(&context)) as u32);
+        let reward = self
+            .bandit
+            .reward(&context, current_action, &mut self.bandit_rng);
+        self.rewards.update(reward);
+        let regret = self.bandit.max_reward(&context) - self.bandit.mean(&context, current_action);
+        let total = self.cumulative_regret.record().last().unwrap_or(&0.0) + regret;
+        self.regret.update(regret);
+        self.cumulative_regret.update(total);
+        self.agent.step(&context, current_action, reward);
+    }
+
+    /// Replaces the Agent and Bandit random number streams with streams split from the given seed.
+    pub fn reseed(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.agent_rng = StdRng::from_rng(&mut rng).unwrap();
+        self.bandit_rng = StdRng::from_rng(&mut rng).unwrap();
+    }
+
+    /// Resets Game. Resets Agent and counters.
+    pub fn reset(&mut self) {
+        self.agent.reset();
+        self.actions.reset();
+        self.rewards.reset();
+        self.wins.reset();
+        self.regret.reset();
+        self.cumulative_regret.reset();
+    }
+
+    /// Returns vector of running totals of pseudo-regret.
+    pub fn cumulative_regret(&self) -> &Vec<f64> {
+        self.cumulative_regret.record()
+    }
+
+    /// Returns vector of pseudo-regrets.
+    pub fn regret(&self) -> &Vec<f64> {
+        self.regret.record()
+    }
+
+    /// Returns vector of rewards.
+    pub fn rewards(&self) -> &Vec<f64> {
+        self.rewards.record()
+    }
+
+    /// Run game for a certain number of steps.
+    pub fn run(&mut self, steps: u32) {
+        for _ in 1..=steps {
+            self.pull_arm()
+        }
+    }
+
+    /// Returns vector of wins.
+    pub fn wins(&self) -> &Vec<u32> {
+        self.wins.record()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{LinTsAgent, LinUcbAgent, LinearBandit, LogisticBandit, RatelError};
+
+    use super::ContextualGame;
+
+    fn thetas() -> Vec<Vec<f64>> {
+        vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]
+    }
+
+    fn lin_ucb_game(seed: u64) -> ContextualGame {
+        let agent = LinUcbAgent::new(3, 3, 0.5, 1.0);
+        let bandit = LinearBandit::new(thetas(), 0.1);
+        ContextualGame::with_seed(Box::new(agent), Box::new(bandit), seed)
+    }
+
+    #[test]
+    fn test_lin_ucb() {
+        let mut game = lin_ucb_game(42);
+        game.run(1000);
+        let early: u32 = game.wins()[..100].iter().sum();
+        let late: u32 = game.wins()[900..].iter().sum();
+        assert!(late > 90 && late > early);
+        let regret = game.cumulative_regret();
+        assert!(regret[999] - regret[899] < regret[99])
+    }
+
+    #[test]
+    fn test_lin_ts() {
+        let agent = LinTsAgent::new(3, 3, 0.1, 1.0);
+        let thetas = thetas()
+            .iter()
+            .map(|theta| theta.iter().map(|t| 5.0 * t).collect())
+            .collect();
+        let bandit = LogisticBandit::new(thetas);
+        let mut game = ContextualGame::with_seed(Box::new(agent), Box::new(bandit), 7);
+        game.run(2000);
+        let early: u32 = game.wins()[..500].iter().sum();
+        let late: u32 = game.wins()[1500..].iter().sum();
+        assert!(late > 450 && late > early);
+        assert!(game.rewards().iter().all(|&r| r == 0.0 || r == 1.0))
+    }
+
+    #[test]
+    fn test_with_seed() {
+        let mut game1 = lin_ucb_game(3);
+        let mut game2 = lin_ucb_game(3);
+        game1.run(100);
+        game2.run(100);
+        assert_eq!(game1.rewards(), game2.rewards());
+        game1.reset();
+        game1.reseed(5);
+        game1.run(50);
+        game2.reset();
+        game2.reseed(5);
+        game2.run(50);
+        assert_eq!(game1.actions(), game2.actions());
+        assert_eq!(game1.regret().len(), 50)
+    }
+
+    #[test]
+    fn test_try_new() {
+        let agent = LinUcbAgent::new(3, 2, 0.5, 1.0);
+        let bandit = LinearBandit::new(thetas(), 0.1);
+        assert_eq!(
+            ContextualGame::try_new(Box::new(agent), Box::new(bandit)).err(),
+            Some(RatelError::LengthMismatch {
+                name: "context",
+                expected: 2,
+                found: 3
+            })
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use std::error::Error;
+use std::fmt;
+
+/// Errors raised when building Bandits, Agents, Steppers, and Games from invalid parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RatelError {
+    /// The Agent and the Bandit of a Game have different numbers of arms.
+    ArmMismatch {
+        /// Number of arms the Agent expects.
+        agent: usize,
+        /// Number of arms on the Bandit.
+        bandit: usize,
+    },
+    /// A parameter that needs at least one value has none.
+    Empty {
+        /// Name of the parameter.
+        name: &'static str,
+    },
+    /// A parameter value is outside its allowed range.
+    InvalidParameter {
+        /// Name of the parameter.
+        name: &'static str,
+        /// Position of the value when the parameter has one value per arm.
+        index: Option<usize>,
+        /// The value given.
+        value: f64,
+        /// The range the value must lie in.
+        reason: &'static str,
+    },
+    /// A required parameter was never given to a builder.
+    Missing {
+        /// Name of the parameter.
+        name: &'static str,
+    },
+    /// A parameter with one value per arm has the wrong number of values.
+    LengthMismatch {
+        /// Name of the parameter.
+        name: &'static str,
+        /// Number of values required.
+        expected: usize,
+        /// Number of values given.
+        found: usize,
+    },
+}
+
+impl fmt::Display for RatelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RatelError::ArmMismatch { agent, bandit } => {
+                write!(f, "agent has {} arms but bandit has {} arms", agent, bandit)
+            }
+            RatelError::Empty { name } => write!(f, "{} must not be empty", name),
+            RatelError::InvalidParameter {
+                name,
+                index: Some(index),
+                value,
+                reason,
+            } => write!(f, "{}[{}] = {} {}", name, index, value, reason),
+            RatelError::InvalidParameter {
+                name,
+                index: None,
+                value,
+                reason,
+            } => write!(f, "{} = {} {}", name, value, reason),
+            RatelError::Missing { name } => write!(f, "{} is required", name),
+            RatelError::LengthMismatch {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{} has {} values but {} are required",
+                name, found, expected
+            ),
+        }
+    }
+}
+
+impl Error for RatelError {}
+
+/// Checks that a single parameter value satisfies a condition.
+pub(crate) fn check<N: Copy + Into<f64>>(
+    name: &'static str,
+    value: N,
+    reason: &'static str,
+    valid: fn(f64) -> bool,
+) -> Result<(), RatelError> {
+    let value = value.into();
+    if valid(value) {
+        Ok(())
+    } else {
+        Err(RatelError::InvalidParameter {
+            name,
+            index: None,
+            value,
+            reason,
+        })
+    }
+}
+
+/// Checks that a per-arm parameter is not empty and that every value satisfies a condition.
+pub(crate) fn check_each<N: Copy + Into<f64>>(
+    name: &'static str,
+    values: &[N],
+    reason: &'static str,
+    valid: fn(f64) -> bool,
+) -> Result<(), RatelError> {
+    if values.is_empty() {
+        return Err(RatelError::Empty { name });
+    }
+    match values
+        .iter()
+        .map(|&v| v.into())
+        .enumerate()
+        .find(|&(_, v)| !valid(v))
+    {
+        Some((index, value)) => Err(RatelError::InvalidParameter {
+            name,
+            index: Some(index),
+            value,
+            reason,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Checks that a per-arm parameter has one value for each arm.
+pub(crate) fn check_len(
+    name: &'static str,
+    found: usize,
+    expected: usize,
+) -> Result<(), RatelError> {
+    if found == expected {
+        Ok(())
+    } else {
+        Err(RatelError::LengthMismatch {
+            name,
+            expected,
+            found,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, check_each, check_len, RatelError};
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check("c", 2.0, "must be positive", |c| c > 0.0), Ok(()));
+        assert_eq!(
+            check("c", f64::NAN, "must be positive", |c| c > 0.0)
+                .unwrap_err()
+                .to_string(),
+            "c = NaN must be positive"
+        )
+    }
+
+    #[test]
+    fn test_check_each() {
+        let err = check_each("probs", &[0.5, 1.2], "must be between 0 and 1", |p| {
+            (0.0..=1.0).contains(&p)
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "probs[1] = 1.2 must be between 0 and 1");
+        assert_eq!(
+            check_each::<u32>("nums", &[], "must be positive", |n| n > 0.0),
+            Err(RatelError::Empty { name: "nums" })
+        )
+    }
+
+    #[test]
+    fn test_check_len() {
+        assert_eq!(check_len("stds", 5, 5), Ok(()));
+        assert_eq!(
+            check_len("stds", 4, 5).unwrap_err().to_string(),
+            "stds has 4 values but 5 are required"
+        )
+    }
+}
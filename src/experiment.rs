@@ -0,0 +1,343 @@
+use std::ops::AddAssign;
+
+use num_traits::{Num, ToPrimitive};
+
+use super::error::{check, check_len};
+use super::{Game, RatelError};
+
+/// The average of a per-step quantity over many runs, with the standard error of each average.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Curve {
+    /// Average value at each step.
+    mean: Vec<f64>,
+    /// Standard error of the average at each step.
+    std_err: Vec<f64>,
+}
+
+impl Curve {
+    /// Returns the average value at each step.
+    pub fn mean(&self) -> &Vec<f64> {
+        &self.mean
+    }
+
+    /// Returns the standard error of the average at each step. Undefined, and reported as `NaN`,
+    /// when there is only one run.
+    pub fn std_err(&self) -> &Vec<f64> {
+        &self.std_err
+    }
+}
+
+/// Averaged curves from all the runs of an Experiment.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExperimentSummary {
+    /// Number of runs averaged.
+    runs: u32,
+    /// Fraction of runs in which the best arm was pulled at each step.
+    win_rate: Curve,
+    /// Average reward at each step.
+    rewards: Curve,
+    /// Average pseudo-regret at each step.
+    regret: Curve,
+    /// Average cumulative pseudo-regret at each step.
+    cumulative_regret: Curve,
+}
+
+impl ExperimentSummary {
+    /// Returns the average cumulative pseudo-regret at each step.
+    pub fn cumulative_regret(&self) -> &Curve {
+        &self.cumulative_regret
+    }
+
+    /// Returns the average pseudo-regret at each step.
+    pub fn regret(&self) -> &Curve {
+        &self.regret
+    }
+
+    /// Returns the average reward at each step.
+    pub fn rewards(&self) -> &Curve {
+        &self.rewards
+    }
+
+    /// Returns the number of runs averaged.
+    pub fn runs(&self) -> u32 {
+        self.runs
+    }
+
+    /// Returns the fraction of runs in which the best arm was pulled at each step.
+    pub fn win_rate(&self) -> &Curve {
+        &self.win_rate
+    }
+}
+
+/// Running per-step means and sums of squared deviations, updated one run at a time.
+#[derive(Clone)]
+struct CurveStats {
+    /// Average value at each step.
+    mean: Vec<f64>,
+    /// Sum of squared deviations from the average at each step.
+    m2: Vec<f64>,
+}
+
+impl CurveStats {
+    /// Initializes empty statistics for the given number of steps.
+    fn new(steps: usize) -> CurveStats {
+        CurveStats {
+            mean: vec![0.0; steps],
+            m2: vec![0.0; steps],
+        }
+    }
+
+    /// Converts the statistics of `runs` runs into a Curve.
+    fn curve(&self, runs: u32) -> Curve {
+        let n = f64::from(runs);
+        Curve {
+            mean: self.mean.clone(),
+            std_err: self
+                .m2
+                .iter()
+                .map(|m2| (m2 / (n - 1.0) / n).sqrt())
+                .collect(),
+        }
+    }
+
+    /// Combines statistics over `runs` runs with statistics over `other_runs` later runs.
+    fn merge(&mut self, runs: u32, other: &CurveStats, other_runs: u32) {
+        let (n_a, n_b) = (f64::from(runs), f64::from(other_runs));
+        let n = n_a + n_b;
+        for (step, (mean, m2)) in self.mean.iter_mut().zip(&mut self.m2).enumerate() {
+            let delta = other.mean[step] - *mean;
+            *mean += delta * n_b / n;
+            *m2 += other.m2[step] + delta * delta * n_a * n_b / n;
+        }
+    }
+
+    /// Adds the values of one more run, which becomes run number `runs`.
+    fn update<I: Iterator<Item = f64>>(&mut self, runs: u32, values: I) {
+        let n = f64::from(runs);
+        for ((mean, m2), x) in self.mean.iter_mut().zip(&mut self.m2).zip(values) {
+            let delta = x - *mean;
+            *mean += delta / n;
+            *m2 += delta * (x - *mean);
+        }
+    }
+}
+
+/// Per-step statistics of the curves recorded by a Game, accumulated over many runs.
+#[derive(Clone)]
+pub(crate) struct ExperimentStats {
+    /// Number of runs recorded.
+    runs: u32,
+    win_rate: CurveStats,
+    rewards: CurveStats,
+    regret: CurveStats,
+    cumulative_regret: CurveStats,
+}
+
+impl ExperimentStats {
+    /// Initializes empty statistics for runs of the given number of steps.
+    pub(crate) fn new(steps: u32) -> ExperimentStats {
+        let stats = CurveStats::new(steps as usize);
+        ExperimentStats {
+            runs: 0,
+            win_rate: stats.clone(),
+            rewards: stats.clone(),
+            regret: stats.clone(),
+            cumulative_regret: stats,
+        }
+    }
+
+    /// Adds the statistics of later runs to these statistics.
+    pub(crate) fn merge(&mut self, other: &ExperimentStats) {
+        if other.runs == 0 {
+            return;
+        }
+        self.win_rate.merge(self.runs, &other.win_rate, other.runs);
+        self.rewards.merge(self.runs, &other.rewards, other.runs);
+        self.regret.merge(self.runs, &other.regret, other.runs);
+        self.cumulative_regret
+            .merge(self.runs, &other.cumulative_regret, other.runs);
+        self.runs += other.runs
+    }
+
+    /// Adds the curves recorded by a Game that has just finished a run.
+    pub(crate) fn record<T: AddAssign + Copy + Num + ToPrimitive>(&mut self, game: &Game<T>) {
+        self.runs += 1;
+        self.win_rate
+            .update(self.runs, game.wins().iter().map(|&w| f64::from(w)));
+        self.rewards.update(
+            self.runs,
+            game.rewards().iter().map(|r| r.to_f64().unwrap()),
+        );
+        self.regret.update(self.runs, game.regret().iter().cloned());
+        self.cumulative_regret
+            .update(self.runs, game.cumulative_regret().iter().cloned());
+    }
+
+    /// Converts the statistics into averaged curves.
+    pub(crate) fn summary(&self) -> ExperimentSummary {
+        ExperimentSummary {
+            runs: self.runs,
+            win_rate: self.win_rate.curve(self.runs),
+            rewards: self.rewards.curve(self.runs),
+            regret: self.regret.curve(self.runs),
+            cumulative_regret: self.cumulative_regret.curve(self.runs),
+        }
+    }
+}
+
+/// Derives the seed of a single run from the Experiment seed and the run index.
+pub(crate) fn run_seed(seed: u64, run: u32) -> u64 {
+    seed.wrapping_add(u64::from(run).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+}
+
+/// Structure to repeat a Game many times and average the results.
+///
+/// Each run resets the Game to the same initial guess and reseeds it, so runs are independent but
+/// the Experiment as a whole is reproducible from its seed.
+pub struct Experiment<T: AddAssign + Num + ToPrimitive> {
+    /// Game played in every run.
+    game: Game<T>,
+    /// Initial guess of the arm values given to the Agent at the start of each run.
+    q_init: Vec<f64>,
+    /// Number of independent runs.
+    runs: u32,
+    /// Number of steps in each run.
+    steps: u32,
+    /// Seed from which the seed of each run is derived.
+    seed: u64,
+}
+
+impl<T: AddAssign + Copy + Num + ToPrimitive> Experiment<T> {
+    /// Initializes an Experiment that plays a Game `runs` times for `steps` steps each. Panics if
+    /// the parameters are invalid; see `try_new`.
+    pub fn new(game: Game<T>, q_init: Vec<f64>, runs: u32, steps: u32, seed: u64) -> Experiment<T> {
+        Experiment::try_new(game, q_init, runs, steps, seed).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes an Experiment that plays a Game `runs` times for `steps` steps each. Fails if
+    /// the initial guess does not have one value per arm or if there are no runs.
+    pub fn try_new(
+        game: Game<T>,
+        q_init: Vec<f64>,
+        runs: u32,
+        steps: u32,
+        seed: u64,
+    ) -> Result<Experiment<T>, RatelError> {
+        check_len("q_init", q_init.len(), game.arms())?;
+        check("runs", runs, "must be positive", |n| n > 0.0)?;
+        Ok(Experiment {
+            game,
+            q_init,
+            runs,
+            steps,
+            seed,
+        })
+    }
+
+    /// Plays every run and returns the averaged curves.
+    pub fn run(&mut self) -> ExperimentSummary {
+        let mut stats = ExperimentStats::new(self.steps);
+        for run in 0..self.runs {
+            self.game.reset(&self.q_init);
+            self.game.reseed(run_seed(self.seed, run));
+            self.game.run(self.steps);
+            stats.record(&self.game);
+        }
+        stats.summary()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{
+        BinomialBandit, EpsilonGreedyAgent, Game, GreedyAgent, HarmonicStepper, RatelError,
+    };
+
+    use super::{CurveStats, Experiment};
+
+    fn epsilon_experiment(runs: u32, seed: u64) -> Experiment<u32> {
+        let q_init = vec![0.0; 3];
+        let stepper = HarmonicStepper::new(1, 3);
+        let agent = EpsilonGreedyAgent::new(q_init.clone(), Box::new(stepper), 0.1);
+        let bandit = BinomialBandit::new(vec![5, 5, 5], vec![0.2, 0.5, 0.4]);
+        let game = Game::new(Box::new(agent), Box::new(bandit));
+        Experiment::new(game, q_init, runs, 100, seed)
+    }
+
+    #[test]
+    fn test_curve_stats() {
+        let mut stats = CurveStats::new(2);
+        stats.update(1, vec![1.0, 4.0].into_iter());
+        stats.update(2, vec![3.0, 4.0].into_iter());
+        stats.update(3, vec![5.0, 4.0].into_iter());
+        let curve = stats.curve(3);
+        assert_eq!(curve.mean(), &vec![3.0, 4.0]);
+        assert_approx_eq!(curve.std_err()[0], (4.0f64 / 3.0).sqrt());
+        assert_eq!(curve.std_err()[1], 0.0)
+    }
+
+    #[test]
+    fn test_curve_stats_merge() {
+        let mut stats = CurveStats::new(1);
+        stats.update(1, vec![1.0].into_iter());
+        let mut other = CurveStats::new(1);
+        other.update(1, vec![3.0].into_iter());
+        other.update(2, vec![5.0].into_iter());
+        stats.merge(1, &other, 2);
+        let curve = stats.curve(3);
+        assert_approx_eq!(curve.mean()[0], 3.0);
+        assert_approx_eq!(curve.std_err()[0], (4.0f64 / 3.0).sqrt())
+    }
+
+    #[test]
+    fn test_run() {
+        let summary = epsilon_experiment(20, 1).run();
+        assert_eq!(summary.runs(), 20);
+        assert_eq!(summary.rewards().mean().len(), 100);
+        assert_eq!(summary.win_rate().std_err().len(), 100);
+        assert!(summary
+            .win_rate()
+            .mean()
+            .iter()
+            .all(|w| (0.0..=1.0).contains(w)))
+    }
+
+    #[test]
+    fn test_run_greedy() {
+        let q_init = vec![0.0, 10.0];
+        let stepper = HarmonicStepper::new(1, 2);
+        let agent = GreedyAgent::new(q_init.clone(), Box::new(stepper));
+        let bandit = BinomialBandit::new(vec![1, 4], vec![1.0, 1.0]);
+        let game = Game::new(Box::new(agent), Box::new(bandit));
+        let summary = Experiment::new(game, q_init, 5, 10, 0).run();
+        assert_eq!(summary.win_rate().mean(), &vec![1.0; 10]);
+        assert_eq!(summary.rewards().mean(), &vec![4.0; 10]);
+        assert_eq!(summary.regret().std_err(), &vec![0.0; 10])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let agent = GreedyAgent::new(vec![0.0; 2], Box::new(stepper));
+        let bandit = BinomialBandit::new(vec![1, 4], vec![1.0, 1.0]);
+        let game = Game::new(Box::new(agent), Box::new(bandit));
+        assert_eq!(
+            Experiment::try_new(game, vec![0.0; 2], 0, 10, 0).err(),
+            Some(RatelError::InvalidParameter {
+                name: "runs",
+                index: None,
+                value: 0.0,
+                reason: "must be positive"
+            })
+        )
+    }
+
+    #[test]
+    fn test_seed() {
+        let summary1 = epsilon_experiment(10, 5).run();
+        let summary2 = epsilon_experiment(10, 5).run();
+        assert_eq!(summary1, summary2)
+    }
+}
@@ -1,8 +1,11 @@
+use std::io;
 use std::ops::AddAssign;
 
 use num_traits::{Num, ToPrimitive};
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
 
-use super::{Agent, Bandit, Counter, RecordCounter};
+use super::{Agent, Bandit, Counter, RatelError, RecordCounter, TraceStep, TraceWriter};
 
 ///Structure to make the Agent interact with the Bandit.
 pub struct Game<T: AddAssign + Num + ToPrimitive> {
@@ -10,22 +13,95 @@ pub struct Game<T: AddAssign + Num + ToPrimitive> {
     agent: Box<dyn Agent<T>>,
     ///Bandit used by agent.
     bandit: Box<dyn Bandit<T>>,
+    /// Records the arm chosen in each arm pull.
+    actions: RecordCounter<usize>,
     /// Records wins and losses from each arm pull. Win means pulling the best arm.
     wins: RecordCounter<u32>,
     /// Records rewards from each arm pull.
     rewards: RecordCounter<T>,
+    /// Records the pseudo-regret of each arm pull, the gap between the best arm's mean and the
+    /// mean of the arm pulled.
+    regret: RecordCounter<f64>,
+    /// Records the running total of the pseudo-regret after each arm pull.
+    cumulative_regret: RecordCounter<f64>,
+    /// Random number generator used by the agent to choose arms.
+    agent_rng: StdRng,
+    /// Random number generator used by the bandit to draw rewards.
+    bandit_rng: StdRng,
 }
 
 impl<T: AddAssign + Copy + Num + ToPrimitive> Game<T> {
-    /// Initializes a Game with an Agent, Bandit, and new counters.
+    /// Initializes a Game with an Agent, Bandit, and new counters. Randomness is seeded from the
+    /// operating system, so runs cannot be replayed.
     pub fn new(agent: Box<dyn Agent<T>>, bandit: Box<dyn Bandit<T>>) -> Game<T> {
-        assert_eq!(agent.arms(), bandit.arms());
-        Game {
+        Game::with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game with an Agent, Bandit, and new counters, failing if the Agent and the
+    /// Bandit have different numbers of arms.
+    pub fn try_new(
+        agent: Box<dyn Agent<T>>,
+        bandit: Box<dyn Bandit<T>>,
+    ) -> Result<Game<T>, RatelError> {
+        Game::try_with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed.
+    pub fn with_seed(agent: Box<dyn Agent<T>>, bandit: Box<dyn Bandit<T>>, seed: u64) -> Game<T> {
+        Game::with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed, failing if the
+    /// Agent and the Bandit have different numbers of arms.
+    pub fn try_with_seed(
+        agent: Box<dyn Agent<T>>,
+        bandit: Box<dyn Bandit<T>>,
+        seed: u64,
+    ) -> Result<Game<T>, RatelError> {
+        Game::try_with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator.
+    ///
+    /// Independent streams for the Agent and the Bandit are split from the generator, so the
+    /// rewards drawn do not depend on how much randomness the Agent consumes.
+    pub fn with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn Agent<T>>,
+        bandit: Box<dyn Bandit<T>>,
+        rng: &mut R,
+    ) -> Game<T> {
+        Game::try_with_rng(agent, bandit, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator,
+    /// failing if the Agent and the Bandit have different numbers of arms.
+    pub fn try_with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn Agent<T>>,
+        bandit: Box<dyn Bandit<T>>,
+        rng: &mut R,
+    ) -> Result<Game<T>, RatelError> {
+        if agent.arms() != bandit.arms() {
+            return Err(RatelError::ArmMismatch {
+                agent: agent.arms(),
+                bandit: bandit.arms(),
+            });
+        }
+        Ok(Game {
             agent,
             bandit,
+            actions: RecordCounter::new(),
             wins: RecordCounter::new(),
             rewards: RecordCounter::new(),
-        }
+            regret: RecordCounter::new(),
+            cumulative_regret: RecordCounter::new(),
+            agent_rng: StdRng::from_rng(&mut *rng).unwrap(),
+            bandit_rng: StdRng::from_rng(&mut *rng).unwrap(),
+        })
+    }
+
+    /// Returns vector of the arms chosen.
+    pub fn actions(&self) -> &Vec<usize> {
+        self.actions.record()
     }
 
     /// Returns the number of bandit arms.
@@ -35,19 +111,47 @@ impl<T: AddAssign + Copy + Num + ToPrimitive> Game<T> {
 
     /// Agent chooses an arm to pull and updates based on reward.
     fn pull_arm(&mut self) {
-        let current_action = self.agent.action();
+        let current_action = self.agent.action(&mut self.agent_rng);
+        self.actions.update(current_action);
         self.wins
             .update((current_action == self.bandit.best_arm()) as u32);
-        let reward = self.bandit.reward(current_action);
+        let reward = self.bandit.reward(current_action, &mut self.bandit_rng);
         self.rewards.update(reward);
+        let regret = self.bandit.max_reward() - self.bandit.mean(current_action);
+        let total = self.cumulative_regret.record().last().unwrap_or(&0.0) + regret;
+        self.regret.update(regret);
+        self.cumulative_regret.update(total);
         self.agent.step(current_action, reward);
+        self.bandit.advance(&mut self.bandit_rng);
+    }
+
+    /// Replaces the Agent and Bandit random number streams with streams split from the given seed.
+    pub fn reseed(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.agent_rng = StdRng::from_rng(&mut rng).unwrap();
+        self.bandit_rng = StdRng::from_rng(&mut rng).unwrap();
     }
 
-    /// Resets Game. Resets Agent with new initial guess and resets counters.
+    /// Resets Game. Resets Agent with new initial guess, returns the Bandit to its initial state,
+    /// and resets counters.
     pub fn reset(&mut self, q_init: &[f64]) {
         self.agent.reset(q_init);
+        self.bandit.reset();
+        self.actions.reset();
         self.rewards.reset();
         self.wins.reset();
+        self.regret.reset();
+        self.cumulative_regret.reset();
+    }
+
+    /// Returns vector of running totals of pseudo-regret.
+    pub fn cumulative_regret(&self) -> &Vec<f64> {
+        self.cumulative_regret.record()
+    }
+
+    /// Returns vector of pseudo-regrets.
+    pub fn regret(&self) -> &Vec<f64> {
+        self.regret.record()
     }
 
     /// Returns vector of rewards.
@@ -62,8 +166,144 @@ impl<T: AddAssign + Copy + Num + ToPrimitive> Game<T> {
         }
     }
 
+    /// Run game for a certain number of steps, writing each step to a trace.
+    ///
+    /// Each step records the arm chosen, the reward, whether the arm was the best arm, and the
+    /// Agent's estimates of every arm after updating on the reward.
+    pub fn run_traced(&mut self, steps: u32, writer: &mut dyn TraceWriter) -> io::Result<()> {
+        for _ in 1..=steps {
+            self.pull_arm();
+            let step = TraceStep {
+                step: self.actions.record().len(),
+                arm: *self.actions.record().last().unwrap(),
+                reward: self.rewards.record().last().unwrap().to_f64().unwrap(),
+                win: *self.wins.record().last().unwrap() == 1,
+                estimates: (0..self.arms())
+                    .map(|arm| self.agent.current_estimate(arm))
+                    .collect(),
+            };
+            writer.write_step(&step)?;
+        }
+        writer.flush()
+    }
+
     /// Returns vector of wins.
     pub fn wins(&self) -> &Vec<u32> {
         self.wins.record()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::{
+        Bandit, BinomialBandit, EpsilonGreedyAgent, GaussianBandit, GreedyAgent, HarmonicStepper,
+        RatelError, SwitchingBandit,
+    };
+
+    use super::Game;
+
+    fn greedy_game() -> Game<f64> {
+        let stepper = HarmonicStepper::new(1, 3);
+        let agent = GreedyAgent::new(vec![0.0, 10.0, 0.0], Box::new(stepper));
+        let bandit = GaussianBandit::new(vec![1.0, 0.5, 2.0], vec![0.1, 0.1, 0.1]);
+        Game::new(Box::new(agent), Box::new(bandit))
+    }
+
+    fn epsilon_game(seed: u64) -> Game<u32> {
+        let stepper = HarmonicStepper::new(1, 3);
+        let agent = EpsilonGreedyAgent::new(vec![0.0; 3], Box::new(stepper), 0.2);
+        let bandit = BinomialBandit::new(vec![5, 5, 5], vec![0.2, 0.5, 0.4]);
+        Game::with_seed(Box::new(agent), Box::new(bandit), seed)
+    }
+
+    #[test]
+    fn test_cumulative_regret() {
+        let mut game = greedy_game();
+        game.run(3);
+        game.cumulative_regret()
+            .iter()
+            .zip(vec![1.5, 3.0, 4.5])
+            .for_each(|(r1, r2)| assert_approx_eq!(r1, r2))
+    }
+
+    #[test]
+    fn test_regret() {
+        let mut game = greedy_game();
+        game.run(3);
+        assert_eq!(game.regret(), &vec![1.5; 3]);
+        assert_eq!(game.wins(), &vec![0; 3]);
+        assert_eq!(game.actions(), &vec![1; 3])
+    }
+
+    #[test]
+    fn test_try_new() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let agent = GreedyAgent::new(vec![0.0, 0.0], Box::new(stepper));
+        let bandit = GaussianBandit::new(vec![1.0, 0.5, 2.0], vec![0.1, 0.1, 0.1]);
+        let game: Result<Game<f64>, RatelError> =
+            Game::try_new(Box::new(agent), Box::new(bandit));
+        assert_eq!(
+            game.err(),
+            Some(RatelError::ArmMismatch {
+                agent: 2,
+                bandit: 3
+            })
+        )
+    }
+
+    #[test]
+    fn test_with_seed() {
+        let mut game1 = epsilon_game(42);
+        let mut game2 = epsilon_game(42);
+        game1.run(500);
+        game2.run(500);
+        assert_eq!(game1.rewards(), game2.rewards());
+        assert_eq!(game1.wins(), game2.wins())
+    }
+
+    #[test]
+    fn test_reseed() {
+        let mut game1 = epsilon_game(42);
+        let mut game2 = epsilon_game(7);
+        game1.run(10);
+        game1.reset(&[0.0; 3]);
+        game1.reseed(3);
+        game1.run(500);
+        game2.reseed(3);
+        game2.run(500);
+        assert_eq!(game1.rewards(), game2.rewards())
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut game = greedy_game();
+        game.run(3);
+        game.reset(&[0.0, 0.0, 10.0]);
+        assert!(game.regret().is_empty());
+        assert!(game.cumulative_regret().is_empty());
+        game.run(2);
+        assert_eq!(game.regret(), &vec![0.0; 2]);
+        assert_eq!(game.wins(), &vec![1; 2])
+    }
+
+    #[test]
+    fn test_switching_bandit() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let agent = GreedyAgent::new(vec![10.0, 0.0], Box::new(stepper));
+        let bandits: Vec<Box<dyn Bandit<f64>>> = vec![
+            Box::new(GaussianBandit::new(vec![1.0, 0.0], vec![0.1; 2])),
+            Box::new(GaussianBandit::new(vec![0.0, 2.0], vec![0.1; 2])),
+        ];
+        let bandit = SwitchingBandit::new(bandits, vec![2]);
+        let mut game = Game::new(Box::new(agent), Box::new(bandit));
+        game.run(4);
+        assert_eq!(game.actions(), &vec![0; 4]);
+        assert_eq!(game.wins(), &vec![1, 1, 0, 0]);
+        assert_eq!(game.regret(), &vec![0.0, 0.0, 2.0, 2.0]);
+        game.reset(&[10.0, 0.0]);
+        game.run(1);
+        assert_eq!(game.wins(), &vec![1])
+    }
+}
@@ -7,18 +7,59 @@
 //! bandit arm. A Game then consists of an Agent learning about a Bandit by pulling arms according
 //! to its preferred strategy.
 
+#![allow(clippy::module_inception)]
+
 #[cfg(test)]
 #[macro_use]
 extern crate lazy_static;
 
-pub use agent::{Agent, EpsilonGreedyAgent, GreedyAgent, OptimisticAgent};
+pub use agent::{
+    Agent, ChangePointAgent, ContextualAgent, DiscountedUcbAgent, EpsilonGreedyAgent, Exp3Agent,
+    Exp3IxAgent, Exp3PAgent, GradientAgent, GreedyAgent, KlUcbAgent, LinTsAgent, LinUcbAgent,
+    OptimisticAgent, Restart, RobustUcbAgent, SlidingWindowUcbAgent, SoftmaxAgent,
+    ThompsonSamplingAgent, UcbAgent,
+};
 pub use bandit::{
-    Bandit, BinomialBandit, ExponentialBandit, GammaBandit, GaussianBandit, LogNormalBandit,
+    Bandit, BernoulliBandit, BernoulliBanditBuilder, BetaBandit, BetaBanditBuilder, BinomialBandit,
+    BinomialBanditBuilder, BoxedDistribution, ContaminatedGaussianBandit,
+    ContaminatedGaussianBanditBuilder, ContextualBandit, DistributionBandit,
+    DistributionBanditBuilder, DriftingBandit, EmpiricalDistribution, ExponentialBandit,
+    ExponentialBanditBuilder, GammaBandit, GammaBanditBuilder, GaussianBandit,
+    GaussianBanditBuilder, LinearBandit, LoggedEvent, LogNormalBandit, LogNormalBanditBuilder,
+    LogisticBandit, MixtureDistribution, MixtureDistributionBuilder, Moments, ParetoBandit,
+    ParetoBanditBuilder, PoissonBandit, PoissonBanditBuilder, ReplayBandit, StudentTBandit,
+    StudentTBanditBuilder, SwitchingBandit, UniformBandit, UniformBanditBuilder, WeibullBandit,
+    WeibullBanditBuilder,
 };
+pub use config::{
+    AgentConfig, AgentSpec, BanditConfig, ConfigError, DetectorConfig, EstimatorConfig,
+    ExperimentConfig, IndexConfig, ScheduleConfig, StepperConfig,
+};
+pub use contextual_game::ContextualGame;
+pub use experiment::{Curve, Experiment, ExperimentSummary};
+pub use error::RatelError;
 pub use game::Game;
-pub use util::{Counter, HarmonicStepper, print_hms, RecordCounter, Stepper};
+pub use parallel::ParallelExperiment;
+pub use replay_game::ReplayGame;
+pub use trace::{CsvTraceWriter, JsonLinesTraceWriter, TraceStep, TraceWriter};
+pub use util::{
+    ArgBounds, BernoulliDivergence, BetaPosterior, ChangeDetector, ConstantSchedule,
+    ConstantStepper, ConstantStepperBuilder, Counter, CusumDetector, Divergence, EstimatorState,
+    ExponentialDivergence, ExponentialSchedule, GaussianDivergence, GlrDetector, HarmonicStepper,
+    HarmonicStepperBuilder, InverseSchedule, LinearSchedule, LogarithmicSchedule,
+    MedianOfMeansEstimator, MossIndex, NormalGammaPosterior, PageHinkleyDetector, PoissonDivergence,
+    Posterior, print_hms, RecordCounter, RewardScale, RobustEstimator, Schedule, Stepper,
+    TruncatedMeanEstimator, Ucb1Index, Ucb1TunedIndex, UcbIndex, UcbVIndex,
+};
 
 mod agent;
 mod bandit;
+mod config;
+mod contextual_game;
+mod error;
+mod experiment;
 mod game;
+mod parallel;
+mod replay_game;
+mod trace;
 mod util;
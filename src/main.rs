@@ -0,0 +1,738 @@
+//! Command line interface to the Ratel bandit simulator.
+//!
+//! Builds a Bandit, an Agent, and a Stepper from flags, or a whole study from a config file, plays
+//! the Game over many independent runs, and prints summary statistics or writes the averaged
+//! per-step curves to a CSV file.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::time::Instant;
+
+use clap::error::ErrorKind;
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+
+use ratel_bandit::{
+    print_hms, AgentConfig, AgentSpec, BanditConfig, ConfigError, DetectorConfig, EstimatorConfig,
+    ExperimentConfig, ExperimentSummary, IndexConfig, Restart, ScheduleConfig, StepperConfig,
+};
+
+/// Defines the command line flags.
+fn command() -> Command {
+    let list = |name: &'static str, help: &'static str| {
+        Arg::new(name)
+            .long(name)
+            .help(help)
+            .value_delimiter(',')
+            .value_parser(value_parser!(f64))
+    };
+    Command::new("ratel")
+        .about("Runs multi-armed bandit simulations")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .help("TOML or JSON file describing the bandit, the agents, and the run plan")
+                .conflicts_with("bandit"),
+        )
+        .arg(
+            Arg::new("bandit")
+                .long("bandit")
+                .help("Distribution family of the bandit arms")
+                .required_unless_present("config")
+                .value_parser([
+                    "binomial",
+                    "bernoulli",
+                    "poisson",
+                    "gaussian",
+                    "exponential",
+                    "gamma",
+                    "log-normal",
+                    "uniform",
+                    "beta",
+                    "student-t",
+                    "pareto",
+                    "weibull",
+                    "contaminated",
+                    "empirical",
+                ]),
+        )
+        .arg(
+            Arg::new("nums")
+                .long("nums")
+                .help("Trials per pull of each binomial arm")
+                .value_delimiter(',')
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(list(
+            "probs",
+            "Success probabilities of the binomial or bernoulli arms",
+        ))
+        .arg(list(
+            "means",
+            "Means of the gaussian or student-t arms, or centers of the contaminated arms",
+        ))
+        .arg(list(
+            "stds",
+            "Standard deviations of the gaussian arms or of the contaminated arms' inliers",
+        ))
+        .arg(list("lambdas", "Rates of the exponential or poisson arms"))
+        .arg(list("alphas", "Shapes of the gamma arms, or first shapes of the beta arms"))
+        .arg(list("thetas", "Scales of the gamma arms"))
+        .arg(list(
+            "mus",
+            "Means of the log-normal arms' underlying normals",
+        ))
+        .arg(list(
+            "sigmas",
+            "Standard deviations of the log-normal arms' underlying normals",
+        ))
+        .arg(list("lows", "Lower ends of the uniform arms"))
+        .arg(list("highs", "Upper ends of the uniform arms"))
+        .arg(list("betas", "Second shapes of the beta arms"))
+        .arg(list("scales", "Scales of the student-t, pareto or weibull arms"))
+        .arg(list("shapes", "Shapes of the pareto or weibull arms"))
+        .arg(list("dofs", "Degrees of freedom of the student-t arms"))
+        .arg(
+            Arg::new("files")
+                .long("files")
+                .help(
+                    "Files of observed rewards the empirical arms resample, one per arm, with \
+                     rewards separated by commas, spaces or newlines",
+                )
+                .value_delimiter(','),
+        )
+        .arg(
+            Arg::new("contamination")
+                .long("contamination")
+                .help("Probability a contaminated arm pays a Cauchy outlier")
+                .default_value("0.1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("outlier-scale")
+                .long("outlier-scale")
+                .help("Scale of the Cauchy outliers of the contaminated arms")
+                .default_value("10.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("agent")
+                .long("agent")
+                .help("Strategy used to pick arms")
+                .default_value("epsilon-greedy")
+                .value_parser([
+                    "greedy",
+                    "epsilon-greedy",
+                    "optimistic",
+                    "thompson",
+                    "ucb",
+                    "sliding-window-ucb",
+                    "discounted-ucb",
+                    "robust-ucb",
+                    "kl-ucb",
+                    "gradient",
+                    "softmax",
+                    "exp3",
+                    "exp3-ix",
+                    "exp3-p",
+                ]),
+        )
+        .arg(
+            Arg::new("epsilon")
+                .long("epsilon")
+                .help(
+                    "Probability of a random arm for the epsilon-greedy agent, its initial value, or \
+                     the scale of the inverse and logarithmic schedules",
+                )
+                .default_value("0.1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("c")
+                .long("c")
+                .help("Confidence bound multiple for the optimistic agent")
+                .default_value("2.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("alpha")
+                .long("alpha")
+                .help("Preference step size of the gradient agent")
+                .default_value("0.1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("gamma")
+                .long("gamma")
+                .help("Probability of a uniformly random arm for the exp3 agent")
+                .default_value("0.1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("eta")
+                .long("eta")
+                .help("Learning rate of the exp3-ix agent")
+                .default_value("0.1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("delta")
+                .long("delta")
+                .help(
+                    "Failure probability of the exp3-p agent and false alarm probability of the \
+                     glr detector; exp3-p uses --steps as its horizon",
+                )
+                .default_value("0.05")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(list(
+            "reward-range",
+            "Lowest and highest rewards the exp3 agents scale to [0, 1]; defaults to the range of \
+             binomial or gaussian arms",
+        ))
+        .arg(
+            Arg::new("temperature")
+                .long("temperature")
+                .help("Initial temperature, or scale of the logarithmic schedule, of the softmax agent")
+                .default_value("1.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("schedule")
+                .long("schedule")
+                .help("How the softmax temperature or the epsilon-greedy epsilon changes over time")
+                .default_value("constant")
+                .value_parser(["constant", "exponential", "inverse", "logarithmic"]),
+        )
+        .arg(
+            Arg::new("decay")
+                .long("decay")
+                .help("Factor the value is multiplied by each step in the exponential schedule")
+                .default_value("0.99")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("prior-weight")
+                .long("prior-weight")
+                .help("Pseudo-trials of the initial guess in the thompson agent's Beta prior")
+                .default_value("0.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("index")
+                .long("index")
+                .help(
+                    "Upper confidence bound rule of the ucb agents; moss uses --steps as its horizon",
+                )
+                .default_value("ucb1")
+                .value_parser(["ucb1", "ucb1-tuned", "ucb-v", "moss"]),
+        )
+        .arg(
+            Arg::new("window")
+                .long("window")
+                .help("Number of most recent rounds the sliding-window-ucb agent remembers")
+                .default_value("100")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("discount")
+                .long("discount")
+                .help("Factor the discounted-ucb agent weights past rewards by each round")
+                .default_value("0.99")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("range")
+                .long("range")
+                .help("Width of the reward range for the ucb-v index")
+                .default_value("1.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("estimator")
+                .long("estimator")
+                .help("Robust mean estimator of the robust-ucb agent")
+                .default_value("median-of-means")
+                .value_parser(["median-of-means", "truncated-mean"]),
+        )
+        .arg(
+            Arg::new("moment-bound")
+                .long("moment-bound")
+                .help(
+                    "Bound on the reward moment of order 1 + --moment-order assumed by the \
+                     robust-ucb agent, raw for truncated-mean and centered for median-of-means",
+                )
+                .default_value("1.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("moment-order")
+                .long("moment-order")
+                .help("Order of the bounded reward moment, less one, in (0, 1]")
+                .default_value("1.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("detector")
+                .long("detector")
+                .help("Change detector to wrap the agent in, restarting it when an arm changes")
+                .value_parser(["cusum", "page-hinkley", "glr"]),
+        )
+        .arg(
+            Arg::new("threshold")
+                .long("threshold")
+                .help("Threshold of the cusum and page-hinkley detectors")
+                .default_value("5.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("tolerance")
+                .long("tolerance")
+                .help("Deviation from the mean the cusum and page-hinkley detectors tolerate")
+                .default_value("0.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("glr-window")
+                .long("glr-window")
+                .help("Number of each arm's most recent rewards the glr detector tests")
+                .default_value("1000")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("warmup")
+                .long("warmup")
+                .help("Number of rewards the cusum detector estimates each arm's mean from")
+                .default_value("20")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("restart")
+                .long("restart")
+                .help("Whether a detected change resets every arm or only the changed one")
+                .default_value("global")
+                .value_parser(["global", "per-arm"]),
+        )
+        .arg(
+            Arg::new("explore")
+                .long("explore")
+                .help("Probability of a uniformly random arm when a detector is used")
+                .default_value("0.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            list(
+                "q-init",
+                "Initial guess of the arm values, one per arm or a single value for all arms",
+            )
+            .default_value("0.0"),
+        )
+        .arg(
+            Arg::new("stepper")
+                .long("stepper")
+                .help("Step size rule for agent updates")
+                .default_value("harmonic")
+                .value_parser(["harmonic", "constant"]),
+        )
+        .arg(
+            Arg::new("step-size")
+                .long("step-size")
+                .help(
+                    "Constant step size, or the initial inverse step size of the harmonic stepper",
+                )
+                .default_value("1")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("drift")
+                .long("drift")
+                .help("Standard deviation of the random walk every arm mean takes after each pull")
+                .default_value("0.0")
+                .value_parser(value_parser!(f64)),
+        )
+        .arg(
+            Arg::new("steps")
+                .long("steps")
+                .help("Number of arm pulls in each run")
+                .default_value("1000")
+                .value_parser(value_parser!(u32).range(1..)),
+        )
+        .arg(
+            Arg::new("runs")
+                .long("runs")
+                .help("Number of independent runs")
+                .default_value("100")
+                .value_parser(value_parser!(u32)),
+        )
+        .arg(
+            Arg::new("seed")
+                .long("seed")
+                .help("Seed of the random number generator")
+                .default_value("0")
+                .value_parser(value_parser!(u64)),
+        )
+        .arg(
+            Arg::new("threads")
+                .long("threads")
+                .help("Number of worker threads, one per core by default")
+                .value_parser(value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .help("CSV file to write each agent's averaged per-step curves to")
+                .action(ArgAction::Set),
+        )
+}
+
+/// Returns the values of a list flag, exiting with an error if the flag is missing.
+fn values<T: Clone + Send + Sync + 'static>(matches: &ArgMatches, name: &str) -> Vec<T> {
+    match matches.get_many::<T>(name) {
+        Some(values) => values.cloned().collect(),
+        None => command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                format!("--{} is required for this bandit", name),
+            )
+            .exit(),
+    }
+}
+
+/// Returns the value of a flag with a default.
+fn value<T: Clone + Send + Sync + 'static>(matches: &ArgMatches, name: &str) -> T {
+    matches.get_one::<T>(name).unwrap().clone()
+}
+
+/// Builds the Stepper config chosen by the flags.
+fn stepper(matches: &ArgMatches) -> StepperConfig {
+    let step_size: f64 = value(matches, "step-size");
+    match value::<String>(matches, "stepper").as_str() {
+        "constant" => StepperConfig::Constant { step_size },
+        _ if step_size.fract() != 0.0 || !(0.0..=f64::from(u32::MAX)).contains(&step_size) => {
+            command()
+                .error(
+                    ErrorKind::InvalidValue,
+                    "--step-size must be a whole number for the harmonic stepper",
+                )
+                .exit()
+        }
+        _ => StepperConfig::Harmonic {
+            step_size: step_size as u32,
+        },
+    }
+}
+
+/// Builds the upper confidence bound index config chosen by the flags.
+fn index(matches: &ArgMatches) -> IndexConfig {
+    match value::<String>(matches, "index").as_str() {
+        "ucb1-tuned" => IndexConfig::Ucb1Tuned,
+        "ucb-v" => IndexConfig::UcbV {
+            range: value(matches, "range"),
+        },
+        "moss" => IndexConfig::Moss {
+            horizon: value(matches, "steps"),
+        },
+        _ => IndexConfig::Ucb1,
+    }
+}
+
+/// Builds the robust estimator config chosen by the flags.
+fn estimator(matches: &ArgMatches) -> EstimatorConfig {
+    let epsilon = value(matches, "moment-order");
+    let bound = value(matches, "moment-bound");
+    match value::<String>(matches, "estimator").as_str() {
+        "truncated-mean" => EstimatorConfig::TruncatedMean { epsilon, u: bound },
+        _ => EstimatorConfig::MedianOfMeans { epsilon, v: bound },
+    }
+}
+
+/// Returns the reward range given by the flags, if any.
+fn reward_range(matches: &ArgMatches) -> Option<(f64, f64)> {
+    let range: Vec<f64> = matches.get_many("reward-range")?.cloned().collect();
+    match range[..] {
+        [low, high] => Some((low, high)),
+        _ => command()
+            .error(
+                ErrorKind::WrongNumberOfValues,
+                "--reward-range takes the lowest and highest rewards",
+            )
+            .exit(),
+    }
+}
+
+/// Builds the schedule config chosen by the flags, starting from the value of the given flag.
+fn schedule(matches: &ArgMatches, name: &str) -> ScheduleConfig {
+    let initial: f64 = value(matches, name);
+    match value::<String>(matches, "schedule").as_str() {
+        "exponential" => ScheduleConfig::Exponential {
+            initial,
+            rate: value(matches, "decay"),
+            minimum: 0.0,
+        },
+        "inverse" => ScheduleConfig::Inverse { scale: initial },
+        "logarithmic" => ScheduleConfig::Logarithmic { scale: initial },
+        _ => ScheduleConfig::Constant { value: initial },
+    }
+}
+
+/// Builds the Agent config chosen by the flags.
+fn agent(matches: &ArgMatches) -> AgentConfig {
+    match value::<String>(matches, "agent").as_str() {
+        "greedy" => AgentConfig::Greedy {
+            stepper: stepper(matches),
+        },
+        "optimistic" => AgentConfig::Optimistic {
+            c: value(matches, "c"),
+            stepper: stepper(matches),
+        },
+        "thompson" => AgentConfig::Thompson {
+            prior_weight: value(matches, "prior-weight"),
+            lambda: 1.0,
+            alpha: 1.0,
+            beta: 1.0,
+        },
+        "ucb" => AgentConfig::Ucb {
+            index: index(matches),
+        },
+        "sliding-window-ucb" => AgentConfig::SlidingWindowUcb {
+            window: value(matches, "window"),
+            index: index(matches),
+        },
+        "discounted-ucb" => AgentConfig::DiscountedUcb {
+            gamma: value(matches, "discount"),
+            index: index(matches),
+        },
+        "robust-ucb" => AgentConfig::RobustUcb {
+            estimator: estimator(matches),
+        },
+        "kl-ucb" => AgentConfig::KlUcb { c: 0.0 },
+        "softmax" => AgentConfig::Softmax {
+            temperature: schedule(matches, "temperature"),
+            stepper: stepper(matches),
+        },
+        "gradient" => AgentConfig::Gradient {
+            alpha: value(matches, "alpha"),
+            stepper: stepper(matches),
+        },
+        "exp3" => AgentConfig::Exp3 {
+            gamma: value(matches, "gamma"),
+            reward_range: reward_range(matches),
+        },
+        "exp3-ix" => AgentConfig::Exp3Ix {
+            eta: value(matches, "eta"),
+            gamma: None,
+            reward_range: reward_range(matches),
+        },
+        "exp3-p" => AgentConfig::Exp3P {
+            horizon: value(matches, "steps"),
+            delta: value(matches, "delta"),
+            reward_range: reward_range(matches),
+        },
+        _ => AgentConfig::EpsilonGreedy {
+            epsilon: schedule(matches, "epsilon"),
+            stepper: stepper(matches),
+        },
+    }
+}
+
+/// Wraps the Agent config in a change-point Agent if the flags choose a detector.
+fn change_point(matches: &ArgMatches, agent: AgentConfig) -> AgentConfig {
+    let tolerance: f64 = value(matches, "tolerance");
+    let detector = match matches.get_one::<String>("detector").map(String::as_str) {
+        Some("cusum") => DetectorConfig::Cusum {
+            epsilon: tolerance,
+            threshold: value(matches, "threshold"),
+            warmup: value(matches, "warmup"),
+        },
+        Some("page-hinkley") => DetectorConfig::PageHinkley {
+            delta: tolerance,
+            threshold: value(matches, "threshold"),
+        },
+        Some(_) => DetectorConfig::Glr {
+            delta: value(matches, "delta"),
+            window: value(matches, "glr-window"),
+        },
+        None => return agent,
+    };
+    AgentConfig::ChangePoint {
+        agent: Box::new(agent),
+        detector,
+        restart: match value::<String>(matches, "restart").as_str() {
+            "per-arm" => Restart::PerArm,
+            _ => Restart::Global,
+        },
+        alpha: value(matches, "explore"),
+    }
+}
+
+/// Builds the Bandit config chosen by the flags.
+fn bandit(matches: &ArgMatches) -> BanditConfig {
+    let m = matches;
+    match value::<String>(m, "bandit").as_str() {
+        "binomial" => BanditConfig::Binomial {
+            nums: values(m, "nums"),
+            probs: values(m, "probs"),
+        },
+        "gaussian" => BanditConfig::Gaussian {
+            means: values(m, "means"),
+            stds: values(m, "stds"),
+        },
+        "bernoulli" => BanditConfig::Bernoulli {
+            probs: values(m, "probs"),
+        },
+        "poisson" => BanditConfig::Poisson {
+            lambdas: values(m, "lambdas"),
+        },
+        "exponential" => BanditConfig::Exponential {
+            lambdas: values(m, "lambdas"),
+        },
+        "gamma" => BanditConfig::Gamma {
+            alphas: values(m, "alphas"),
+            thetas: values(m, "thetas"),
+        },
+        "uniform" => BanditConfig::Uniform {
+            lows: values(m, "lows"),
+            highs: values(m, "highs"),
+        },
+        "beta" => BanditConfig::Beta {
+            alphas: values(m, "alphas"),
+            betas: values(m, "betas"),
+        },
+        "student-t" => BanditConfig::StudentT {
+            means: values(m, "means"),
+            scales: values(m, "scales"),
+            dofs: values(m, "dofs"),
+        },
+        "pareto" => BanditConfig::Pareto {
+            scales: values(m, "scales"),
+            shapes: values(m, "shapes"),
+        },
+        "weibull" => BanditConfig::Weibull {
+            scales: values(m, "scales"),
+            shapes: values(m, "shapes"),
+        },
+        "empirical" => BanditConfig::Empirical {
+            paths: values(m, "files"),
+        },
+        "contaminated" => BanditConfig::Contaminated {
+            means: values(m, "means"),
+            stds: values(m, "stds"),
+            epsilon: value(m, "contamination"),
+            scale: value(m, "outlier-scale"),
+        },
+        _ => BanditConfig::LogNormal {
+            mus: values(m, "mus"),
+            sigmas: values(m, "sigmas"),
+        },
+    }
+}
+
+/// Loads the experiment config file, or builds a single-agent config from the flags.
+fn config(matches: &ArgMatches) -> Result<ExperimentConfig, ConfigError> {
+    let mut config = match matches.get_one::<String>("config") {
+        Some(path) => ExperimentConfig::from_file(path)?,
+        None => ExperimentConfig {
+            bandit: bandit(matches),
+            agents: vec![AgentSpec {
+                name: None,
+                agent: change_point(matches, agent(matches)),
+            }],
+            q_init: values(matches, "q-init"),
+            steps: value(matches, "steps"),
+            runs: value(matches, "runs"),
+            seed: value(matches, "seed"),
+            drift: value(matches, "drift"),
+            threads: None,
+        },
+    };
+    if let Some(&threads) = matches.get_one::<usize>("threads") {
+        config.threads = Some(threads)
+    }
+    Ok(config)
+}
+
+/// Prints the final and average values of the averaged curves.
+fn print_summary(name: &str, summary: &ExperimentSummary) {
+    let steps = summary.rewards().mean().len();
+    println!("{}", name);
+    println!("  runs: {}, steps: {}", summary.runs(), steps);
+    if steps == 0 {
+        return;
+    }
+    let last = steps - 1;
+    let average = |v: &Vec<f64>| v.iter().sum::<f64>() / v.len() as f64;
+    println!(
+        "  final win rate: {:.4} ± {:.4}",
+        summary.win_rate().mean()[last],
+        summary.win_rate().std_err()[last]
+    );
+    println!(
+        "  average win rate: {:.4}",
+        average(summary.win_rate().mean())
+    );
+    println!("  average reward: {:.4}", average(summary.rewards().mean()));
+    println!(
+        "  final cumulative regret: {:.4} ± {:.4}",
+        summary.cumulative_regret().mean()[last],
+        summary.cumulative_regret().std_err()[last]
+    );
+}
+
+/// Writes each Agent's averaged curves and their standard errors to a CSV file, one row per Agent
+/// and step.
+fn write_curves(file: File, results: &[(String, ExperimentSummary)]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(file);
+    writeln!(
+        writer,
+        "agent,step,win_rate,win_rate_se,reward,reward_se,regret,regret_se,cumulative_regret,cumulative_regret_se"
+    )?;
+    for (name, summary) in results {
+        let curves = [
+            summary.win_rate(),
+            summary.rewards(),
+            summary.regret(),
+            summary.cumulative_regret(),
+        ];
+        for step in 0..summary.rewards().mean().len() {
+            write!(writer, "{},{}", name, step + 1)?;
+            for curve in &curves {
+                write!(writer, ",{},{}", curve.mean()[step], curve.std_err()[step])?;
+            }
+            writeln!(writer)?;
+        }
+    }
+    writer.flush()
+}
+
+fn main() {
+    let matches = command().get_matches();
+    let start = Instant::now();
+    let config = match config(&matches) {
+        Ok(config) => config,
+        Err(e) => command().error(ErrorKind::InvalidValue, e).exit(),
+    };
+    let output = matches.get_one::<String>("output").map(|path| {
+        let file = File::create(path).unwrap_or_else(|e| {
+            command()
+                .error(ErrorKind::Io, format!("could not write {}: {}", path, e))
+                .exit()
+        });
+        (path, file)
+    });
+    let results = match config.run() {
+        Ok(results) => results,
+        Err(e) => command().error(ErrorKind::InvalidValue, e).exit(),
+    };
+    match output {
+        Some((path, file)) => {
+            if let Err(e) = write_curves(file, &results) {
+                command()
+                    .error(ErrorKind::Io, format!("could not write {}: {}", path, e))
+                    .exit()
+            }
+        }
+        None => results
+            .iter()
+            .for_each(|(name, summary)| print_summary(name, summary)),
+    }
+    print_hms(&start)
+}
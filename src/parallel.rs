@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::ops::AddAssign;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use num_traits::{Num, ToPrimitive};
+
+use super::experiment::{run_seed, ExperimentStats};
+use super::{ExperimentSummary, Game};
+
+/// Number of consecutive runs played by a thread before its results are merged.
+const RUNS_PER_CHUNK: u32 = 16;
+
+/// Structure to play many independent runs of a Game on several threads and average the results.
+///
+/// Every run plays a fresh Game built by the factory, seeded from the Experiment seed and the run
+/// index. Runs are grouped into fixed chunks whose results are merged in order, so the averaged
+/// curves do not depend on the number of threads.
+pub struct ParallelExperiment<T, F> {
+    /// Builds the Game played in each run.
+    factory: F,
+    /// Initial guess of the arm values given to the Agent at the start of each run.
+    q_init: Vec<f64>,
+    /// Number of independent runs.
+    runs: u32,
+    /// Number of steps in each run.
+    steps: u32,
+    /// Seed from which the seed of each run is derived.
+    seed: u64,
+    /// Number of worker threads.
+    threads: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T, F> ParallelExperiment<T, F>
+where
+    T: AddAssign + Copy + Num + ToPrimitive,
+    F: Fn() -> Game<T> + Sync,
+{
+    /// Initializes a ParallelExperiment that plays `runs` Games built by `factory` for `steps`
+    /// steps each, using one thread per available core.
+    pub fn new(
+        factory: F,
+        q_init: Vec<f64>,
+        runs: u32,
+        steps: u32,
+        seed: u64,
+    ) -> ParallelExperiment<T, F> {
+        assert!(runs > 0);
+        ParallelExperiment {
+            factory,
+            q_init,
+            runs,
+            steps,
+            seed,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Plays every run and returns the averaged curves.
+    pub fn run(&self) -> ExperimentSummary {
+        let chunks = self.runs.div_ceil(RUNS_PER_CHUNK);
+        let next_chunk = AtomicU32::new(0);
+        let merged = Mutex::new((ExperimentStats::new(self.steps), 0, BTreeMap::new()));
+        let (factory, q_init) = (&self.factory, &self.q_init);
+        let (runs, steps, seed) = (self.runs, self.steps, self.seed);
+        thread::scope(|scope| {
+            for _ in 0..self.threads.min(chunks as usize) {
+                scope.spawn(|| loop {
+                    let chunk = next_chunk.fetch_add(1, Ordering::Relaxed);
+                    if chunk >= chunks {
+                        break;
+                    }
+                    let mut stats = ExperimentStats::new(steps);
+                    let first = chunk * RUNS_PER_CHUNK;
+                    for run in first..runs.min(first + RUNS_PER_CHUNK) {
+                        let mut game = factory();
+                        game.reset(q_init);
+                        game.reseed(run_seed(seed, run));
+                        game.run(steps);
+                        stats.record(&game);
+                    }
+                    let mut guard = merged.lock().unwrap();
+                    let (total, next, pending) = &mut *guard;
+                    pending.insert(chunk, stats);
+                    while let Some(stats) = pending.remove(next) {
+                        total.merge(&stats);
+                        *next += 1;
+                    }
+                });
+            }
+        });
+        merged.into_inner().unwrap().0.summary()
+    }
+
+    /// Sets the number of worker threads.
+    pub fn set_threads(&mut self, threads: usize) {
+        assert!(threads > 0);
+        self.threads = threads
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinomialBandit, EpsilonGreedyAgent, Game, HarmonicStepper};
+
+    use super::ParallelExperiment;
+
+    fn epsilon_game() -> Game<u32> {
+        let stepper = HarmonicStepper::new(1, 3);
+        let agent = EpsilonGreedyAgent::new(vec![0.0; 3], Box::new(stepper), 0.1);
+        let bandit = BinomialBandit::new(vec![5, 5, 5], vec![0.2, 0.5, 0.4]);
+        Game::new(Box::new(agent), Box::new(bandit))
+    }
+
+    #[test]
+    fn test_run() {
+        let experiment = ParallelExperiment::new(epsilon_game, vec![0.0; 3], 40, 50, 3);
+        let summary = experiment.run();
+        assert_eq!(summary.runs(), 40);
+        assert_eq!(summary.regret().mean().len(), 50)
+    }
+
+    #[test]
+    fn test_threads() {
+        let mut experiment = ParallelExperiment::new(epsilon_game, vec![0.0; 3], 70, 50, 11);
+        experiment.set_threads(1);
+        let summary1 = experiment.run();
+        experiment.set_threads(4);
+        let summary4 = experiment.run();
+        assert_eq!(summary1, summary4)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_threads() {
+        let mut experiment = ParallelExperiment::new(epsilon_game, vec![0.0; 3], 10, 10, 0);
+        experiment.set_threads(0)
+    }
+}
@@ -0,0 +1,300 @@
+use rand::rngs::StdRng;
+use rand::{thread_rng, RngCore, SeedableRng};
+
+use super::{Agent, Counter, RatelError, RecordCounter, ReplayBandit};
+
+/// Structure to evaluate an Agent offline against a log of pulls made by another policy, with
+/// the replay method of Li, Chu, Langford, and Wang (2011).
+///
+/// Each logged event is offered to the Agent in turn. When the Agent chooses the logged arm the
+/// event is matched: the Agent learns from the logged reward and the reward counts towards the
+/// estimate. Otherwise the event is skipped, and the Agent neither learns nor advances.
+///
+/// Each matched reward is weighted by the inverse of its logging propensity, and the policy
+/// value is the weighted mean of the matched rewards. When the log was made by a uniformly
+/// random policy every weight is the same, and the value is the plain mean of Li et al.,
+/// unbiased for the Agent's average reward. The effective sample size `(sum w)^2 / sum w^2`
+/// of the weights measures how much evidence the value rests on.
+pub struct ReplayGame {
+    /// Agent being evaluated.
+    agent: Box<dyn Agent<f64>>,
+    /// The logged pulls.
+    bandit: ReplayBandit,
+    /// Number of logged events offered to the Agent so far.
+    position: usize,
+    /// Records the arm of each matched event.
+    actions: RecordCounter<usize>,
+    /// Records the reward of each matched event.
+    rewards: RecordCounter<f64>,
+    /// Records the inverse propensity weight of each matched event.
+    weights: RecordCounter<f64>,
+    /// Random number generator used by the agent to choose arms.
+    agent_rng: StdRng,
+}
+
+impl ReplayGame {
+    /// Initializes a Game with an Agent and a log. Randomness is seeded from the operating
+    /// system, so runs cannot be replayed.
+    pub fn new(agent: Box<dyn Agent<f64>>, bandit: ReplayBandit) -> ReplayGame {
+        ReplayGame::with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game with an Agent and a log, failing if the Agent and the log have
+    /// different numbers of arms.
+    pub fn try_new(
+        agent: Box<dyn Agent<f64>>,
+        bandit: ReplayBandit,
+    ) -> Result<ReplayGame, RatelError> {
+        ReplayGame::try_with_rng(agent, bandit, &mut thread_rng())
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed.
+    pub fn with_seed(agent: Box<dyn Agent<f64>>, bandit: ReplayBandit, seed: u64) -> ReplayGame {
+        ReplayGame::with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is fully determined by the given seed, failing if the
+    /// Agent and the log have different numbers of arms.
+    pub fn try_with_seed(
+        agent: Box<dyn Agent<f64>>,
+        bandit: ReplayBandit,
+        seed: u64,
+    ) -> Result<ReplayGame, RatelError> {
+        ReplayGame::try_with_rng(agent, bandit, &mut StdRng::seed_from_u64(seed))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator.
+    pub fn with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn Agent<f64>>,
+        bandit: ReplayBandit,
+        rng: &mut R,
+    ) -> ReplayGame {
+        ReplayGame::try_with_rng(agent, bandit, rng).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Game whose randomness is drawn from the given random number generator,
+    /// failing if the Agent and the log have different numbers of arms.
+    pub fn try_with_rng<R: RngCore + ?Sized>(
+        agent: Box<dyn Agent<f64>>,
+        bandit: ReplayBandit,
+        rng: &mut R,
+    ) -> Result<ReplayGame, RatelError> {
+        if agent.arms() != bandit.arms() {
+            return Err(RatelError::ArmMismatch {
+                agent: agent.arms(),
+                bandit: bandit.arms(),
+            });
+        }
+        Ok(ReplayGame {
+            agent,
+            bandit,
+            position: 0,
+            actions: RecordCounter::new(),
+            rewards: RecordCounter::new(),
+            weights: RecordCounter::new(),
+            agent_rng: StdRng::from_rng(&mut *rng).unwrap(),
+        })
+    }
+
+    /// Returns vector of the arms of the matched events.
+    pub fn actions(&self) -> &Vec<usize> {
+        self.actions.record()
+    }
+
+    /// Returns the number of bandit arms.
+    pub fn arms(&self) -> usize {
+        self.bandit.arms()
+    }
+
+    /// Returns the effective sample size of the matched events' weights, the number of matches
+    /// when the log was made by a uniformly random policy.
+    pub fn effective_sample_size(&self) -> f64 {
+        let weights = self.weights.record();
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+            return 0.0;
+        }
+        total * total / weights.iter().map(|w| w * w).sum::<f64>()
+    }
+
+    /// Returns the number of logged events offered to the Agent so far.
+    pub fn events(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of logged events the Agent matched.
+    pub fn matches(&self) -> usize {
+        self.actions.record().len()
+    }
+
+    /// Offers the next logged event to the Agent, which learns from it if it chooses the logged
+    /// arm.
+    fn offer_event(&mut self) {
+        let event = self.bandit.events()[self.position];
+        self.position += 1;
+        if self.agent.action(&mut self.agent_rng) == event.arm {
+            self.actions.update(event.arm);
+            self.rewards.update(event.reward);
+            self.weights.update(1.0 / self.bandit.propensity(&event));
+            self.agent.step(event.arm, event.reward);
+        }
+    }
+
+    /// Returns the estimated average reward of the Agent, the weighted mean of the matched
+    /// rewards, or `None` if no event has been matched.
+    pub fn policy_value(&self) -> Option<f64> {
+        let weights = self.weights.record();
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+            return None;
+        }
+        let weighted: f64 = weights
+            .iter()
+            .zip(self.rewards.record())
+            .map(|(w, r)| w * r)
+            .sum();
+        Some(weighted / total)
+    }
+
+    /// Replaces the Agent random number stream with a stream split from the given seed.
+    pub fn reseed(&mut self, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.agent_rng = StdRng::from_rng(&mut rng).unwrap();
+    }
+
+    /// Resets Game. Resets Agent with new initial guess, rewinds the log, and resets counters.
+    pub fn reset(&mut self, q_init: &[f64]) {
+        self.agent.reset(q_init);
+        self.position = 0;
+        self.actions.reset();
+        self.rewards.reset();
+        self.weights.reset();
+    }
+
+    /// Returns vector of the rewards of the matched events.
+    pub fn rewards(&self) -> &Vec<f64> {
+        self.rewards.record()
+    }
+
+    /// Offers a certain number of logged events to the Agent, stopping early at the end of the
+    /// log.
+    pub fn run(&mut self, events: u32) {
+        for _ in 1..=events {
+            if self.position == self.bandit.events().len() {
+                break;
+            }
+            self.offer_event()
+        }
+    }
+
+    /// Offers every remaining logged event to the Agent.
+    pub fn run_to_end(&mut self) {
+        while self.position < self.bandit.events().len() {
+            self.offer_event()
+        }
+    }
+
+    /// Returns vector of the inverse propensity weights of the matched events.
+    pub fn weights(&self) -> &Vec<f64> {
+        self.weights.record()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{
+        EpsilonGreedyAgent, GreedyAgent, HarmonicStepper, LoggedEvent, RatelError, ReplayBandit,
+        Ucb1Index, UcbAgent,
+    };
+
+    use super::ReplayGame;
+
+    /// A log of a uniformly random policy on Bernoulli arms with the given means.
+    fn uniform_log(means: &[f64], events: usize, seed: u64) -> ReplayBandit {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let events = (0..events)
+            .map(|_| {
+                let arm = rng.gen_range(0..means.len());
+                LoggedEvent {
+                    arm,
+                    reward: (rng.gen::<f64>() < means[arm]) as u32 as f64,
+                    propensity: None,
+                }
+            })
+            .collect();
+        ReplayBandit::new(means.len(), events)
+    }
+
+    #[test]
+    fn test_skipped() {
+        let events = vec![(1, 1.0), (0, 0.5), (0, 2.0), (1, 0.0)]
+            .into_iter()
+            .map(|(arm, reward)| LoggedEvent {
+                arm,
+                reward,
+                propensity: None,
+            })
+            .collect();
+        let agent = GreedyAgent::new(vec![1.0, 0.0], Box::new(HarmonicStepper::new(1, 2)));
+        let mut game = ReplayGame::with_seed(Box::new(agent), ReplayBandit::new(2, events), 0);
+        assert_eq!(game.policy_value(), None);
+        game.run(10);
+        assert_eq!(game.events(), 4);
+        assert_eq!(game.actions(), &vec![0, 0]);
+        assert_eq!(game.rewards(), &vec![0.5, 2.0]);
+        assert_eq!(game.policy_value(), Some(1.25));
+        assert_eq!(game.effective_sample_size(), 2.0);
+        game.reset(&[0.0, 1.0]);
+        game.run(1);
+        assert_eq!(game.matches(), 1);
+        assert_eq!(game.policy_value(), Some(1.0))
+    }
+
+    #[test]
+    fn test_propensity() {
+        let events = vec![(0, 1.0, 0.5), (0, 3.0, 0.25), (1, 5.0, 0.75)]
+            .into_iter()
+            .map(|(arm, reward, p)| LoggedEvent {
+                arm,
+                reward,
+                propensity: Some(p),
+            })
+            .collect();
+        let agent = GreedyAgent::new(vec![1.0, 0.0], Box::new(HarmonicStepper::new(1, 2)));
+        let mut game = ReplayGame::with_seed(Box::new(agent), ReplayBandit::new(2, events), 0);
+        game.run_to_end();
+        assert_eq!(game.weights(), &vec![2.0, 4.0]);
+        assert_eq!(game.policy_value(), Some((2.0 + 12.0) / 6.0));
+        assert_eq!(game.effective_sample_size(), 36.0 / 20.0)
+    }
+
+    #[test]
+    fn test_unbiased() {
+        let log = uniform_log(&[0.2, 0.7, 0.4], 30000, 1);
+        let agent =
+            EpsilonGreedyAgent::new(vec![0.0; 3], Box::new(HarmonicStepper::new(1, 3)), 0.99);
+        let mut game = ReplayGame::with_seed(Box::new(agent), log.clone(), 2);
+        game.run_to_end();
+        assert!((game.matches() as f64 - 10000.0).abs() < 300.0);
+        assert!((game.policy_value().unwrap() - 1.3 / 3.0).abs() < 0.02);
+        let agent = UcbAgent::new(vec![0.0; 3], Box::new(Ucb1Index::new()));
+        let mut game = ReplayGame::with_seed(Box::new(agent), log, 2);
+        game.run_to_end();
+        assert!(game.policy_value().unwrap() > 0.6)
+    }
+
+    #[test]
+    fn test_arm_mismatch() {
+        let agent = GreedyAgent::new(vec![0.0; 3], Box::new(HarmonicStepper::new(1, 3)));
+        assert!(matches!(
+            ReplayGame::try_new(Box::new(agent), uniform_log(&[0.5, 0.5], 10, 0)),
+            Err(RatelError::ArmMismatch {
+                agent: 3,
+                bandit: 2
+            })
+        ))
+    }
+}
@@ -0,0 +1,167 @@
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+/// The data of a single arm pull in a Game.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TraceStep {
+    /// Number of arm pulls so far, counting this one.
+    pub step: usize,
+    /// The arm chosen by the Agent.
+    pub arm: usize,
+    /// The reward from the arm.
+    pub reward: f64,
+    /// Whether the arm chosen was the best arm.
+    pub win: bool,
+    /// The Agent's estimates of every arm after updating on the reward.
+    pub estimates: Vec<f64>,
+}
+
+/// A trait for writers that stream the steps of a Game.
+pub trait TraceWriter {
+    /// Flushes any buffered steps to the underlying writer.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Writes a single step.
+    fn write_step(&mut self, step: &TraceStep) -> io::Result<()>;
+}
+
+/// Writes steps as CSV with a header row and one column per arm estimate.
+pub struct CsvTraceWriter<W: Write> {
+    /// Destination of the rows.
+    writer: W,
+    /// Whether the header row has been written.
+    header: bool,
+}
+
+impl<W: Write> CsvTraceWriter<W> {
+    /// Initializes a CSV writer. The header is written with the first step.
+    pub fn new(writer: W) -> CsvTraceWriter<W> {
+        CsvTraceWriter {
+            writer,
+            header: false,
+        }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> TraceWriter for CsvTraceWriter<W> {
+    /// Flushes the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Writes the step as a CSV row, preceded by the header row if this is the first step.
+    fn write_step(&mut self, step: &TraceStep) -> io::Result<()> {
+        if !self.header {
+            write!(self.writer, "step,arm,reward,win")?;
+            for arm in 0..step.estimates.len() {
+                write!(self.writer, ",estimate_{}", arm)?;
+            }
+            writeln!(self.writer)?;
+            self.header = true;
+        }
+        write!(
+            self.writer,
+            "{},{},{},{}",
+            step.step, step.arm, step.reward, step.win
+        )?;
+        for estimate in &step.estimates {
+            write!(self.writer, ",{}", estimate)?;
+        }
+        writeln!(self.writer)
+    }
+}
+
+/// Writes steps as JSON Lines, one JSON object per step.
+pub struct JsonLinesTraceWriter<W: Write> {
+    /// Destination of the lines.
+    writer: W,
+}
+
+impl<W: Write> JsonLinesTraceWriter<W> {
+    /// Initializes a JSON Lines writer.
+    pub fn new(writer: W) -> JsonLinesTraceWriter<W> {
+        JsonLinesTraceWriter { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> TraceWriter for JsonLinesTraceWriter<W> {
+    /// Flushes the underlying writer.
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+
+    /// Writes the step as a single line of JSON.
+    fn write_step(&mut self, step: &TraceStep) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, step)?;
+        writeln!(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, GaussianBandit, GreedyAgent, HarmonicStepper};
+
+    use super::{CsvTraceWriter, JsonLinesTraceWriter, TraceStep, TraceWriter};
+
+    fn step() -> TraceStep {
+        TraceStep {
+            step: 3,
+            arm: 1,
+            reward: 0.5,
+            win: false,
+            estimates: vec![0.25, 1.5],
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut writer = CsvTraceWriter::new(Vec::new());
+        writer.write_step(&step()).unwrap();
+        writer.write_step(&step()).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "step,arm,reward,win,estimate_0,estimate_1\n\
+             3,1,0.5,false,0.25,1.5\n\
+             3,1,0.5,false,0.25,1.5\n"
+        )
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let mut writer = JsonLinesTraceWriter::new(Vec::new());
+        writer.write_step(&step()).unwrap();
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "{\"step\":3,\"arm\":1,\"reward\":0.5,\"win\":false,\"estimates\":[0.25,1.5]}\n"
+        )
+    }
+
+    #[test]
+    fn test_run_traced() {
+        let stepper = HarmonicStepper::new(1, 2);
+        let agent = GreedyAgent::new(vec![0.0, 10.0], Box::new(stepper));
+        let bandit = GaussianBandit::new(vec![1.0, 2.0], vec![0.1, 0.1]);
+        let mut game = Game::with_seed(Box::new(agent), Box::new(bandit), 0);
+        let mut writer = CsvTraceWriter::new(Vec::new());
+        game.run_traced(4, &mut writer).unwrap();
+        let trace = String::from_utf8(writer.into_inner()).unwrap();
+        let lines: Vec<&str> = trace.lines().collect();
+        assert_eq!(lines.len(), 5);
+        let fields: Vec<&str> = lines[4].split(',').collect();
+        assert_eq!(fields[..2], ["4", "1"]);
+        assert_eq!(fields[2], game.rewards()[3].to_string());
+        assert_eq!(fields[3], "true");
+        assert_eq!(fields[4], "0")
+    }
+}
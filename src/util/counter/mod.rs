@@ -1,7 +1,9 @@
 pub use counter::Counter;
 pub use record_counter::RecordCounter;
 
+#[allow(dead_code)]
 mod aggregate_counter;
+#[allow(dead_code)]
 mod base_counter;
 mod counter;
 mod record_counter;
@@ -0,0 +1,143 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::ChangeDetector;
+
+/// The two-sided CUSUM test used by the CUSUM-UCB Algorithm of Liu, Lee and Shroff (2018).
+///
+/// Estimates an arm's mean from its first `warmup` rewards, then sums how far each later reward
+/// lies above and below that mean, less a tolerance `epsilon`. Either sum is floored at zero, and
+/// a change is detected once one exceeds `threshold`.
+pub struct CusumDetector {
+    /// Deviation from the mean tolerated before rewards count towards a change.
+    epsilon: f64,
+
+    /// Value of either sum that signals a change.
+    threshold: f64,
+
+    /// Number of rewards used to estimate each arm's mean.
+    warmup: u32,
+
+    /// Number of rewards from each arm since it was last reset.
+    counts: Vec<u32>,
+
+    /// Mean of each arm's warmup rewards, a running sum until the warmup is over.
+    means: Vec<f64>,
+
+    /// Sum of each arm's deviations above its mean.
+    highs: Vec<f64>,
+
+    /// Sum of each arm's deviations below its mean.
+    lows: Vec<f64>,
+}
+
+impl CusumDetector {
+    /// Initializes a CUSUM detector for the given number of arms. Panics if the parameters are
+    /// invalid; see `try_new`.
+    pub fn new(arms: usize, epsilon: f64, threshold: f64, warmup: u32) -> CusumDetector {
+        CusumDetector::try_new(arms, epsilon, threshold, warmup).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a CUSUM detector for the given number of arms, failing if there are no arms,
+    /// `epsilon` is negative, `threshold` is not positive or `warmup` is zero.
+    pub fn try_new(
+        arms: usize,
+        epsilon: f64,
+        threshold: f64,
+        warmup: u32,
+    ) -> Result<CusumDetector, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("epsilon", epsilon, "must be non-negative and finite", |e| {
+            e >= 0.0 && e.is_finite()
+        })?;
+        check("threshold", threshold, "must be positive and finite", |h| {
+            h > 0.0 && h.is_finite()
+        })?;
+        check("warmup", warmup, "must be positive", |m| m > 0.0)?;
+        Ok(CusumDetector {
+            epsilon,
+            threshold,
+            warmup,
+            counts: vec![0; arms],
+            means: vec![0.0; arms],
+            highs: vec![0.0; arms],
+            lows: vec![0.0; arms],
+        })
+    }
+}
+
+impl ChangeDetector for CusumDetector {
+    /// Returns the number of arms the detector watches.
+    fn arms(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Forgets the rewards of an arm and starts a new warmup.
+    fn reset(&mut self, arm: usize) {
+        self.counts[arm] = 0;
+        self.means[arm] = 0.0;
+        self.highs[arm] = 0.0;
+        self.lows[arm] = 0.0
+    }
+
+    /// Adds a reward to the warmup, or to the sums once the warmup is over.
+    fn update(&mut self, arm: usize, reward: f64) -> bool {
+        self.counts[arm] += 1;
+        if self.counts[arm] <= self.warmup {
+            self.means[arm] += reward;
+            if self.counts[arm] == self.warmup {
+                self.means[arm] /= f64::from(self.warmup)
+            }
+            return false;
+        }
+        let deviation = reward - self.means[arm];
+        self.highs[arm] = (self.highs[arm] + deviation - self.epsilon).max(0.0);
+        self.lows[arm] = (self.lows[arm] - deviation - self.epsilon).max(0.0);
+        self.highs[arm] > self.threshold || self.lows[arm] > self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChangeDetector, CusumDetector};
+
+    #[test]
+    fn test_update() {
+        let mut cusum = CusumDetector::new(2, 0.1, 1.0, 2);
+        assert!(!cusum.update(0, 0.4));
+        assert!(!cusum.update(0, 0.6));
+        assert_eq!(cusum.means[0], 0.5);
+        assert!(!cusum.update(0, 1.0));
+        assert_eq!(cusum.highs[0], 0.4);
+        assert!(!cusum.update(0, 1.0));
+        assert!(cusum.update(0, 1.0));
+        assert!(!cusum.update(1, 5.0))
+    }
+
+    #[test]
+    fn test_decrease() {
+        let mut cusum = CusumDetector::new(1, 0.0, 0.5, 1);
+        cusum.update(0, 1.0);
+        assert!(!cusum.update(0, 0.6));
+        assert!(cusum.update(0, 0.8))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut cusum = CusumDetector::new(1, 0.1, 1.0, 1);
+        cusum.update(0, 0.0);
+        cusum.update(0, 1.0);
+        cusum.reset(0);
+        assert!(!cusum.update(0, 1.0));
+        assert_eq!(cusum.means[0], 1.0);
+        assert_eq!(cusum.highs[0], 0.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_threshold() {
+        CusumDetector::new(2, 0.1, 0.0, 10);
+    }
+}
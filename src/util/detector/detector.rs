@@ -0,0 +1,11 @@
+/// Trait for the tests that watch each arm's rewards for an abrupt change in the arm's mean.
+pub trait ChangeDetector {
+    /// Returns the number of arms the detector watches.
+    fn arms(&self) -> usize;
+
+    /// Forgets the rewards of an arm, after a change is detected or when the Agent restarts.
+    fn reset(&mut self, arm: usize);
+
+    /// Adds a reward from an arm and returns whether a change in the arm's mean is detected.
+    fn update(&mut self, arm: usize, reward: f64) -> bool;
+}
@@ -0,0 +1,185 @@
+use std::collections::VecDeque;
+
+use crate::error::check;
+use crate::{Divergence, RatelError};
+
+use super::ChangeDetector;
+
+/// The Generalized Likelihood Ratio test of Besson, Kaufmann, Maillard and Seznec (2020).
+///
+/// After each of an arm's `n` rewards, tries every split of them into a first `s` and a last
+/// `n - s`, and detects a change if for some split
+/// `s d(mean of first, mean of all) + (n - s) d(mean of last, mean of all)` exceeds
+/// `ln(3 n^(3/2) / delta)`, where `d` is the divergence of the reward family. With a
+/// `BernoulliDivergence` this is the Bernoulli GLR test of GLR-klUCB.
+///
+/// Only the most recent `window` rewards of an arm are tested, so each update takes time and
+/// memory proportional to the window however long the arm is played. `new` uses a window of
+/// `DEFAULT_WINDOW` rewards.
+pub struct GlrDetector {
+    /// Divergence between reward distributions with given means.
+    divergence: Box<dyn Divergence>,
+
+    /// Probability of a false alarm the threshold is set for.
+    delta: f64,
+
+    /// Largest number of each arm's most recent rewards tested.
+    window: usize,
+
+    /// Running sums of each arm's rewards since its last reset, starting from zero, with at most
+    /// `window + 1` of the most recent kept.
+    sums: Vec<VecDeque<f64>>,
+}
+
+impl GlrDetector {
+    /// Number of rewards of each arm a detector built with `new` tests.
+    pub const DEFAULT_WINDOW: usize = 1000;
+
+    /// Initializes a GLR detector for the arms of the divergence. Panics if `delta` is invalid;
+    /// see `try_new`.
+    pub fn new(divergence: Box<dyn Divergence>, delta: f64) -> GlrDetector {
+        GlrDetector::try_new(divergence, delta).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a GLR detector for the arms of the divergence, failing if there are no arms or
+    /// `delta` is not in `(0, 1)`.
+    pub fn try_new(divergence: Box<dyn Divergence>, delta: f64) -> Result<GlrDetector, RatelError> {
+        GlrDetector::try_with_window(divergence, delta, GlrDetector::DEFAULT_WINDOW)
+    }
+
+    /// Initializes a GLR detector that tests only the most recent `window` rewards of each arm.
+    /// Panics if the parameters are invalid; see `try_with_window`.
+    pub fn with_window(divergence: Box<dyn Divergence>, delta: f64, window: usize) -> GlrDetector {
+        GlrDetector::try_with_window(divergence, delta, window).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a GLR detector that tests only the most recent `window` rewards of each arm,
+    /// failing if there are no arms, `delta` is not in `(0, 1)`, or the window holds fewer than
+    /// two rewards.
+    pub fn try_with_window(
+        divergence: Box<dyn Divergence>,
+        delta: f64,
+        window: usize,
+    ) -> Result<GlrDetector, RatelError> {
+        if divergence.arms() == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("delta", delta, "must be in (0, 1)", |d| d > 0.0 && d < 1.0)?;
+        check("window", window as f64, "must be at least 2", |w| w >= 2.0)?;
+        let arms = divergence.arms();
+        Ok(GlrDetector {
+            divergence,
+            delta,
+            window,
+            sums: vec![VecDeque::from(vec![0.0]); arms],
+        })
+    }
+
+    /// Returns the largest likelihood ratio statistic over every split of an arm's rewards in
+    /// the window.
+    fn statistic(&self, arm: usize) -> f64 {
+        let sums = &self.sums[arm];
+        let n = (sums.len() - 1) as f64;
+        let total = sums[sums.len() - 1] - sums[0];
+        let mean = total / n;
+        let d = |p: f64| self.divergence.divergence(arm, p, mean);
+        (1..sums.len() - 1)
+            .map(|s| {
+                let (first, last) = (s as f64, n - s as f64);
+                let head = sums[s] - sums[0];
+                first * d(head / first) + last * d((total - head) / last)
+            })
+            .fold(0.0, f64::max)
+    }
+}
+
+impl ChangeDetector for GlrDetector {
+    /// Returns the number of arms the detector watches.
+    fn arms(&self) -> usize {
+        self.sums.len()
+    }
+
+    /// Forgets the rewards of an arm.
+    fn reset(&mut self, arm: usize) {
+        self.sums[arm] = VecDeque::from(vec![0.0])
+    }
+
+    /// Adds a reward and compares the largest likelihood ratio with the threshold.
+    fn update(&mut self, arm: usize, reward: f64) -> bool {
+        let sums = &mut self.sums[arm];
+        let total = sums[sums.len() - 1];
+        sums.push_back(total + reward);
+        if sums.len() > self.window + 1 {
+            sums.pop_front();
+        }
+        let n = (self.sums[arm].len() - 1) as f64;
+        self.statistic(arm) > (3.0 * n.powf(1.5) / self.delta).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    use crate::{BernoulliDivergence, GaussianDivergence};
+
+    use super::{ChangeDetector, GlrDetector};
+
+    #[test]
+    fn test_statistic() {
+        let mut glr = GlrDetector::new(Box::new(GaussianDivergence::new(vec![1.0])), 0.1);
+        glr.update(0, 0.0);
+        glr.update(0, 0.0);
+        glr.update(0, 3.0);
+        assert_approx_eq!(glr.statistic(0), 2.0 * 0.5 + 0.5 * 4.0)
+    }
+
+    #[test]
+    fn test_update() {
+        let mut glr = GlrDetector::new(Box::new(BernoulliDivergence::new(vec![1; 2])), 0.05);
+        for _ in 0..50 {
+            assert!(!glr.update(0, 1.0))
+        }
+        let detected = (0..50).position(|_| glr.update(0, 0.0));
+        assert!(detected.is_some());
+        assert!(!glr.update(1, 0.0))
+    }
+
+    #[test]
+    fn test_long_stream() {
+        let mut glr =
+            GlrDetector::with_window(Box::new(BernoulliDivergence::new(vec![1])), 0.05, 100);
+        let mut rng = StdRng::seed_from_u64(3);
+        let alarms = (0..20_000)
+            .filter(|_| glr.update(0, f64::from(u8::from(rng.gen_bool(0.5)))))
+            .count();
+        assert!(alarms <= 2);
+        assert_eq!(glr.sums[0].len(), 101);
+        for _ in 0..100 {
+            glr.update(0, 1.0);
+        }
+        assert!((0..100).any(|_| glr.update(0, 0.0)))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut glr = GlrDetector::new(Box::new(BernoulliDivergence::new(vec![1])), 0.05);
+        glr.update(0, 1.0);
+        glr.reset(0);
+        assert_eq!(glr.sums[0], vec![0.0])
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_with_window_short() {
+        GlrDetector::with_window(Box::new(BernoulliDivergence::new(vec![1])), 0.05, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_delta() {
+        GlrDetector::new(Box::new(BernoulliDivergence::new(vec![1])), 1.0);
+    }
+}
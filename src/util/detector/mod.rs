@@ -0,0 +1,9 @@
+pub use cusum::CusumDetector;
+pub use detector::ChangeDetector;
+pub use glr::GlrDetector;
+pub use page_hinkley::PageHinkleyDetector;
+
+mod cusum;
+mod detector;
+mod glr;
+mod page_hinkley;
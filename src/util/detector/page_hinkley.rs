@@ -0,0 +1,145 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::ChangeDetector;
+
+/// The two-sided Page-Hinkley test.
+///
+/// Sums how far each of an arm's rewards lies above and below the running mean of its rewards,
+/// less a tolerance `delta`. A change is detected once either sum rises more than `threshold`
+/// above its smallest value so far.
+pub struct PageHinkleyDetector {
+    /// Deviation from the mean tolerated before rewards count towards a change.
+    delta: f64,
+
+    /// Rise of either sum above its minimum that signals a change.
+    threshold: f64,
+
+    /// Number of rewards from each arm since it was last reset.
+    counts: Vec<f64>,
+
+    /// Running mean of each arm's rewards.
+    means: Vec<f64>,
+
+    /// Sum of each arm's deviations above its running mean.
+    highs: Vec<f64>,
+
+    /// Smallest value of each arm's sum of deviations above its running mean.
+    high_minima: Vec<f64>,
+
+    /// Sum of each arm's deviations below its running mean.
+    lows: Vec<f64>,
+
+    /// Smallest value of each arm's sum of deviations below its running mean.
+    low_minima: Vec<f64>,
+}
+
+impl PageHinkleyDetector {
+    /// Initializes a Page-Hinkley detector for the given number of arms. Panics if the parameters
+    /// are invalid; see `try_new`.
+    pub fn new(arms: usize, delta: f64, threshold: f64) -> PageHinkleyDetector {
+        PageHinkleyDetector::try_new(arms, delta, threshold).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Page-Hinkley detector for the given number of arms, failing if there are no
+    /// arms, `delta` is negative or `threshold` is not positive.
+    pub fn try_new(
+        arms: usize,
+        delta: f64,
+        threshold: f64,
+    ) -> Result<PageHinkleyDetector, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        check("delta", delta, "must be non-negative and finite", |d| {
+            d >= 0.0 && d.is_finite()
+        })?;
+        check("threshold", threshold, "must be positive and finite", |h| {
+            h > 0.0 && h.is_finite()
+        })?;
+        Ok(PageHinkleyDetector {
+            delta,
+            threshold,
+            counts: vec![0.0; arms],
+            means: vec![0.0; arms],
+            highs: vec![0.0; arms],
+            high_minima: vec![0.0; arms],
+            lows: vec![0.0; arms],
+            low_minima: vec![0.0; arms],
+        })
+    }
+}
+
+impl ChangeDetector for PageHinkleyDetector {
+    /// Returns the number of arms the detector watches.
+    fn arms(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Forgets the rewards of an arm.
+    fn reset(&mut self, arm: usize) {
+        self.counts[arm] = 0.0;
+        self.means[arm] = 0.0;
+        self.highs[arm] = 0.0;
+        self.high_minima[arm] = 0.0;
+        self.lows[arm] = 0.0;
+        self.low_minima[arm] = 0.0
+    }
+
+    /// Updates the running mean and both sums with a reward.
+    fn update(&mut self, arm: usize, reward: f64) -> bool {
+        self.counts[arm] += 1.0;
+        self.means[arm] += (reward - self.means[arm]) / self.counts[arm];
+        let deviation = reward - self.means[arm];
+        self.highs[arm] += deviation - self.delta;
+        self.lows[arm] += -deviation - self.delta;
+        self.high_minima[arm] = self.high_minima[arm].min(self.highs[arm]);
+        self.low_minima[arm] = self.low_minima[arm].min(self.lows[arm]);
+        self.highs[arm] - self.high_minima[arm] > self.threshold
+            || self.lows[arm] - self.low_minima[arm] > self.threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{ChangeDetector, PageHinkleyDetector};
+
+    #[test]
+    fn test_update() {
+        let mut ph = PageHinkleyDetector::new(1, 0.0, 1.0);
+        for _ in 0..10 {
+            assert!(!ph.update(0, 0.0))
+        }
+        assert!(!ph.update(0, 1.0));
+        assert_approx_eq!(ph.highs[0], 10.0 / 11.0);
+        assert!(ph.update(0, 1.0))
+    }
+
+    #[test]
+    fn test_delta() {
+        let mut ph = PageHinkleyDetector::new(1, 0.5, 1.0);
+        for _ in 0..10 {
+            ph.update(0, 0.0);
+        }
+        assert!(!ph.update(0, 1.0));
+        assert!(!ph.update(0, 1.0));
+        assert!(ph.update(0, 1.0))
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ph = PageHinkleyDetector::new(2, 0.0, 1.0);
+        ph.update(1, 3.0);
+        ph.reset(1);
+        assert_eq!(ph.means[1], 0.0);
+        assert!(!ph.update(1, 100.0))
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_delta() {
+        PageHinkleyDetector::new(2, -0.1, 1.0);
+    }
+}
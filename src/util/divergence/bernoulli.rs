@@ -0,0 +1,94 @@
+use crate::error::check_each;
+use crate::RatelError;
+
+use super::divergence::bisect;
+use super::Divergence;
+
+/// Smallest distance kept between a success rate and 0 or 1, so the divergence stays finite.
+const EPSILON: f64 = 1e-15;
+
+/// Kullback-Leibler divergence between binomial rewards, Bernoulli when an arm has one trial.
+///
+/// The rewards of an arm with `n` trials lie in `[0, n]`, and the divergence between mean rewards
+/// `p` and `q` is `n` times the Bernoulli divergence between the success rates `p / n` and `q / n`.
+pub struct BernoulliDivergence {
+    /// Number of trials in a single pull of each arm.
+    trials: Vec<u32>,
+}
+
+impl BernoulliDivergence {
+    /// Initializes the divergence for arms with the given numbers of trials per pull. Panics if a
+    /// number of trials is zero.
+    pub fn new(trials: Vec<u32>) -> BernoulliDivergence {
+        BernoulliDivergence::try_new(trials).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the divergence, failing if there are no arms or a number of trials is zero.
+    pub fn try_new(trials: Vec<u32>) -> Result<BernoulliDivergence, RatelError> {
+        check_each("trials", &trials, "must be positive", |n| n > 0.0)?;
+        Ok(BernoulliDivergence { trials })
+    }
+}
+
+/// Returns the divergence between Bernoulli distributions with success rates `p` and `q`.
+fn bernoulli(p: f64, q: f64) -> f64 {
+    let p = p.clamp(EPSILON, 1.0 - EPSILON);
+    let q = q.clamp(EPSILON, 1.0 - EPSILON);
+    p * (p / q).ln() + (1.0 - p) * ((1.0 - p) / (1.0 - q)).ln()
+}
+
+impl Divergence for BernoulliDivergence {
+    /// Returns the number of arms the divergence is defined for.
+    fn arms(&self) -> usize {
+        self.trials.len()
+    }
+
+    /// Returns the divergence between the binomial rewards with means `p` and `q`.
+    fn divergence(&self, arm: usize, p: f64, q: f64) -> f64 {
+        let n = f64::from(self.trials[arm]);
+        n * bernoulli(p / n, q / n)
+    }
+
+    /// Returns the largest mean reward, at most the number of trials, within `level` of `p`.
+    fn upper_bound(&self, arm: usize, p: f64, level: f64) -> f64 {
+        let n = f64::from(self.trials[arm]);
+        let p = p.clamp(0.0, n);
+        bisect(|q| self.divergence(arm, p, q), p, n, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{BernoulliDivergence, Divergence};
+
+    #[test]
+    fn test_divergence() {
+        let kl = BernoulliDivergence::new(vec![1, 4]);
+        assert_approx_eq!(kl.divergence(0, 0.5, 0.5), 0.0);
+        assert_approx_eq!(
+            kl.divergence(0, 0.5, 0.25),
+            0.5 * 2f64.ln() + 0.5 * (2.0f64 / 3.0).ln()
+        );
+        assert_approx_eq!(
+            kl.divergence(1, 2.0, 1.0),
+            4.0 * kl.divergence(0, 0.5, 0.25)
+        )
+    }
+
+    #[test]
+    fn test_upper_bound() {
+        let kl = BernoulliDivergence::new(vec![1, 4]);
+        let q = kl.upper_bound(0, 0.3, 0.1);
+        assert!(q > 0.3 && q < 1.0);
+        assert_approx_eq!(kl.divergence(0, 0.3, q), 0.1);
+        assert_eq!(kl.upper_bound(1, 4.0, 0.1), 4.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_zero_trials() {
+        BernoulliDivergence::new(vec![1, 0]);
+    }
+}
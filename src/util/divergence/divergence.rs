@@ -0,0 +1,66 @@
+/// Number of halvings used to invert a divergence, enough to reach the precision of an `f64`.
+const BISECTIONS: u32 = 60;
+
+/// Largest number of doublings tried when searching for an upper limit of an unbounded mean.
+const DOUBLINGS: u32 = 1000;
+
+/// Trait containing all methods required by the Kullback-Leibler divergences of reward families.
+pub trait Divergence {
+    /// Returns the number of arms the divergence is defined for.
+    fn arms(&self) -> usize;
+
+    /// Returns the divergence between the given arm's reward distributions with means `p` and `q`.
+    fn divergence(&self, arm: usize, p: f64, q: f64) -> f64;
+
+    /// Returns the largest mean `q >= p` whose divergence from `p` is at most `level`.
+    fn upper_bound(&self, arm: usize, p: f64, level: f64) -> f64;
+}
+
+/// Returns the largest `q` in `[low, high]` with `d(q) <= level` for a `d` increasing on the
+/// interval, found by bisection.
+pub(crate) fn bisect<F: Fn(f64) -> f64>(d: F, low: f64, high: f64, level: f64) -> f64 {
+    if d(high) <= level {
+        return high;
+    }
+    let (mut low, mut high) = (low, high);
+    for _ in 0..BISECTIONS {
+        let mid = (low + high) / 2.0;
+        if d(mid) <= level {
+            low = mid
+        } else {
+            high = mid
+        }
+    }
+    low
+}
+
+/// Returns the largest `q >= low` with `d(q) <= level` for a `d` increasing above `low` without
+/// bound, doubling an upper limit until it is passed and then bisecting.
+pub(crate) fn bisect_unbounded<F: Fn(f64) -> f64>(d: F, low: f64, level: f64) -> f64 {
+    let mut high = 2.0 * low.max(1.0);
+    for _ in 0..DOUBLINGS {
+        if d(high) > level {
+            break;
+        }
+        high *= 2.0
+    }
+    bisect(d, low, high, level)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{bisect, bisect_unbounded};
+
+    #[test]
+    fn test_bisect() {
+        assert_approx_eq!(bisect(|q| q * q, 0.0, 1.0, 0.25), 0.5);
+        assert_eq!(bisect(|q| q * q, 0.0, 1.0, 2.0), 1.0)
+    }
+
+    #[test]
+    fn test_bisect_unbounded() {
+        assert_approx_eq!(bisect_unbounded(|q| q * q, 0.0, 100.0), 10.0)
+    }
+}
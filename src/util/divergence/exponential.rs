@@ -0,0 +1,61 @@
+use super::divergence::bisect_unbounded;
+use super::Divergence;
+
+/// Smallest mean reward used, so the divergence stays finite.
+const EPSILON: f64 = 1e-15;
+
+/// Kullback-Leibler divergence between exponential rewards, parameterized by their means.
+///
+/// The divergence between means `p` and `q` is `p / q - 1 - ln(p / q)`.
+pub struct ExponentialDivergence {
+    /// Number of arms.
+    arms: usize,
+}
+
+impl ExponentialDivergence {
+    /// Initializes the divergence for the given number of arms.
+    pub fn new(arms: usize) -> ExponentialDivergence {
+        ExponentialDivergence { arms }
+    }
+}
+
+impl Divergence for ExponentialDivergence {
+    /// Returns the number of arms the divergence is defined for.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    /// Returns the divergence between the exponential rewards with means `p` and `q`.
+    fn divergence(&self, _arm: usize, p: f64, q: f64) -> f64 {
+        let r = p.max(EPSILON) / q.max(EPSILON);
+        r - 1.0 - r.ln()
+    }
+
+    /// Returns the largest mean within `level` of `p`.
+    fn upper_bound(&self, arm: usize, p: f64, level: f64) -> f64 {
+        let p = p.max(EPSILON);
+        bisect_unbounded(|q| self.divergence(arm, p, q), p, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{Divergence, ExponentialDivergence};
+
+    #[test]
+    fn test_divergence() {
+        let kl = ExponentialDivergence::new(1);
+        assert_approx_eq!(kl.divergence(0, 2.0, 2.0), 0.0);
+        assert_approx_eq!(kl.divergence(0, 1.0, 2.0), 0.5 - 1.0 - 0.5f64.ln())
+    }
+
+    #[test]
+    fn test_upper_bound() {
+        let kl = ExponentialDivergence::new(1);
+        let q = kl.upper_bound(0, 0.5, 0.1);
+        assert!(q > 0.5);
+        assert_approx_eq!(kl.divergence(0, 0.5, q), 0.1)
+    }
+}
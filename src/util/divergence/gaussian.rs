@@ -0,0 +1,68 @@
+use crate::error::check_each;
+use crate::RatelError;
+
+use super::Divergence;
+
+/// Kullback-Leibler divergence between Gaussian rewards with known standard deviations.
+///
+/// The divergence between means `p` and `q` is `(p - q)^2 / (2 σ^2)`, so its upper bound has the
+/// closed form `p + σ sqrt(2 level)`.
+pub struct GaussianDivergence {
+    /// Standard deviations of the arms.
+    stds: Vec<f64>,
+}
+
+impl GaussianDivergence {
+    /// Initializes the divergence for arms with the given standard deviations. Panics if a
+    /// standard deviation is not positive.
+    pub fn new(stds: Vec<f64>) -> GaussianDivergence {
+        GaussianDivergence::try_new(stds).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the divergence, failing if there are no arms or a standard deviation is not
+    /// positive.
+    pub fn try_new(stds: Vec<f64>) -> Result<GaussianDivergence, RatelError> {
+        check_each("stds", &stds, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        Ok(GaussianDivergence { stds })
+    }
+}
+
+impl Divergence for GaussianDivergence {
+    /// Returns the number of arms the divergence is defined for.
+    fn arms(&self) -> usize {
+        self.stds.len()
+    }
+
+    /// Returns the divergence between the Gaussian rewards with means `p` and `q`.
+    fn divergence(&self, arm: usize, p: f64, q: f64) -> f64 {
+        (p - q).powi(2) / (2.0 * self.stds[arm].powi(2))
+    }
+
+    /// Returns the largest mean within `level` of `p`.
+    fn upper_bound(&self, arm: usize, p: f64, level: f64) -> f64 {
+        p + self.stds[arm] * (2.0 * level).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{Divergence, GaussianDivergence};
+
+    #[test]
+    fn test_divergence() {
+        let kl = GaussianDivergence::new(vec![1.0, 2.0]);
+        assert_approx_eq!(kl.divergence(0, 1.0, 3.0), 2.0);
+        assert_approx_eq!(kl.divergence(1, 1.0, 3.0), 0.5)
+    }
+
+    #[test]
+    fn test_upper_bound() {
+        let kl = GaussianDivergence::new(vec![1.0, 2.0]);
+        assert_approx_eq!(kl.upper_bound(1, 1.0, 0.5), 3.0);
+        assert_approx_eq!(kl.divergence(1, 1.0, kl.upper_bound(1, 1.0, 0.3)), 0.3)
+    }
+}
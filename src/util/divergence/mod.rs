@@ -0,0 +1,11 @@
+pub use bernoulli::BernoulliDivergence;
+pub use divergence::Divergence;
+pub use exponential::ExponentialDivergence;
+pub use gaussian::GaussianDivergence;
+pub use poisson::PoissonDivergence;
+
+mod bernoulli;
+mod divergence;
+mod exponential;
+mod gaussian;
+mod poisson;
@@ -0,0 +1,64 @@
+use super::divergence::bisect_unbounded;
+use super::Divergence;
+
+/// Kullback-Leibler divergence between Poisson rewards.
+///
+/// The divergence between means `p` and `q` is `q - p + p ln(p / q)`.
+pub struct PoissonDivergence {
+    /// Number of arms.
+    arms: usize,
+}
+
+impl PoissonDivergence {
+    /// Initializes the divergence for the given number of arms.
+    pub fn new(arms: usize) -> PoissonDivergence {
+        PoissonDivergence { arms }
+    }
+}
+
+impl Divergence for PoissonDivergence {
+    /// Returns the number of arms the divergence is defined for.
+    fn arms(&self) -> usize {
+        self.arms
+    }
+
+    /// Returns the divergence between the Poisson rewards with means `p` and `q`.
+    fn divergence(&self, _arm: usize, p: f64, q: f64) -> f64 {
+        let p = p.max(0.0);
+        if p == 0.0 {
+            q
+        } else {
+            q - p + p * (p / q).ln()
+        }
+    }
+
+    /// Returns the largest mean within `level` of `p`.
+    fn upper_bound(&self, arm: usize, p: f64, level: f64) -> f64 {
+        let p = p.max(0.0);
+        bisect_unbounded(|q| self.divergence(arm, p, q), p, level)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{Divergence, PoissonDivergence};
+
+    #[test]
+    fn test_divergence() {
+        let kl = PoissonDivergence::new(2);
+        assert_approx_eq!(kl.divergence(0, 2.0, 2.0), 0.0);
+        assert_approx_eq!(kl.divergence(0, 2.0, 1.0), 2f64.ln() * 2.0 - 1.0);
+        assert_approx_eq!(kl.divergence(1, 0.0, 1.5), 1.5)
+    }
+
+    #[test]
+    fn test_upper_bound() {
+        let kl = PoissonDivergence::new(2);
+        let q = kl.upper_bound(0, 3.0, 0.2);
+        assert!(q > 3.0);
+        assert_approx_eq!(kl.divergence(0, 3.0, q), 0.2);
+        assert_approx_eq!(kl.upper_bound(1, 0.0, 0.5), 0.5)
+    }
+}
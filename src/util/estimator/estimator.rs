@@ -0,0 +1,31 @@
+/// Work an estimator caches between estimates from a growing sequence of rewards.
+#[derive(Clone, Debug, Default)]
+pub struct EstimatorState {
+    /// The number of rewards included in the sums.
+    pub(crate) count: usize,
+
+    /// Running sums of the rewards, one per block.
+    pub(crate) sums: Vec<f64>,
+}
+
+/// Trait for the robust estimators of an arm's mean used by Agents playing heavy-tailed Bandits.
+///
+/// Each estimator assumes a bound on a moment of order `1 + epsilon` of the rewards, for some
+/// `epsilon` in `(0, 1]`, rather than a bounded variance, and comes with a confidence width that
+/// holds with probability at least `1 - delta` under that assumption.
+pub trait RobustEstimator {
+    /// Returns the estimate of the mean of the given rewards, in the order they were received, at
+    /// confidence level `delta`, or zero if there are no rewards.
+    fn estimate(&self, rewards: &[f64], delta: f64) -> f64;
+
+    /// Returns the same estimate as `estimate`, reusing the work cached in `state` by earlier
+    /// calls. The state must follow a single sequence of rewards that only grows, and be replaced
+    /// by a fresh one when the rewards are cleared. By default nothing is cached.
+    fn estimate_with(&self, rewards: &[f64], delta: f64, _state: &mut EstimatorState) -> f64 {
+        self.estimate(rewards, delta)
+    }
+
+    /// Returns the width of the confidence interval around an estimate from `count` rewards at
+    /// confidence level `delta`.
+    fn width(&self, count: usize, delta: f64) -> f64;
+}
@@ -0,0 +1,151 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::{EstimatorState, RobustEstimator};
+
+/// The median-of-means estimator of Bubeck, Cesa-Bianchi, and Lugosi (2013).
+///
+/// Deals the rewards in turn into `k = floor(min(8 ln(e^(1/8) / delta), n / 2))` blocks of equal
+/// size, dropping the remainder, and returns the median of the block means. Since the blocks are
+/// interleaved, their sums can be kept up to date as rewards arrive until `k` changes. The width assumes `v`
+/// bounds the centered moment `E|X - mu|^(1 + epsilon)` of the rewards.
+#[derive(Clone, Debug)]
+pub struct MedianOfMeansEstimator {
+    /// Order of the bounded moment, less one.
+    epsilon: f64,
+
+    /// Bound on the centered moment of the rewards.
+    v: f64,
+}
+
+impl MedianOfMeansEstimator {
+    /// Initializes the median of means. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(epsilon: f64, v: f64) -> MedianOfMeansEstimator {
+        MedianOfMeansEstimator::try_new(epsilon, v).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the median of means, failing if `epsilon` is not in `(0, 1]` or if the moment
+    /// bound `v` is not positive.
+    pub fn try_new(epsilon: f64, v: f64) -> Result<MedianOfMeansEstimator, RatelError> {
+        check("epsilon", epsilon, "must be in (0, 1]", |e| {
+            e > 0.0 && e <= 1.0
+        })?;
+        check("v", v, "must be positive and finite", |v| {
+            v > 0.0 && v.is_finite()
+        })?;
+        Ok(MedianOfMeansEstimator { epsilon, v })
+    }
+
+    /// Returns the number of blocks `k` for `count` rewards at confidence level `delta`.
+    fn blocks(&self, count: usize, delta: f64) -> usize {
+        ((8.0 * log_term(delta)).min(count as f64 / 2.0).floor() as usize).max(1)
+    }
+}
+
+/// Returns `ln(e^(1/8) / delta)`, the confidence term of the median of means.
+fn log_term(delta: f64) -> f64 {
+    0.125 - delta.ln()
+}
+
+impl RobustEstimator for MedianOfMeansEstimator {
+    /// Returns the median of the block means of the rewards.
+    fn estimate(&self, rewards: &[f64], delta: f64) -> f64 {
+        self.estimate_with(rewards, delta, &mut EstimatorState::default())
+    }
+
+    /// Returns the median of the block means, adding only the new rewards to the cached block
+    /// sums, and rebuilding them when the number of blocks changes.
+    fn estimate_with(&self, rewards: &[f64], delta: f64, state: &mut EstimatorState) -> f64 {
+        let n = rewards.len();
+        if n == 0 {
+            return 0.0;
+        }
+        let blocks = self.blocks(n, delta);
+        if state.sums.len() != blocks || state.count > n {
+            *state = EstimatorState {
+                count: 0,
+                sums: vec![0.0; blocks],
+            };
+        }
+        let size = n / blocks;
+        for (i, reward) in rewards
+            .iter()
+            .enumerate()
+            .take(size * blocks)
+            .skip(state.count)
+        {
+            state.sums[i % blocks] += reward;
+        }
+        state.count = size * blocks;
+        let mut means = state
+            .sums
+            .iter()
+            .map(|sum| sum / size as f64)
+            .collect::<Vec<f64>>();
+        means.sort_by(f64::total_cmp);
+        let mid = means.len() / 2;
+        if means.len() % 2 == 0 {
+            (means[mid - 1] + means[mid]) / 2.0
+        } else {
+            means[mid]
+        }
+    }
+
+    /// Returns the width
+    /// `(12 v)^(1 / (1 + epsilon)) (16 ln(e^(1/8) / delta) / count)^(epsilon / (1 + epsilon))`.
+    fn width(&self, count: usize, delta: f64) -> f64 {
+        let power = 1.0 / (1.0 + self.epsilon);
+        (12.0 * self.v).powf(power)
+            * (16.0 * log_term(delta) / count as f64).powf(self.epsilon * power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{EstimatorState, MedianOfMeansEstimator, RobustEstimator};
+
+    #[test]
+    fn test_estimate() {
+        let estimator = MedianOfMeansEstimator::new(1.0, 1.0);
+        let rewards = [1.0, 3.0, 2.0, 2.0, 100.0, 0.0, 5.0];
+        // Three blocks of two, dealt in turn, with means 1.5, 51.5 and 1, and the last reward
+        // dropped.
+        assert_eq!(estimator.estimate(&rewards, 0.5), 1.5);
+        assert_eq!(estimator.estimate(&rewards[..4], 0.5), 2.0);
+        assert_eq!(estimator.estimate(&[4.0], 0.5), 4.0);
+        assert_eq!(estimator.estimate(&[], 0.5), 0.0);
+        // A NaN block mean sorts above every other mean rather than panicking.
+        assert_eq!(
+            estimator.estimate(&[f64::NAN, 1.0, 2.0, 2.0, 0.0, 0.0], 0.5),
+            1.0
+        );
+        let log = 0.125 + 2f64.ln();
+        assert_approx_eq!(estimator.width(16, 0.5), 12f64.sqrt() * log.sqrt())
+    }
+
+    #[test]
+    fn test_estimate_with() {
+        let estimator = MedianOfMeansEstimator::new(1.0, 1.0);
+        let rewards = (0..200)
+            .map(|i| ((i * 37) % 11) as f64)
+            .collect::<Vec<f64>>();
+        let mut state = EstimatorState::default();
+        for n in 1..=rewards.len() {
+            let delta = ((n + 1) as f64).powi(-2);
+            assert_approx_eq!(
+                estimator.estimate_with(&rewards[..n], delta, &mut state),
+                estimator.estimate(&rewards[..n], delta)
+            );
+        }
+        assert_eq!(estimator.estimate_with(&[], 0.5, &mut state), 0.0);
+        assert_eq!(estimator.estimate_with(&[4.0], 0.5, &mut state), 4.0)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_v() {
+        MedianOfMeansEstimator::new(1.0, 0.0);
+    }
+}
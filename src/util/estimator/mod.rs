@@ -0,0 +1,7 @@
+pub use estimator::{EstimatorState, RobustEstimator};
+pub use median_of_means::MedianOfMeansEstimator;
+pub use truncated_mean::TruncatedMeanEstimator;
+
+mod estimator;
+mod median_of_means;
+mod truncated_mean;
@@ -0,0 +1,84 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::RobustEstimator;
+
+/// The truncated empirical mean of Bubeck, Cesa-Bianchi, and Lugosi (2013).
+///
+/// Drops the `i`-th reward when its absolute value exceeds
+/// `(u i / ln(1 / delta))^(1 / (1 + epsilon))` and divides the sum of the rest by the number of
+/// rewards, where `u` bounds the raw moment `E|X|^(1 + epsilon)` of the rewards.
+#[derive(Clone, Debug)]
+pub struct TruncatedMeanEstimator {
+    /// Order of the bounded moment, less one.
+    epsilon: f64,
+
+    /// Bound on the raw moment of the rewards.
+    u: f64,
+}
+
+impl TruncatedMeanEstimator {
+    /// Initializes the truncated mean. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(epsilon: f64, u: f64) -> TruncatedMeanEstimator {
+        TruncatedMeanEstimator::try_new(epsilon, u).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the truncated mean, failing if `epsilon` is not in `(0, 1]` or if the moment
+    /// bound `u` is not positive.
+    pub fn try_new(epsilon: f64, u: f64) -> Result<TruncatedMeanEstimator, RatelError> {
+        check("epsilon", epsilon, "must be in (0, 1]", |e| {
+            e > 0.0 && e <= 1.0
+        })?;
+        check("u", u, "must be positive and finite", |u| {
+            u > 0.0 && u.is_finite()
+        })?;
+        Ok(TruncatedMeanEstimator { epsilon, u })
+    }
+}
+
+impl RobustEstimator for TruncatedMeanEstimator {
+    /// Returns the mean of the rewards, counting those above their truncation level as zero.
+    fn estimate(&self, rewards: &[f64], delta: f64) -> f64 {
+        if rewards.is_empty() {
+            return 0.0;
+        }
+        let log = (1.0 / delta).ln();
+        let power = 1.0 / (1.0 + self.epsilon);
+        rewards
+            .iter()
+            .enumerate()
+            .filter(|(i, x)| x.abs() <= (self.u * (i + 1) as f64 / log).powf(power))
+            .map(|(_, x)| x)
+            .sum::<f64>()
+            / rewards.len() as f64
+    }
+
+    /// Returns `4 u^(1 / (1 + epsilon)) (ln(1 / delta) / count)^(epsilon / (1 + epsilon))`.
+    fn width(&self, count: usize, delta: f64) -> f64 {
+        let power = 1.0 / (1.0 + self.epsilon);
+        4.0 * self.u.powf(power) * ((1.0 / delta).ln() / count as f64).powf(self.epsilon * power)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{RobustEstimator, TruncatedMeanEstimator};
+
+    #[test]
+    fn test_estimate() {
+        let estimator = TruncatedMeanEstimator::new(1.0, 2.0);
+        let delta = (-1f64).exp();
+        // Truncation levels are sqrt(2 i): 1.41, 2, 2.45, 2.83.
+        assert_approx_eq!(estimator.estimate(&[1.0, -3.0, 2.0, 100.0], delta), 0.75);
+        assert_eq!(estimator.estimate(&[], delta), 0.0);
+        assert_approx_eq!(estimator.width(4, delta), 4.0 * 2f64.sqrt() * 0.5)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_epsilon() {
+        TruncatedMeanEstimator::new(1.5, 1.0);
+    }
+}
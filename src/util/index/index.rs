@@ -0,0 +1,9 @@
+/// Trait for the rules that turn an arm's reward statistics into an upper confidence bound.
+pub trait UcbIndex {
+    /// Returns the upper confidence bound of an arm's average reward.
+    ///
+    /// `mean` and `variance` are the sample mean and (biased) sample variance of the arm's
+    /// rewards, `pulls` the number of times the arm has been pulled, `total` the number of pulls of
+    /// all arms, and `arms` the number of arms. Only called once every arm has been pulled.
+    fn index(&self, mean: f64, variance: f64, pulls: f64, total: f64, arms: usize) -> f64;
+}
@@ -0,0 +1,11 @@
+pub use index::UcbIndex;
+pub use moss::MossIndex;
+pub use ucb1::Ucb1Index;
+pub use ucb1_tuned::Ucb1TunedIndex;
+pub use ucb_v::UcbVIndex;
+
+mod index;
+mod moss;
+mod ucb1;
+mod ucb1_tuned;
+mod ucb_v;
@@ -0,0 +1,55 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::UcbIndex;
+
+/// The MOSS index of Audibert and Bubeck (2009).
+///
+/// Adds `sqrt(max(ln(T / (K n)), 0) / n)` to the sample mean of an arm pulled `n` times, where `T`
+/// is the number of rounds that will be played and `K` the number of arms.
+#[derive(Clone, Debug)]
+pub struct MossIndex {
+    /// Total number of rounds in a game.
+    horizon: f64,
+}
+
+impl MossIndex {
+    /// Initializes the MOSS index for games of the given number of rounds. Panics if the horizon
+    /// is zero.
+    pub fn new(horizon: u32) -> MossIndex {
+        MossIndex::try_new(horizon).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the MOSS index, failing if the horizon is zero.
+    pub fn try_new(horizon: u32) -> Result<MossIndex, RatelError> {
+        check("horizon", horizon, "must be positive", |h| h > 0.0)?;
+        Ok(MossIndex {
+            horizon: f64::from(horizon),
+        })
+    }
+}
+
+impl UcbIndex for MossIndex {
+    /// Returns the sample mean plus the MOSS exploration bonus.
+    fn index(&self, mean: f64, _variance: f64, pulls: f64, _total: f64, arms: usize) -> f64 {
+        let log = (self.horizon / (arms as f64 * pulls)).ln().max(0.0);
+        mean + (log / pulls).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{MossIndex, UcbIndex};
+
+    #[test]
+    fn test_index() {
+        let index = MossIndex::new(1000);
+        assert_approx_eq!(
+            index.index(0.5, 0.1, 10.0, 40.0, 4),
+            0.5 + (25f64.ln() / 10.0).sqrt()
+        );
+        assert_approx_eq!(index.index(0.5, 0.1, 300.0, 900.0, 4), 0.5)
+    }
+}
@@ -0,0 +1,34 @@
+use super::UcbIndex;
+
+/// The UCB1 index of Auer, Cesa-Bianchi, and Fischer (2002).
+///
+/// Adds `sqrt(2 ln(t) / n)` to the sample mean of an arm pulled `n` times in `t` rounds.
+#[derive(Clone, Debug, Default)]
+pub struct Ucb1Index;
+
+impl Ucb1Index {
+    /// Initializes the UCB1 index.
+    pub fn new() -> Ucb1Index {
+        Ucb1Index
+    }
+}
+
+impl UcbIndex for Ucb1Index {
+    /// Returns the sample mean plus the UCB1 exploration bonus.
+    fn index(&self, mean: f64, _variance: f64, pulls: f64, total: f64, _arms: usize) -> f64 {
+        mean + (2.0 * total.ln() / pulls).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{Ucb1Index, UcbIndex};
+
+    #[test]
+    fn test_index() {
+        let index = Ucb1Index::new();
+        assert_approx_eq!(index.index(0.5, 0.0, 2.0, 10.0, 3), 0.5 + 10f64.ln().sqrt())
+    }
+}
@@ -0,0 +1,46 @@
+use super::UcbIndex;
+
+/// The UCB1-Tuned index of Auer, Cesa-Bianchi, and Fischer (2002).
+///
+/// Replaces the constant in the UCB1 bonus with an upper bound on the arm's variance, capped at
+/// 1/4, the largest variance of a reward in `[0, 1]`.
+#[derive(Clone, Debug, Default)]
+pub struct Ucb1TunedIndex;
+
+impl Ucb1TunedIndex {
+    /// Initializes the UCB1-Tuned index.
+    pub fn new() -> Ucb1TunedIndex {
+        Ucb1TunedIndex
+    }
+}
+
+impl UcbIndex for Ucb1TunedIndex {
+    /// Returns the sample mean plus the UCB1-Tuned exploration bonus.
+    fn index(&self, mean: f64, variance: f64, pulls: f64, total: f64, _arms: usize) -> f64 {
+        let log = total.ln() / pulls;
+        let v = variance + (2.0 * log).sqrt();
+        mean + (log * v.min(0.25)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{Ucb1TunedIndex, UcbIndex};
+
+    #[test]
+    fn test_index() {
+        let index = Ucb1TunedIndex::new();
+        let log = 100f64.ln() / 50.0;
+        assert_approx_eq!(
+            index.index(0.5, 0.0, 50.0, 100.0, 3),
+            0.5 + (log * 0.25).sqrt()
+        );
+        let log = 1000f64.ln() / 1000.0;
+        assert_approx_eq!(
+            index.index(0.5, 0.01, 1000.0, 1000.0, 3),
+            0.5 + (log * (0.01 + (2.0 * log).sqrt())).sqrt()
+        )
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::UcbIndex;
+
+/// Exploration rate of the UCB-V index, the value used by Audibert, Munos, and Szepesvári.
+const ZETA: f64 = 1.2;
+
+/// The variance-aware UCB-V index of Audibert, Munos, and Szepesvári (2009).
+///
+/// Adds `sqrt(2 V ζ ln(t) / n) + 3 b ζ ln(t) / n` to the sample mean of an arm with sample
+/// variance `V` pulled `n` times in `t` rounds, where `b` is the width of the reward range and
+/// `ζ = 1.2`.
+#[derive(Clone, Debug)]
+pub struct UcbVIndex {
+    /// Width of the range the rewards lie in.
+    range: f64,
+}
+
+impl UcbVIndex {
+    /// Initializes the UCB-V index for rewards in a range of the given width. Panics if the width
+    /// is not positive.
+    pub fn new(range: f64) -> UcbVIndex {
+        UcbVIndex::try_new(range).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the UCB-V index, failing if the width of the reward range is not positive.
+    pub fn try_new(range: f64) -> Result<UcbVIndex, RatelError> {
+        check("range", range, "must be positive and finite", |r| {
+            r > 0.0 && r.is_finite()
+        })?;
+        Ok(UcbVIndex { range })
+    }
+}
+
+impl UcbIndex for UcbVIndex {
+    /// Returns the sample mean plus the UCB-V exploration bonus.
+    fn index(&self, mean: f64, variance: f64, pulls: f64, total: f64, _arms: usize) -> f64 {
+        let log = ZETA * total.ln() / pulls;
+        mean + (2.0 * variance * log).sqrt() + 3.0 * self.range * log
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{UcbIndex, UcbVIndex};
+
+    #[test]
+    fn test_index() {
+        let index = UcbVIndex::new(2.0);
+        let log = 1.2 * 20f64.ln() / 4.0;
+        assert_approx_eq!(
+            index.index(1.0, 0.5, 4.0, 20.0, 5),
+            1.0 + log.sqrt() + 6.0 * log
+        )
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_range() {
+        UcbVIndex::new(0.0);
+    }
+}
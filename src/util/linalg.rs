@@ -0,0 +1,62 @@
+//! Small dense linear algebra for the contextual Agents, on square matrices stored as rows.
+
+/// Returns the dot product of two vectors of the same length.
+pub(crate) fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Returns the given multiple of the identity matrix.
+pub(crate) fn identity(n: usize, scale: f64) -> Vec<Vec<f64>> {
+    (0..n)
+        .map(|i| (0..n).map(|j| if i == j { scale } else { 0.0 }).collect())
+        .collect()
+}
+
+/// Returns the product of a matrix and a vector.
+pub(crate) fn mat_vec(m: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    m.iter().map(|row| dot(row, v)).collect()
+}
+
+/// Returns the quadratic form `v^T m v`.
+pub(crate) fn quadratic_form(m: &[Vec<f64>], v: &[f64]) -> f64 {
+    dot(v, &mat_vec(m, v))
+}
+
+/// Replaces the inverse of a symmetric matrix `A` with the inverse of `A + x x^T`, by the
+/// Sherman-Morrison formula.
+pub(crate) fn rank_one_update(inverse: &mut [Vec<f64>], x: &[f64]) {
+    let u = mat_vec(inverse, x);
+    let denominator = 1.0 + dot(x, &u);
+    for (row, ui) in inverse.iter_mut().zip(&u) {
+        for (m, uj) in row.iter_mut().zip(&u) {
+            *m -= ui * uj / denominator
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{dot, identity, mat_vec, quadratic_form, rank_one_update};
+
+    #[test]
+    fn test_mat_vec() {
+        let m = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+        assert_eq!(mat_vec(&m, &[1.0, -1.0]), vec![-1.0, -1.0]);
+        assert_eq!(quadratic_form(&m, &[1.0, 1.0]), 10.0);
+        assert_eq!(dot(&[1.0, 2.0], &[3.0, 4.0]), 11.0)
+    }
+
+    #[test]
+    fn test_rank_one_update() {
+        let mut inverse = identity(2, 0.5);
+        rank_one_update(&mut inverse, &[1.0, 2.0]);
+        let expected = [[6.0, -2.0], [-2.0, 3.0]];
+        for (row, expected) in inverse.iter().zip(&expected) {
+            for (m, e) in row.iter().zip(expected) {
+                assert_approx_eq!(m, e / 14.0)
+            }
+        }
+    }
+}
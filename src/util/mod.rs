@@ -2,10 +2,35 @@
 
 pub use arg_bounds::ArgBounds;
 pub use counter::{Counter, RecordCounter};
-pub use stepper::{ConstantStepper, HarmonicStepper, Stepper};
+pub use detector::{ChangeDetector, CusumDetector, GlrDetector, PageHinkleyDetector};
+pub use divergence::{
+    BernoulliDivergence, Divergence, ExponentialDivergence, GaussianDivergence, PoissonDivergence,
+};
+pub use estimator::{
+    EstimatorState, MedianOfMeansEstimator, RobustEstimator, TruncatedMeanEstimator,
+};
+pub use index::{MossIndex, Ucb1Index, Ucb1TunedIndex, UcbIndex, UcbVIndex};
+pub use posterior::{BetaPosterior, NormalGammaPosterior, Posterior};
+pub use reward_scale::RewardScale;
+pub use schedule::{
+    ConstantSchedule, ExponentialSchedule, InverseSchedule, LinearSchedule, LogarithmicSchedule,
+    Schedule,
+};
+pub use stepper::{
+    ConstantStepper, ConstantStepperBuilder, HarmonicStepper, HarmonicStepperBuilder, Stepper,
+};
 pub use timer::print_hms;
 
 mod arg_bounds;
 mod counter;
+mod detector;
+mod divergence;
+mod estimator;
+mod index;
+pub(crate) mod linalg;
+mod posterior;
+mod reward_scale;
+mod schedule;
+pub(crate) mod special;
 mod stepper;
 mod timer;
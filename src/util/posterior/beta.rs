@@ -0,0 +1,162 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::Beta;
+
+use crate::error::{check, check_each};
+use crate::RatelError;
+
+use super::Posterior;
+
+/// Beta posterior for the success probabilities of binomial rewards.
+///
+/// Each pull of an arm is treated as a fixed number of `yes-no` trials, so a reward of `k` adds `k`
+/// successes and `n - k` failures to the arm's Beta distribution.
+pub struct BetaPosterior {
+    /// Number of trials in a single pull of each arm.
+    trials: Vec<u32>,
+
+    /// Number of pseudo-trials the initial guess is worth.
+    weight: f64,
+
+    /// Success parameters of the arms' Beta distributions.
+    alphas: Vec<f64>,
+
+    /// Failure parameters of the arms' Beta distributions.
+    betas: Vec<f64>,
+}
+
+impl BetaPosterior {
+    /// Initializes a uniform Beta posterior for arms with the given numbers of trials per pull.
+    ///
+    /// When the posterior is reset, the initial guesses are added to the uniform prior as `weight`
+    /// pseudo-trials. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(trials: Vec<u32>, weight: f64) -> BetaPosterior {
+        BetaPosterior::try_new(trials, weight).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a uniform Beta posterior, failing if there are no arms, a number of trials is
+    /// zero, or the weight is negative.
+    pub fn try_new(trials: Vec<u32>, weight: f64) -> Result<BetaPosterior, RatelError> {
+        check_each("trials", &trials, "must be positive", |n| n > 0.0)?;
+        check("weight", weight, "must be non-negative and finite", |w| {
+            w >= 0.0 && w.is_finite()
+        })?;
+        let arms = trials.len();
+        Ok(BetaPosterior {
+            trials,
+            weight,
+            alphas: vec![1.0; arms],
+            betas: vec![1.0; arms],
+        })
+    }
+
+    /// Returns the success parameters of the arms' Beta distributions.
+    pub fn alphas(&self) -> &Vec<f64> {
+        &self.alphas
+    }
+
+    /// Returns the failure parameters of the arms' Beta distributions.
+    pub fn betas(&self) -> &Vec<f64> {
+        &self.betas
+    }
+}
+
+impl Posterior for BetaPosterior {
+    /// Returns the number of arms tracked by the posterior.
+    fn arms(&self) -> usize {
+        self.trials.len()
+    }
+
+    /// Returns the posterior mean of the number of successes in a pull of the given arm.
+    fn mean(&self, arm: usize) -> f64 {
+        f64::from(self.trials[arm]) * self.alphas[arm] / (self.alphas[arm] + self.betas[arm])
+    }
+
+    /// Resets each arm to a uniform prior plus `weight` pseudo-trials at the guessed success rate.
+    fn reset(&mut self, q_init: &[f64]) {
+        assert_eq!(q_init.len(), self.trials.len());
+        for (arm, (&q, &n)) in q_init.iter().zip(&self.trials).enumerate() {
+            let p = (q / f64::from(n)).clamp(0.0, 1.0);
+            self.alphas[arm] = 1.0 + self.weight * p;
+            self.betas[arm] = 1.0 + self.weight * (1.0 - p);
+        }
+    }
+
+    /// Draws a success probability from the arm's Beta distribution, scaled by its trials.
+    fn sample(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        let beta = Beta::new(self.alphas[arm], self.betas[arm]).unwrap();
+        f64::from(self.trials[arm]) * beta.sample(rng)
+    }
+
+    /// Adds the reward to the arm's successes and the remaining trials to its failures. Rewards
+    /// outside `[0, trials]` are clamped to that range.
+    fn update(&mut self, arm: usize, reward: f64) {
+        let n = f64::from(self.trials[arm]);
+        let reward = reward.clamp(0.0, n);
+        self.alphas[arm] += reward;
+        self.betas[arm] += n - reward;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use super::{BetaPosterior, Posterior};
+
+    #[test]
+    fn test_mean() {
+        let mut beta = BetaPosterior::new(vec![1, 4], 2.0);
+        beta.reset(&[0.5, 1.0]);
+        assert_approx_eq!(beta.mean(0), 0.5);
+        assert_approx_eq!(beta.mean(1), 1.5)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_zero_trials() {
+        BetaPosterior::new(vec![1, 0, 3], 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut beta = BetaPosterior::new(vec![1, 4], 2.0);
+        beta.update(1, 3.0);
+        beta.reset(&[0.25, 6.0]);
+        assert_eq!(beta.alphas(), &vec![1.5, 3.0]);
+        assert_eq!(beta.betas(), &vec![2.5, 1.0])
+    }
+
+    #[test]
+    fn test_sample() {
+        let beta = BetaPosterior::new(vec![1, 4], 0.0);
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            let s = beta.sample(1, &mut rng);
+            assert!((0.0..=4.0).contains(&s))
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let mut beta = BetaPosterior::new(vec![1, 4], 0.0);
+        beta.update(1, 3.0);
+        beta.update(0, 0.0);
+        assert_eq!(beta.alphas(), &vec![1.0, 4.0]);
+        assert_eq!(beta.betas(), &vec![2.0, 2.0]);
+        assert_approx_eq!(beta.mean(1), 8.0 / 3.0)
+    }
+
+    #[test]
+    fn test_update_out_of_range() {
+        let mut beta = BetaPosterior::new(vec![1, 4], 0.0);
+        beta.update(0, 3.0);
+        beta.update(1, -2.0);
+        assert_eq!(beta.alphas(), &vec![2.0, 1.0]);
+        assert_eq!(beta.betas(), &vec![1.0, 5.0]);
+        let mut rng = thread_rng();
+        assert!((0.0..=1.0).contains(&beta.sample(0, &mut rng)));
+        assert!((0.0..=4.0).contains(&beta.sample(1, &mut rng)))
+    }
+}
@@ -0,0 +1,7 @@
+pub use beta::BetaPosterior;
+pub use normal_gamma::NormalGammaPosterior;
+pub use posterior::Posterior;
+
+mod beta;
+mod normal_gamma;
+mod posterior;
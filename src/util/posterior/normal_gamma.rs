@@ -0,0 +1,173 @@
+use rand::distributions::Distribution;
+use rand::RngCore;
+use rand_distr::{Gamma, Normal};
+
+use crate::error::check;
+use crate::RatelError;
+
+use super::Posterior;
+
+/// Normal-Gamma posterior for Gaussian rewards with unknown mean and precision.
+///
+/// The mean of each arm is Normal given the arm's precision, and the precision is Gamma
+/// distributed.
+pub struct NormalGammaPosterior {
+    /// Prior number of pseudo-observations of the mean.
+    lambda: f64,
+
+    /// Prior shape of the precision.
+    alpha: f64,
+
+    /// Prior rate of the precision.
+    beta: f64,
+
+    /// Posterior locations of the arm means.
+    mus: Vec<f64>,
+
+    /// Posterior numbers of pseudo-observations of the arm means.
+    lambdas: Vec<f64>,
+
+    /// Posterior shapes of the arm precisions.
+    alphas: Vec<f64>,
+
+    /// Posterior rates of the arm precisions.
+    betas: Vec<f64>,
+}
+
+impl NormalGammaPosterior {
+    /// Initializes a Normal-Gamma posterior with the same prior parameters for every arm.
+    ///
+    /// The prior locations of the means are set from the initial guesses when the posterior is
+    /// reset. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(arms: usize, lambda: f64, alpha: f64, beta: f64) -> NormalGammaPosterior {
+        NormalGammaPosterior::try_new(arms, lambda, alpha, beta).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a Normal-Gamma posterior, failing if there are no arms or a prior parameter is
+    /// not positive.
+    pub fn try_new(
+        arms: usize,
+        lambda: f64,
+        alpha: f64,
+        beta: f64,
+    ) -> Result<NormalGammaPosterior, RatelError> {
+        if arms == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        let positive = |x: f64| x > 0.0 && x.is_finite();
+        check("lambda", lambda, "must be positive and finite", positive)?;
+        check("alpha", alpha, "must be positive and finite", positive)?;
+        check("beta", beta, "must be positive and finite", positive)?;
+        Ok(NormalGammaPosterior {
+            lambda,
+            alpha,
+            beta,
+            mus: vec![0.0; arms],
+            lambdas: vec![lambda; arms],
+            alphas: vec![alpha; arms],
+            betas: vec![beta; arms],
+        })
+    }
+
+    /// Returns the posterior numbers of pseudo-observations of the arm means.
+    pub fn lambdas(&self) -> &Vec<f64> {
+        &self.lambdas
+    }
+
+    /// Returns the posterior shapes of the arm precisions.
+    pub fn alphas(&self) -> &Vec<f64> {
+        &self.alphas
+    }
+
+    /// Returns the posterior rates of the arm precisions.
+    pub fn betas(&self) -> &Vec<f64> {
+        &self.betas
+    }
+}
+
+impl Posterior for NormalGammaPosterior {
+    /// Returns the number of arms tracked by the posterior.
+    fn arms(&self) -> usize {
+        self.mus.len()
+    }
+
+    /// Returns the posterior location of the arm's mean.
+    fn mean(&self, arm: usize) -> f64 {
+        self.mus[arm]
+    }
+
+    /// Resets each arm to the prior, centered on the initial guess of its mean.
+    fn reset(&mut self, q_init: &[f64]) {
+        assert_eq!(q_init.len(), self.mus.len());
+        let arms = q_init.len();
+        self.mus = q_init.to_owned();
+        self.lambdas = vec![self.lambda; arms];
+        self.alphas = vec![self.alpha; arms];
+        self.betas = vec![self.beta; arms];
+    }
+
+    /// Draws a precision from the arm's Gamma distribution and then a mean given that precision.
+    fn sample(&self, arm: usize, rng: &mut dyn RngCore) -> f64 {
+        let tau = Gamma::new(self.alphas[arm], 1.0 / self.betas[arm])
+            .unwrap()
+            .sample(rng);
+        Normal::new(self.mus[arm], (1.0 / (self.lambdas[arm] * tau)).sqrt())
+            .unwrap()
+            .sample(rng)
+    }
+
+    /// Applies the conjugate update for a single observation.
+    fn update(&mut self, arm: usize, reward: f64) {
+        let (mu, lambda) = (self.mus[arm], self.lambdas[arm]);
+        self.mus[arm] = (lambda * mu + reward) / (lambda + 1.0);
+        self.lambdas[arm] = lambda + 1.0;
+        self.alphas[arm] += 0.5;
+        self.betas[arm] += lambda * (reward - mu).powi(2) / (2.0 * (lambda + 1.0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+    use rand::thread_rng;
+
+    use super::{NormalGammaPosterior, Posterior};
+
+    #[test]
+    #[should_panic]
+    fn test_new_neg_lambda() {
+        NormalGammaPosterior::new(3, -1.0, 1.0, 1.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut ng = NormalGammaPosterior::new(3, 1.0, 2.0, 3.0);
+        ng.update(2, 4.0);
+        ng.reset(&[0.5, -1.2, 2.0]);
+        assert_eq!(ng.mean(1), -1.2);
+        assert_eq!(ng.lambdas(), &vec![1.0; 3]);
+        assert_eq!(ng.alphas(), &vec![2.0; 3]);
+        assert_eq!(ng.betas(), &vec![3.0; 3])
+    }
+
+    #[test]
+    fn test_sample() {
+        let ng = NormalGammaPosterior::new(3, 1.0, 2.0, 3.0);
+        let mut rng = thread_rng();
+        for _ in 0..1000 {
+            assert!(ng.sample(0, &mut rng).is_finite())
+        }
+    }
+
+    #[test]
+    fn test_update() {
+        let mut ng = NormalGammaPosterior::new(2, 1.0, 2.0, 3.0);
+        ng.reset(&[1.0, 0.0]);
+        ng.update(0, 3.0);
+        assert_approx_eq!(ng.mean(0), 2.0);
+        assert_approx_eq!(ng.lambdas()[0], 2.0);
+        assert_approx_eq!(ng.alphas()[0], 2.5);
+        assert_approx_eq!(ng.betas()[0], 4.0);
+        assert_eq!(ng.mean(1), 0.0)
+    }
+}
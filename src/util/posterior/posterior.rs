@@ -0,0 +1,20 @@
+use rand::RngCore;
+
+/// Trait containing all methods required by all conjugate posteriors.
+pub trait Posterior {
+    /// Returns the number of arms tracked by the posterior.
+    fn arms(&self) -> usize;
+
+    /// Returns the posterior mean of the given arm's average reward.
+    fn mean(&self, arm: usize) -> f64;
+
+    /// Resets the posterior to a prior centered on the given initial guesses.
+    fn reset(&mut self, q_init: &[f64]);
+
+    /// Draws a sample of the given arm's average reward from the posterior using the given random
+    /// number generator.
+    fn sample(&self, arm: usize, rng: &mut dyn RngCore) -> f64;
+
+    /// Updates the posterior of the given arm with an observed reward.
+    fn update(&mut self, arm: usize, reward: f64);
+}
@@ -0,0 +1,95 @@
+use crate::error::check;
+use crate::RatelError;
+
+/// Maps rewards from a known range onto `[0, 1]`, for Agents whose guarantees assume bounded
+/// rewards.
+///
+/// Rewards outside the range, such as the tails of a Gaussian arm, are clipped to its ends.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RewardScale {
+    /// Smallest reward, mapped to 0.
+    low: f64,
+
+    /// Largest reward, mapped to 1.
+    high: f64,
+}
+
+impl Default for RewardScale {
+    /// Leaves rewards in `[0, 1]` unchanged.
+    fn default() -> Self {
+        RewardScale {
+            low: 0.0,
+            high: 1.0,
+        }
+    }
+}
+
+impl RewardScale {
+    /// Initializes a scale for rewards between `low` and `high`. Panics if the range is invalid;
+    /// see `try_new`.
+    pub fn new(low: f64, high: f64) -> RewardScale {
+        RewardScale::try_new(low, high).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes a scale for rewards between `low` and `high`, failing if either end is not
+    /// finite or `high` is not greater than `low`.
+    pub fn try_new(low: f64, high: f64) -> Result<RewardScale, RatelError> {
+        check("low", low, "must be finite", f64::is_finite)?;
+        check("high", high, "must be finite", f64::is_finite)?;
+        if high <= low {
+            return Err(RatelError::InvalidParameter {
+                name: "high",
+                index: None,
+                value: high,
+                reason: "must be greater than low",
+            });
+        }
+        Ok(RewardScale { low, high })
+    }
+
+    /// Returns the largest reward.
+    pub fn high(&self) -> f64 {
+        self.high
+    }
+
+    /// Returns the smallest reward.
+    pub fn low(&self) -> f64 {
+        self.low
+    }
+
+    /// Returns the reward mapped onto `[0, 1]`.
+    pub fn scale(&self, reward: f64) -> f64 {
+        ((reward - self.low) / (self.high - self.low)).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RatelError;
+
+    use super::RewardScale;
+
+    #[test]
+    fn test_scale() {
+        let scale = RewardScale::new(-2.0, 2.0);
+        assert_eq!(scale.scale(0.0), 0.5);
+        assert_eq!(scale.scale(1.0), 0.75);
+        assert_eq!(scale.scale(-5.0), 0.0);
+        assert_eq!(scale.scale(3.0), 1.0);
+        assert_eq!(RewardScale::default().scale(0.25), 0.25)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            RewardScale::try_new(1.0, 1.0),
+            Err(RatelError::InvalidParameter {
+                name: "high",
+                index: None,
+                value: 1.0,
+                reason: "must be greater than low",
+            })
+        );
+        assert!(RewardScale::try_new(f64::NEG_INFINITY, 1.0).is_err())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::Schedule;
+
+/// Schedule that keeps the same value in every round.
+pub struct ConstantSchedule {
+    /// Value in every round.
+    value: f64,
+}
+
+impl ConstantSchedule {
+    /// Initializes the schedule with a non-negative value. Panics if the value is invalid.
+    pub fn new(value: f64) -> ConstantSchedule {
+        ConstantSchedule::try_new(value).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the schedule, failing unless the value is non-negative and finite.
+    pub fn try_new(value: f64) -> Result<ConstantSchedule, RatelError> {
+        check("value", value, "must be non-negative and finite", |v| {
+            v >= 0.0 && v.is_finite()
+        })?;
+        Ok(ConstantSchedule { value })
+    }
+}
+
+impl Schedule for ConstantSchedule {
+    /// Returns the constant value.
+    fn value(&self, _t: u32) -> f64 {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RatelError;
+
+    use super::{ConstantSchedule, Schedule};
+
+    #[test]
+    fn test_value() {
+        let schedule = ConstantSchedule::new(0.3);
+        assert_eq!(schedule.value(0), 0.3);
+        assert_eq!(schedule.value(1), 0.3);
+        assert_eq!(schedule.value(1000), 0.3)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            ConstantSchedule::try_new(-0.1).err(),
+            Some(RatelError::InvalidParameter {
+                name: "value",
+                index: None,
+                value: -0.1,
+                reason: "must be non-negative and finite"
+            })
+        );
+        assert!(ConstantSchedule::try_new(f64::INFINITY).is_err());
+        assert!(ConstantSchedule::try_new(0.0).is_ok())
+    }
+}
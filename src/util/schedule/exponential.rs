@@ -0,0 +1,71 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::Schedule;
+
+/// Schedule that decays geometrically, `initial * rate^(t - 1)`, down to a floor.
+pub struct ExponentialSchedule {
+    /// Value in the first round.
+    initial: f64,
+    /// Factor the value is multiplied by each round.
+    rate: f64,
+    /// Smallest value the schedule decays to.
+    minimum: f64,
+}
+
+impl ExponentialSchedule {
+    /// Initializes the schedule. Panics if the parameters are invalid; see `try_new`.
+    pub fn new(initial: f64, rate: f64, minimum: f64) -> ExponentialSchedule {
+        ExponentialSchedule::try_new(initial, rate, minimum).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the schedule, failing if the initial value is not positive, the rate is not in
+    /// `(0, 1]`, or the floor is negative.
+    pub fn try_new(
+        initial: f64,
+        rate: f64,
+        minimum: f64,
+    ) -> Result<ExponentialSchedule, RatelError> {
+        check("initial", initial, "must be positive and finite", |i| {
+            i > 0.0 && i.is_finite()
+        })?;
+        check("rate", rate, "must be in (0, 1]", |r| r > 0.0 && r <= 1.0)?;
+        check("minimum", minimum, "must be non-negative and finite", |m| {
+            m >= 0.0 && m.is_finite()
+        })?;
+        Ok(ExponentialSchedule {
+            initial,
+            rate,
+            minimum,
+        })
+    }
+}
+
+impl Schedule for ExponentialSchedule {
+    /// Returns the decayed value, never below the floor.
+    fn value(&self, t: u32) -> f64 {
+        let exponent = f64::from(t.max(1) - 1);
+        (self.initial * self.rate.powf(exponent)).max(self.minimum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{ExponentialSchedule, Schedule};
+
+    #[test]
+    fn test_value() {
+        let schedule = ExponentialSchedule::new(2.0, 0.5, 0.1);
+        assert_approx_eq!(schedule.value(1), 2.0);
+        assert_approx_eq!(schedule.value(3), 0.5);
+        assert_approx_eq!(schedule.value(100), 0.1)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_rate() {
+        ExponentialSchedule::new(1.0, 1.5, 0.0);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::Schedule;
+
+/// Schedule that decays as `scale / t`.
+///
+/// As an epsilon schedule with `scale = c K / d^2`, capped at 1, this is the epsilon_n-greedy rule
+/// of Auer, Cesa-Bianchi, and Fischer (2002) for `K` arms whose best and second best means differ
+/// by at least `d`.
+pub struct InverseSchedule {
+    /// Value in the first round.
+    scale: f64,
+}
+
+impl InverseSchedule {
+    /// Initializes the schedule with a positive scale. Panics if the scale is invalid.
+    pub fn new(scale: f64) -> InverseSchedule {
+        InverseSchedule::try_new(scale).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the schedule, failing unless the scale is positive and finite.
+    pub fn try_new(scale: f64) -> Result<InverseSchedule, RatelError> {
+        check("scale", scale, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        Ok(InverseSchedule { scale })
+    }
+}
+
+impl Schedule for InverseSchedule {
+    /// Returns the scale divided by the round number.
+    fn value(&self, t: u32) -> f64 {
+        self.scale / f64::from(t.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{InverseSchedule, Schedule};
+
+    #[test]
+    fn test_value() {
+        let schedule = InverseSchedule::new(5.0);
+        assert_approx_eq!(schedule.value(1), 5.0);
+        assert_approx_eq!(schedule.value(20), 0.25)
+    }
+}
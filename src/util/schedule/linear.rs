@@ -0,0 +1,94 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::Schedule;
+
+/// Schedule that anneals linearly between breakpoints.
+///
+/// Each breakpoint is a round and the value in that round. Between breakpoints the value is
+/// interpolated linearly; before the first and after the last it stays at the nearest breakpoint's
+/// value.
+pub struct LinearSchedule {
+    /// Rounds and values of the breakpoints, in increasing order of round.
+    points: Vec<(u32, f64)>,
+}
+
+impl LinearSchedule {
+    /// Initializes the schedule from its breakpoints. Panics if the breakpoints are invalid; see
+    /// `try_new`.
+    pub fn new(points: Vec<(u32, f64)>) -> LinearSchedule {
+        LinearSchedule::try_new(points).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the schedule, failing if there are no breakpoints, their rounds are not
+    /// strictly increasing, or a value is negative.
+    pub fn try_new(points: Vec<(u32, f64)>) -> Result<LinearSchedule, RatelError> {
+        if points.is_empty() {
+            return Err(RatelError::Empty { name: "points" });
+        }
+        for (index, window) in points.windows(2).enumerate() {
+            if window[1].0 <= window[0].0 {
+                return Err(RatelError::InvalidParameter {
+                    name: "points",
+                    index: Some(index + 1),
+                    value: f64::from(window[1].0),
+                    reason: "must come after the previous round",
+                });
+            }
+        }
+        for &(_, value) in &points {
+            check("value", value, "must be non-negative and finite", |v| {
+                v >= 0.0 && v.is_finite()
+            })?;
+        }
+        Ok(LinearSchedule { points })
+    }
+}
+
+impl Schedule for LinearSchedule {
+    /// Returns the value interpolated between the breakpoints around the round.
+    fn value(&self, t: u32) -> f64 {
+        let after = self.points.iter().position(|&(round, _)| round > t);
+        match after {
+            Some(0) => self.points[0].1,
+            Some(i) => {
+                let (t0, v0) = self.points[i - 1];
+                let (t1, v1) = self.points[i];
+                v0 + (v1 - v0) * f64::from(t - t0) / f64::from(t1 - t0)
+            }
+            None => self.points[self.points.len() - 1].1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use crate::RatelError;
+
+    use super::{LinearSchedule, Schedule};
+
+    #[test]
+    fn test_value() {
+        let schedule = LinearSchedule::new(vec![(1, 1.0), (11, 0.5), (21, 0.1)]);
+        assert_approx_eq!(schedule.value(0), 1.0);
+        assert_approx_eq!(schedule.value(6), 0.75);
+        assert_approx_eq!(schedule.value(11), 0.5);
+        assert_approx_eq!(schedule.value(16), 0.3);
+        assert_approx_eq!(schedule.value(1000), 0.1)
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert_eq!(
+            LinearSchedule::try_new(vec![(1, 1.0), (1, 0.5)]).err(),
+            Some(RatelError::InvalidParameter {
+                name: "points",
+                index: Some(1),
+                value: 1.0,
+                reason: "must come after the previous round"
+            })
+        )
+    }
+}
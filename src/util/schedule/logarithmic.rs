@@ -0,0 +1,46 @@
+use crate::error::check;
+use crate::RatelError;
+
+use super::Schedule;
+
+/// Schedule that decays logarithmically, `scale / ln(t + 1)`.
+pub struct LogarithmicSchedule {
+    /// Multiple of the inverse logarithm.
+    scale: f64,
+}
+
+impl LogarithmicSchedule {
+    /// Initializes the schedule with a positive scale. Panics if the scale is invalid.
+    pub fn new(scale: f64) -> LogarithmicSchedule {
+        LogarithmicSchedule::try_new(scale).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes the schedule, failing unless the scale is positive and finite.
+    pub fn try_new(scale: f64) -> Result<LogarithmicSchedule, RatelError> {
+        check("scale", scale, "must be positive and finite", |s| {
+            s > 0.0 && s.is_finite()
+        })?;
+        Ok(LogarithmicSchedule { scale })
+    }
+}
+
+impl Schedule for LogarithmicSchedule {
+    /// Returns the scale divided by the logarithm of the round number plus one.
+    fn value(&self, t: u32) -> f64 {
+        self.scale / f64::from(t.max(1)).ln_1p()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::{LogarithmicSchedule, Schedule};
+
+    #[test]
+    fn test_value() {
+        let schedule = LogarithmicSchedule::new(2.0);
+        assert_approx_eq!(schedule.value(1), 2.0 / 2f64.ln());
+        assert_approx_eq!(schedule.value(9), 2.0 / 10f64.ln())
+    }
+}
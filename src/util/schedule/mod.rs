@@ -0,0 +1,13 @@
+pub use constant::ConstantSchedule;
+pub use exponential::ExponentialSchedule;
+pub use inverse::InverseSchedule;
+pub use linear::LinearSchedule;
+pub use logarithmic::LogarithmicSchedule;
+pub use schedule::Schedule;
+
+mod constant;
+mod exponential;
+mod inverse;
+mod linear;
+mod logarithmic;
+mod schedule;
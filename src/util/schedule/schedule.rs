@@ -0,0 +1,5 @@
+/// Trait containing all methods required by all schedules of Agent hyperparameters.
+pub trait Schedule {
+    /// Returns the value of the hyperparameter in round `t`, counting the first round as 1.
+    fn value(&self, t: u32) -> f64;
+}
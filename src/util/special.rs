@@ -0,0 +1,53 @@
+//! Special functions needed for the moments of some reward distributions.
+
+use std::f64::consts::PI;
+
+/// Offset of the Lanczos approximation.
+const LANCZOS_G: f64 = 7.0;
+
+/// Coefficients of the Lanczos approximation with `g = 7`.
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// Returns the natural logarithm of the gamma function of a positive argument, by the Lanczos
+/// approximation for arguments of at least one half and the reflection formula below that.
+/// Accurate to about 15 significant digits.
+pub(crate) fn ln_gamma(x: f64) -> f64 {
+    if x < 0.5 {
+        (PI / (PI * x).sin()).ln() - ln_gamma(1.0 - x)
+    } else {
+        let x = x - 1.0;
+        let t = x + LANCZOS_G + 0.5;
+        let series = LANCZOS
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(LANCZOS[0], |a, (i, c)| a + c / (x + i as f64));
+        0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_approx_eq::assert_approx_eq;
+
+    use super::ln_gamma;
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_approx_eq!(ln_gamma(1.0), 0.0, 1e-12);
+        assert_approx_eq!(ln_gamma(5.0), 24f64.ln(), 1e-12);
+        assert_approx_eq!(ln_gamma(0.5), std::f64::consts::PI.sqrt().ln(), 1e-12);
+        assert_approx_eq!(ln_gamma(0.25), 3.625_609_908_221_908f64.ln(), 1e-12);
+        assert_approx_eq!(ln_gamma(301.0), 1_414.905_849_945_068, 1e-9)
+    }
+}
@@ -1,3 +1,6 @@
+use crate::error::check;
+use crate::RatelError;
+
 use super::Stepper;
 
 /// Stepper using a constant value.
@@ -8,14 +11,62 @@ pub struct ConstantStepper {
 
 impl ConstantStepper {
     /// Initialize stepper with positive value.
-    fn new(step_size: f64) -> ConstantStepper {
-        assert!(step_size > 0.0);
-        ConstantStepper { step_size }
+    pub fn new(step_size: f64) -> ConstantStepper {
+        ConstantStepper::try_new(step_size).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initialize stepper, failing unless the value is positive and finite.
+    pub fn try_new(step_size: f64) -> Result<ConstantStepper, RatelError> {
+        check_step_size(step_size)?;
+        Ok(ConstantStepper { step_size })
+    }
+
+    /// Returns a builder for a ConstantStepper.
+    pub fn builder() -> ConstantStepperBuilder {
+        ConstantStepperBuilder::default()
+    }
+
+    /// Updates the step size. Panics unless the value is positive and finite; see `try_update`.
+    pub fn update(&mut self, step_size: f64) {
+        self.try_update(step_size)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Updates the step size, failing unless the value is positive and finite.
+    pub fn try_update(&mut self, step_size: f64) -> Result<(), RatelError> {
+        check_step_size(step_size)?;
+        self.step_size = step_size;
+        Ok(())
+    }
+}
+
+/// Checks that a step size is positive and finite.
+fn check_step_size(step_size: f64) -> Result<(), RatelError> {
+    check("step_size", step_size, "must be positive and finite", |s| {
+        s > 0.0 && s.is_finite()
+    })
+}
+
+/// Builder for a ConstantStepper.
+#[derive(Clone, Debug, Default)]
+pub struct ConstantStepperBuilder {
+    /// Step multiple for update rule.
+    step_size: Option<f64>,
+}
+
+impl ConstantStepperBuilder {
+    /// Sets the step size.
+    pub fn step_size(mut self, step_size: f64) -> Self {
+        self.step_size = Some(step_size);
+        self
     }
 
-    /// Updates the step size.
-    fn update(&mut self, step_size: f64) {
-        self.step_size = step_size
+    /// Builds the Stepper, failing if the step size was not set or is not positive.
+    pub fn build(self) -> Result<ConstantStepper, RatelError> {
+        let step_size = self
+            .step_size
+            .ok_or(RatelError::Missing { name: "step_size" })?;
+        ConstantStepper::try_new(step_size)
     }
 }
 
@@ -28,3 +79,43 @@ impl Stepper for ConstantStepper {
         self.step_size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{RatelError, Stepper};
+
+    use super::ConstantStepper;
+
+    #[test]
+    fn test_builder() {
+        let mut stepper = ConstantStepper::builder().step_size(0.1).build().unwrap();
+        assert_eq!(stepper.step(4), 0.1)
+    }
+
+    #[test]
+    fn test_builder_missing() {
+        assert_eq!(
+            ConstantStepper::builder().build().err(),
+            Some(RatelError::Missing { name: "step_size" })
+        );
+        assert!(ConstantStepper::builder().step_size(0.0).build().is_err())
+    }
+
+    #[test]
+    fn test_update() {
+        let mut stepper = ConstantStepper::new(0.1);
+        stepper.update(0.5);
+        assert_eq!(stepper.step(0), 0.5);
+        assert!(stepper.try_update(f64::NAN).is_err());
+        assert_eq!(
+            stepper.try_update(-1.0).err(),
+            Some(RatelError::InvalidParameter {
+                name: "step_size",
+                index: None,
+                value: -1.0,
+                reason: "must be positive and finite"
+            })
+        );
+        assert_eq!(stepper.step(0), 0.5)
+    }
+}
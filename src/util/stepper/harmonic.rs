@@ -1,3 +1,6 @@
+use crate::error::check;
+use crate::RatelError;
+
 use super::Stepper;
 
 /// Stepper with a harmonically decreasing step size.
@@ -11,14 +14,28 @@ pub struct HarmonicStepper {
 }
 
 impl HarmonicStepper {
-    /// Initializes HarmonicStepper with the same step size for each bandit arm.
+    /// Initializes HarmonicStepper with the same step size for each bandit arm. Panics if either
+    /// argument is zero.
     pub fn new(step_size: u32, length: usize) -> HarmonicStepper {
-        assert!(step_size > 0);
-        assert!(length > 0);
-        HarmonicStepper {
+        HarmonicStepper::try_new(step_size, length).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Initializes HarmonicStepper with the same step size for each bandit arm, failing if either
+    /// argument is zero.
+    pub fn try_new(step_size: u32, length: usize) -> Result<HarmonicStepper, RatelError> {
+        check("step_size", step_size, "must be positive", |s| s > 0.0)?;
+        if length == 0 {
+            return Err(RatelError::Empty { name: "arms" });
+        }
+        Ok(HarmonicStepper {
             warm_up: step_size,
             step_size: vec![step_size; length],
-        }
+        })
+    }
+
+    /// Returns a builder for a HarmonicStepper, starting from a step size of 1.
+    pub fn builder() -> HarmonicStepperBuilder {
+        HarmonicStepperBuilder::default()
     }
 
     /// Returns the number of arms in the bandit.
@@ -27,6 +44,44 @@ impl HarmonicStepper {
     }
 }
 
+/// Builder for a HarmonicStepper.
+#[derive(Clone, Debug)]
+pub struct HarmonicStepperBuilder {
+    /// Initial inverse step size of every arm.
+    step_size: u32,
+    /// Number of bandit arms.
+    arms: usize,
+}
+
+impl Default for HarmonicStepperBuilder {
+    /// A sample average stepper, with no arms yet.
+    fn default() -> Self {
+        HarmonicStepperBuilder {
+            step_size: 1,
+            arms: 0,
+        }
+    }
+}
+
+impl HarmonicStepperBuilder {
+    /// Sets the number of bandit arms.
+    pub fn arms(mut self, arms: usize) -> Self {
+        self.arms = arms;
+        self
+    }
+
+    /// Sets the initial inverse step size of every arm.
+    pub fn step_size(mut self, step_size: u32) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    /// Builds the Stepper, failing if the number of arms was not set or either value is zero.
+    pub fn build(self) -> Result<HarmonicStepper, RatelError> {
+        HarmonicStepper::try_new(self.step_size, self.arms)
+    }
+}
+
 impl Stepper for HarmonicStepper {
     /// Resets all step sizes to initial value.
     fn reset(&mut self) {
@@ -40,3 +95,30 @@ impl Stepper for HarmonicStepper {
         s
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{RatelError, Stepper};
+
+    use super::HarmonicStepper;
+
+    #[test]
+    fn test_builder() {
+        let mut stepper = HarmonicStepper::builder()
+            .step_size(2)
+            .arms(3)
+            .build()
+            .unwrap();
+        assert_eq!(stepper.arms(), 3);
+        assert_eq!(stepper.step(1), 0.5);
+        assert_eq!(stepper.step(1), 1.0 / 3.0)
+    }
+
+    #[test]
+    fn test_builder_no_arms() {
+        assert_eq!(
+            HarmonicStepper::builder().build().err(),
+            Some(RatelError::Empty { name: "arms" })
+        )
+    }
+}
@@ -1,5 +1,5 @@
-pub use constant::ConstantStepper;
-pub use harmonic::HarmonicStepper;
+pub use constant::{ConstantStepper, ConstantStepperBuilder};
+pub use harmonic::{HarmonicStepper, HarmonicStepperBuilder};
 pub use stepper::Stepper;
 
 mod constant;